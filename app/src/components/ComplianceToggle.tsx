@@ -1,10 +1,17 @@
 import { useState } from 'react'
 import { useWallet, useAnchorWallet } from '@solana/wallet-adapter-react'
 import { motion } from 'framer-motion'
-import { SystemProgram, PublicKey } from '@solana/web3.js'
+import { SystemProgram, PublicKey, Ed25519Program, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js'
 import toast from 'react-hot-toast'
 import { getProvider, getProgram } from '../lib/anchor'
-import { getVaultConfigPda, getUserPositionPda, getCompliancePda, getSolscanUrl } from '../lib/utils'
+import {
+  getVaultConfigPda,
+  getUserPositionPda,
+  getCompliancePda,
+  getComplianceProvidersPda,
+  getAdapterRegistryPda,
+  getSolscanUrl,
+} from '../lib/utils'
 import { rangeCompliance } from '../lib/sdkIntegrations'
 import { LoadingSpinner } from './LoadingSpinner'
 import { VaultConfig, ComplianceAttestation } from '../types'
@@ -31,7 +38,7 @@ export function ComplianceToggle({ vaultConfig, compliance, onSuccess }: Complia
     }
 
     setLoading(true)
-    const toastId = toast.loading(isCompliant ? 'Revoking compliance...' : 'Submitting compliance...')
+    const toastId = toast.loading(isCompliant ? 'Renewing compliance...' : 'Submitting compliance...')
 
     try {
       const provider = getProvider(wallet)
@@ -40,35 +47,51 @@ export function ComplianceToggle({ vaultConfig, compliance, onSuccess }: Complia
       const [vaultConfigPda] = getVaultConfigPda()
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
       const [compliancePda] = getCompliancePda(vaultConfigPda, publicKey)
+      const [complianceProvidersPda] = getComplianceProvidersPda(vaultConfigPda)
+      const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
 
-      const { attestationHash, disclosureProof } = rangeCompliance.generateComplianceAttestation({
+      const { disclosureProof } = rangeCompliance.generateComplianceAttestation({
         userAddress: publicKey,
         jurisdiction: 'US',
         validityDays: 30,
       })
+      const { attestation, signature } = await rangeCompliance.requestProviderAttestation({
+        userAddress: publicKey,
+        jurisdiction: 'US',
+      })
+
+      // The program checks the provider's signature through the instructions sysvar
+      const verifyIx = Ed25519Program.createInstructionWithPublicKey({
+        publicKey: attestation.provider.toBytes(),
+        message: rangeCompliance.attestationMessage(attestation, publicKey),
+        signature,
+      })
 
-      const actionParam = isCompliant ? { revoke: {} } : { submit: {} }
+      const actionParam = isCompliant ? { renew: {} } : { submit: {} }
 
       const tx = await program.methods
         .applyCompliance({
           action: actionParam,
-          attestationHash,
           disclosureProof,
-          validityDays: 30,
+          providerAttestation: attestation,
         })
         .accountsStrict({
           user: publicKey,
           vaultConfig: vaultConfigPda,
           userPosition: userPositionPda,
           complianceAttestation: compliancePda,
+          complianceProviders: complianceProvidersPda,
+          adapterRegistry: adapterRegistryPda,
           rangeProgram: RANGE_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           systemProgram: SystemProgram.programId,
         })
+        .preInstructions([verifyIx])
         .rpc({ commitment: 'confirmed' })
 
       toast.success(
         <div>
-          {isCompliant ? 'Compliance revoked' : 'Compliance verified!'}{' '}
+          {isCompliant ? 'Compliance renewed!' : 'Compliance verified!'}{' '}
           <a href={getSolscanUrl(tx)} target="_blank" rel="noopener noreferrer" className="underline">
             View tx
           </a>
@@ -120,7 +143,7 @@ export function ComplianceToggle({ vaultConfig, compliance, onSuccess }: Complia
           disabled={loading || !vaultConfig}
           className={`px-4 py-2 rounded-lg font-medium text-sm transition-colors flex items-center gap-2 ${
             isCompliant
-              ? 'bg-surface border border-surface-border text-text-secondary hover:border-danger hover:text-danger'
+              ? 'bg-surface border border-surface-border text-text-secondary hover:border-primary hover:text-primary'
               : 'bg-primary text-surface hover:bg-primary-dim'
           }`}
         >
@@ -130,7 +153,7 @@ export function ComplianceToggle({ vaultConfig, compliance, onSuccess }: Complia
               <span>Processing...</span>
             </>
           ) : isCompliant ? (
-            'Revoke'
+            'Renew'
           ) : (
             'Verify'
           )}
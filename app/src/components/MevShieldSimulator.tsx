@@ -11,6 +11,8 @@ import {
   getUserPositionPda,
   getShieldedVaultPda,
   getDarkPoolOrderPda,
+  getAdapterRegistryPda,
+  getComplianceAccounts,
   getSolscanUrl,
 } from '../lib/utils'
 import { starpay } from '../lib/sdkIntegrations'
@@ -111,13 +113,23 @@ export function MevShieldSimulator({ vaultConfig, isVisible, onClose }: MevShiel
         const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
         const [darkPoolOrderPda] = getDarkPoolOrderPda(vaultConfigPda, publicKey)
         const [sourceVault] = getShieldedVaultPda(vaultConfig.shieldedMint)
+        const [destVault] = getShieldedVaultPda(vaultConfig.secondaryMint)
+        const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
+        const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+          vaultConfigPda,
+          publicKey,
+          vaultConfig.complianceRequired
+        )
+
+        const amountIn = new BN(1000000) // 0.001 SOL equivalent
+        const slippageBps = 100
 
         // Generate proof using SDK
         const { swapProof, amountInCommitment, minOutCommitment } = await starpay.generateSwapProof({
           inputMint: vaultConfig.shieldedMint,
           outputMint: vaultConfig.secondaryMint,
-          amount: new BN(1000000), // 0.001 SOL equivalent
-          slippageBps: 100,
+          amount: amountIn,
+          slippageBps,
         })
 
         const tx = await program.methods
@@ -126,10 +138,11 @@ export function MevShieldSimulator({ vaultConfig, isVisible, onClose }: MevShiel
             route: { starpay: {} },
             amountInCommitment,
             minOutCommitment,
+            amountIn,
+            minAmountOut: amountIn.muln(10000 - slippageBps).divn(10000),
             limitPriceCommitment: null,
             side: { buy: {} },
             swapProof,
-            maxSlippageBps: 100,
           })
           .accountsStrict({
             user: publicKey,
@@ -139,8 +152,13 @@ export function MevShieldSimulator({ vaultConfig, isVisible, onClose }: MevShiel
             sourceMint: vaultConfig.shieldedMint,
             destMint: vaultConfig.secondaryMint,
             sourceVault,
+            destVault,
+            adapterRegistry: adapterRegistryPda,
             starpayProgram: STARPAY_PROGRAM_ID,
             anoncoinProgram: ANONCOIN_PROGRAM_ID,
+            complianceAttestation,
+            compliancePolicy,
+            jurisdictions,
             token2022Program: TOKEN_2022_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
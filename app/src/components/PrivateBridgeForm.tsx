@@ -1,8 +1,9 @@
 import { useState } from 'react'
 import { useWallet, useAnchorWallet } from '@solana/wallet-adapter-react'
 import { motion } from 'framer-motion'
-import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token'
+import { TOKEN_2022_PROGRAM_ID, getAssociatedTokenAddressSync } from '@solana/spl-token'
 import { SystemProgram, PublicKey } from '@solana/web3.js'
+import { BN } from '@coral-xyz/anchor'
 import toast from 'react-hot-toast'
 import { getProvider, getProgram } from '../lib/anchor'
 import {
@@ -11,12 +12,19 @@ import {
   getUserPositionPda,
   getShieldedVaultPda,
   getBridgeRequestPda,
+  getChainConfigPda,
+  getBridgeStatsPda,
+  getRelayerKeyPda,
+  getBridgeEscrowPda,
+  getAdapterRegistryPda,
+  getComplianceAccounts,
   getChainName,
   getSolscanUrl,
+  MAX_PENDING_BRIDGE_REQUESTS,
 } from '../lib/utils'
 import { silentSwap } from '../lib/sdkIntegrations'
 import { LoadingSpinner } from './LoadingSpinner'
-import { VaultConfig, UserEncryptedPosition } from '../types'
+import { VaultConfig, UserEncryptedPosition, BridgeRequest } from '../types'
 
 interface PrivateBridgeFormProps {
   vaultConfig: VaultConfig | null
@@ -47,6 +55,7 @@ export function PrivateBridgeForm({
   const wallet = useAnchorWallet()
   const [amount, setAmount] = useState('')
   const [destChain, setDestChain] = useState(1)
+  const [recipient, setRecipient] = useState('')
   const [loading, setLoading] = useState(false)
   const [quote, setQuote] = useState<{
     outputAmount: string
@@ -55,6 +64,10 @@ export function PrivateBridgeForm({
   } | null>(null)
 
   const hasPendingBridge = userPosition?.hasPendingBridge ?? false
+  const pendingBridgeCount = userPosition?.pendingBridgeCount ?? 0
+  const atBridgeLimit = pendingBridgeCount >= MAX_PENDING_BRIDGE_REQUESTS
+  const nextBridgeNonce = userPosition?.bridgeNonce ?? new BN(0)
+  const validRecipient = /^0x[0-9a-fA-F]{40}$/.test(recipient.trim())
 
   const handleCancelBridge = async () => {
     if (!wallet || !publicKey || !vaultConfig) return
@@ -68,25 +81,63 @@ export function PrivateBridgeForm({
 
       const [vaultConfigPda] = getVaultConfigPda()
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
-      const [bridgeRequestPda] = getBridgeRequestPda(vaultConfigPda, publicKey)
+
+      // Cancels the most recent request that the relayer has not picked up yet
+      const requests = await program.account.bridgeRequest.all([
+        { memcmp: { offset: 8, bytes: publicKey.toBase58() } },
+      ])
+      const pending = requests
+        .map((r) => r.account as unknown as BridgeRequest)
+        .filter((r) => 'pending' in r.status)
+        .sort((a, b) => b.nonce.cmp(a.nonce))[0]
+      if (!pending) throw new Error('No pending bridge request to cancel')
+
+      const [bridgeRequestPda] = getBridgeRequestPda(vaultConfigPda, publicKey, pending.nonce)
+      const [chainConfigPda] = getChainConfigPda(vaultConfigPda, pending.destChainId.toNumber())
+      const [bridgeStatsPda] = getBridgeStatsPda(vaultConfigPda)
+      const [relayerKeysPda] = getRelayerKeyPda(vaultConfigPda)
       const [shieldedVaultAta] = getShieldedVaultPda(vaultConfig.shieldedMint)
+      const [bridgeEscrowPda] = getBridgeEscrowPda(vaultConfig.shieldedMint)
+      const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
+      const userTokenAccount = getAssociatedTokenAddressSync(
+        vaultConfig.shieldedMint,
+        publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      )
+      const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+        vaultConfigPda,
+        publicKey,
+        vaultConfig.complianceRequired
+      )
 
       const tx = await program.methods
         .privateBridge({
           action: { cancelRequest: {} },
-          destChain: { ethereum: {} },
-          amountCommitment: Array(32).fill(1),
+          destChainId: pending.destChainId,
+          requestNonce: pending.nonce,
+          amount: new BN(0),
+          amountCommitment: pending.amountCommitment,
+          encryptedRecipient: null,
           bridgeProof: Array(32).fill(1),
-          inboundProof: null,
         })
         .accountsStrict({
           user: publicKey,
           vaultConfig: vaultConfigPda,
           userPosition: userPositionPda,
           bridgeRequest: bridgeRequestPda,
+          chainConfig: chainConfigPda,
+          bridgeStats: bridgeStatsPda,
+          relayerKeys: relayerKeysPda,
           shieldedMint: vaultConfig.shieldedMint,
+          userTokenAccount,
           shieldedVaultAta,
+          bridgeEscrow: bridgeEscrowPda,
+          adapterRegistry: adapterRegistryPda,
           silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          complianceAttestation,
+          compliancePolicy,
+          jurisdictions,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -136,8 +187,8 @@ export function PrivateBridgeForm({
       return
     }
 
-    if (hasPendingBridge) {
-      toast.error('You have a pending bridge request')
+    if (atBridgeLimit) {
+      toast.error(`You already have ${MAX_PENDING_BRIDGE_REQUESTS} pending bridge requests`)
       return
     }
 
@@ -147,6 +198,11 @@ export function PrivateBridgeForm({
       return
     }
 
+    if (!validRecipient) {
+      toast.error('Please enter a valid destination address')
+      return
+    }
+
     setLoading(true)
     const toastId = toast.loading('Initiating private bridge...')
 
@@ -156,72 +212,66 @@ export function PrivateBridgeForm({
 
       const [vaultConfigPda] = getVaultConfigPda()
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
-      const [bridgeRequestPda] = getBridgeRequestPda(vaultConfigPda, publicKey)
+      const requestNonce = nextBridgeNonce
+      const [bridgeRequestPda] = getBridgeRequestPda(vaultConfigPda, publicKey, requestNonce)
+      const [chainConfigPda] = getChainConfigPda(vaultConfigPda, destChain)
+      const [bridgeStatsPda] = getBridgeStatsPda(vaultConfigPda)
+      const [relayerKeysPda] = getRelayerKeyPda(vaultConfigPda)
       const [shieldedVaultAta] = getShieldedVaultPda(vaultConfig.shieldedMint)
+      const [bridgeEscrowPda] = getBridgeEscrowPda(vaultConfig.shieldedMint)
+      const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
+      const userTokenAccount = getAssociatedTokenAddressSync(
+        vaultConfig.shieldedMint,
+        publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      )
+      const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+        vaultConfigPda,
+        publicKey,
+        vaultConfig.complianceRequired
+      )
 
       const { bridgeProof, commitment } = await silentSwap.initiateBridge({
         sourceChain: 'solana',
         destChain,
         amount: bridgeAmount,
-        recipient: publicKey.toBase58(),
+        recipient: recipient.trim(),
       })
 
-      const destChainParam = {
-        ethereum: destChain === 1 ? {} : undefined,
-        polygon: destChain === 137 ? {} : undefined,
-        arbitrum: destChain === 42161 ? {} : undefined,
-        optimism: destChain === 10 ? {} : undefined,
-        base: destChain === 8453 ? {} : undefined,
-        avalanche: destChain === 43114 ? {} : undefined,
-        bsc: destChain === 56 ? {} : undefined,
-      }
-
-      const selectedChain = Object.entries(destChainParam).find(([, v]) => v !== undefined)
-      if (!selectedChain) throw new Error('Invalid chain')
+      // The destination is only readable by the relayer holding the current key
+      const relayerKeys = await program.account.relayerKeyRegistry.fetch(relayerKeysPda)
+      const encryptedRecipient = silentSwap.sealRecipient(relayerKeys, recipient.trim())
 
       toast.loading('Submitting to SilentSwap...', { id: toastId })
 
-      await program.methods
-        .privateBridge({
-          action: { initiateOutbound: {} },
-          destChain: { [selectedChain[0]]: {} },
-          amountCommitment: commitment,
-          bridgeProof,
-          inboundProof: null,
-        })
-        .accountsStrict({
-          user: publicKey,
-          vaultConfig: vaultConfigPda,
-          userPosition: userPositionPda,
-          bridgeRequest: bridgeRequestPda,
-          shieldedMint: vaultConfig.shieldedMint,
-          shieldedVaultAta,
-          silentswapProgram: SILENTSWAP_PROGRAM_ID,
-          token2022Program: TOKEN_2022_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
-        .rpc({ commitment: 'confirmed' })
-
-      toast.loading('Waiting for bridge confirmation...', { id: toastId })
-
-      await new Promise((resolve) => setTimeout(resolve, 3000))
-
       const tx = await program.methods
         .privateBridge({
-          action: { verifyCompletion: {} },
-          destChain: { [selectedChain[0]]: {} },
+          action: { initiateOutbound: {} },
+          destChainId: new BN(destChain),
+          requestNonce,
+          amount: bridgeAmount,
           amountCommitment: commitment,
+          encryptedRecipient,
           bridgeProof,
-          inboundProof: null,
         })
         .accountsStrict({
           user: publicKey,
           vaultConfig: vaultConfigPda,
           userPosition: userPositionPda,
           bridgeRequest: bridgeRequestPda,
+          chainConfig: chainConfigPda,
+          bridgeStats: bridgeStatsPda,
+          relayerKeys: relayerKeysPda,
           shieldedMint: vaultConfig.shieldedMint,
+          userTokenAccount,
           shieldedVaultAta,
+          bridgeEscrow: bridgeEscrowPda,
+          adapterRegistry: adapterRegistryPda,
           silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          complianceAttestation,
+          compliancePolicy,
+          jurisdictions,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -229,7 +279,7 @@ export function PrivateBridgeForm({
 
       toast.success(
         <div>
-          Bridge to {getChainName(destChain)} completed!{' '}
+          Bridge to {getChainName(destChain)} submitted, the relayer will settle it!{' '}
           <a href={getSolscanUrl(tx)} target="_blank" rel="noopener noreferrer" className="underline">
             View tx
           </a>
@@ -278,7 +328,9 @@ export function PrivateBridgeForm({
                 <svg className="w-4 h-4" viewBox="0 0 24 24" fill="currentColor">
                   <path d="M1 21h22L12 2 1 21zm12-3h-2v-2h2v2zm0-4h-2v-4h2v4z" />
                 </svg>
-                <span>Pending bridge request</span>
+                <span>
+                  {pendingBridgeCount} pending bridge request{pendingBridgeCount === 1 ? '' : 's'}
+                </span>
               </div>
               <button
                 onClick={handleCancelBridge}
@@ -302,7 +354,7 @@ export function PrivateBridgeForm({
               setQuote(null)
             }}
             className="input-field"
-            disabled={loading || hasPendingBridge}
+            disabled={loading || atBridgeLimit}
           >
             {CHAINS.map((chain) => (
               <option key={chain.id} value={chain.id}>
@@ -312,6 +364,20 @@ export function PrivateBridgeForm({
           </select>
         </div>
 
+        <div>
+          <label className="block text-sm font-medium text-text-secondary mb-2">
+            Recipient Address
+          </label>
+          <input
+            type="text"
+            value={recipient}
+            onChange={(e) => setRecipient(e.target.value)}
+            placeholder="0x..."
+            className="input-field font-mono"
+            disabled={loading || atBridgeLimit}
+          />
+        </div>
+
         <div>
           <label className="block text-sm font-medium text-text-secondary mb-2">
             Amount (SOL)
@@ -326,7 +392,7 @@ export function PrivateBridgeForm({
               }}
               placeholder="0.00"
               className="input-field flex-1"
-              disabled={loading || hasPendingBridge}
+              disabled={loading || atBridgeLimit}
             />
             <button
               onClick={handleGetQuote}
@@ -361,7 +427,7 @@ export function PrivateBridgeForm({
 
         <button
           onClick={handleBridge}
-          disabled={loading || !amount || !vaultConfig || hasPendingBridge}
+          disabled={loading || !amount || !validRecipient || !vaultConfig || atBridgeLimit}
           className="btn-primary w-full flex items-center justify-center gap-2"
         >
           {loading ? (
@@ -8,10 +8,20 @@ import {
   getAssociatedTokenAddressSync,
   getAccount,
 } from '@solana/spl-token'
-import { SystemProgram, PublicKey, Transaction } from '@solana/web3.js'
+import { SystemProgram, PublicKey, Transaction, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js'
 import toast from 'react-hot-toast'
 import { getProvider, getProgram } from '../lib/anchor'
-import { parseAmount, getVaultConfigPda, getUserPositionPda, getShieldedVaultPda, getSolscanUrl } from '../lib/utils'
+import {
+  parseAmount,
+  getVaultConfigPda,
+  getUserPositionPda,
+  getShieldedVaultPda,
+  getScreeningPda,
+  getAuditorKeyPda,
+  getComplianceAccounts,
+  getMissingProofReason,
+  getSolscanUrl,
+} from '../lib/utils'
 import { shadowWire, generateDepositCommitments } from '../lib/sdkIntegrations'
 import { LoadingSpinner } from './LoadingSpinner'
 import { VaultConfig } from '../types'
@@ -53,6 +63,14 @@ export function PrivateDepositForm({
       const provider = getProvider(wallet)
       const program = getProgram(provider)
 
+      const [vaultConfigPda] = getVaultConfigPda()
+      const missingProof = await getMissingProofReason(
+        provider.connection,
+        vaultConfigPda,
+        vaultConfig.complianceRequired
+      )
+      if (missingProof) throw new Error(missingProof)
+
       const ata = getAssociatedTokenAddressSync(
         vaultConfig.shieldedMint,
         publicKey,
@@ -81,7 +99,6 @@ export function PrivateDepositForm({
       }
 
       toast.loading('Wrapping SOL to shielded tokens...', { id: toastId })
-      const [vaultConfigPda] = getVaultConfigPda()
       await program.methods
         .wrapSol({ amount: depositAmount })
         .accountsStrict({
@@ -102,12 +119,20 @@ export function PrivateDepositForm({
 
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
       const [shieldedVaultAta] = getShieldedVaultPda(vaultConfig.shieldedMint)
+      const [auditorKeyPda] = getAuditorKeyPda(vaultConfigPda)
+      const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+        vaultConfigPda,
+        publicKey,
+        vaultConfig.complianceRequired
+      )
 
       const tx = await program.methods
         .privateDeposit({
           amount: depositAmount,
           amountCommitment,
           blindingFactor,
+          screeningProof: null,
+          auditorDisclosure: null,
         })
         .accountsStrict({
           user: publicKey,
@@ -116,7 +141,12 @@ export function PrivateDepositForm({
           userTokenAccount: ata,
           shieldedVaultAta,
           shieldedMint: vaultConfig.shieldedMint,
-          complianceAttestation: null,
+          complianceAttestation,
+          compliancePolicy,
+          jurisdictions,
+          screening: vaultConfig.complianceRequired ? getScreeningPda(vaultConfigPda)[0] : null,
+          auditorKey: auditorKeyPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -1,7 +1,7 @@
 import { useState, useEffect } from 'react'
 import { useWallet, useAnchorWallet } from '@solana/wallet-adapter-react'
 import { motion, AnimatePresence } from 'framer-motion'
-import { SystemProgram, PublicKey, Transaction } from '@solana/web3.js'
+import { SystemProgram, PublicKey, Transaction, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js'
 import {
   TOKEN_2022_PROGRAM_ID,
   createAssociatedTokenAccountInstruction,
@@ -18,6 +18,15 @@ import {
   getDarkPoolOrderPda,
   getLendingPositionPda,
   getBridgeRequestPda,
+  getChainConfigPda,
+  getBridgeStatsPda,
+  getRelayerKeyPda,
+  getBridgeEscrowPda,
+  getAdapterRegistryPda,
+  getScreeningPda,
+  getAuditorKeyPda,
+  getComplianceAccounts,
+  getMissingProofReason,
   getSolscanUrl,
 } from '../lib/utils'
 import { arcium, starpay, anoncoin, privacyCash, silentSwap, shadowWire, generateDepositCommitments, generateWithdrawalProofs } from '../lib/sdkIntegrations'
@@ -143,6 +152,23 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
       const program = getProgram(provider)
       const [vaultConfigPda] = getVaultConfigPda()
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
+      const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
+      const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+        vaultConfigPda,
+        publicKey,
+        vaultConfig.complianceRequired
+      )
+      const [auditorKeyPda] = getAuditorKeyPda(vaultConfigPda)
+      const screeningPda = vaultConfig.complianceRequired ? getScreeningPda(vaultConfigPda)[0] : null
+
+      if (parsedIntent.action === 'deposit' || parsedIntent.action === 'withdraw') {
+        const missingProof = await getMissingProofReason(
+          provider.connection,
+          vaultConfigPda,
+          vaultConfig.complianceRequired
+        )
+        if (missingProof) throw new Error(missingProof)
+      }
 
       let txSignature: string | undefined
       let outcome = ''
@@ -151,12 +177,15 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
         case 'swap': {
           const [darkPoolOrderPda] = getDarkPoolOrderPda(vaultConfigPda, publicKey)
           const [sourceVault] = getShieldedVaultPda(vaultConfig.shieldedMint)
+          const [destVault] = getShieldedVaultPda(vaultConfig.secondaryMint)
 
+          const swapAmount = new BN((parsedIntent.amount || 0.01) * 1e9)
+          const slippageBps = parsedIntent.preferences.maxSlippage ? parsedIntent.preferences.maxSlippage * 100 : 100
           const { swapProof, amountInCommitment, minOutCommitment } = await starpay.generateSwapProof({
             inputMint: vaultConfig.shieldedMint,
             outputMint: vaultConfig.secondaryMint,
-            amount: new BN((parsedIntent.amount || 0.01) * 1e9),
-            slippageBps: parsedIntent.preferences.maxSlippage ? parsedIntent.preferences.maxSlippage * 100 : 100,
+            amount: swapAmount,
+            slippageBps,
           })
 
           txSignature = await program.methods
@@ -165,10 +194,11 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
               route: { starpay: {} },
               amountInCommitment,
               minOutCommitment,
+              amountIn: swapAmount,
+              minAmountOut: swapAmount.muln(10000 - slippageBps).divn(10000),
               limitPriceCommitment: null,
               side: { buy: {} },
               swapProof,
-              maxSlippageBps: 100,
             })
             .accountsStrict({
               user: publicKey,
@@ -178,8 +208,13 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
               sourceMint: vaultConfig.shieldedMint,
               destMint: vaultConfig.secondaryMint,
               sourceVault,
+              destVault,
+              adapterRegistry: adapterRegistryPda,
               starpayProgram: STARPAY_PROGRAM_ID,
               anoncoinProgram: ANONCOIN_PROGRAM_ID,
+              complianceAttestation,
+              compliancePolicy,
+              jurisdictions,
               token2022Program: TOKEN_2022_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
             })
@@ -212,7 +247,11 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
               lendingPosition: lendingPositionPda,
               shieldedVaultAta: lendingVault,
               shieldedMint: vaultConfig.shieldedMint,
+              adapterRegistry: adapterRegistryPda,
               privacyCashProgram: PRIVACY_CASH_PROGRAM_ID,
+              complianceAttestation,
+              compliancePolicy,
+              jurisdictions,
               token2022Program: TOKEN_2022_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
             })
@@ -224,7 +263,12 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
         }
 
         case 'bridge': {
-          const [bridgeRequestPda] = getBridgeRequestPda(vaultConfigPda, publicKey)
+          const recipient = parsedIntent.preferences.recipient
+          if (!recipient) throw new Error('Bridge intents need a 0x destination address')
+
+          const position = await program.account.userEncryptedPosition.fetch(userPositionPda)
+          const requestNonce = position.bridgeNonce
+          const [bridgeRequestPda] = getBridgeRequestPda(vaultConfigPda, publicKey, requestNonce)
           const [shieldedVaultAta] = getShieldedVaultPda(vaultConfig.shieldedMint)
 
           const targetChain = parsedIntent.preferences.targetChain || 'ethereum'
@@ -233,62 +277,69 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
           }
           const destChainId = chainIdMap[targetChain] || 1
 
+          const [chainConfigPda] = getChainConfigPda(vaultConfigPda, destChainId)
+          const [bridgeStatsPda] = getBridgeStatsPda(vaultConfigPda)
+          const [relayerKeysPda] = getRelayerKeyPda(vaultConfigPda)
+          const [bridgeEscrowPda] = getBridgeEscrowPda(vaultConfig.shieldedMint)
+          const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
+          const userTokenAccount = getAssociatedTokenAddressSync(
+            vaultConfig.shieldedMint,
+            publicKey,
+            false,
+            TOKEN_2022_PROGRAM_ID
+          )
+          const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+            vaultConfigPda,
+            publicKey,
+            vaultConfig.complianceRequired
+          )
+
           const bridgeAmount = new BN((parsedIntent.amount || 0.01) * 1e9)
           const { bridgeProof, commitment } = await silentSwap.initiateBridge({
             sourceChain: 'solana',
             destChain: destChainId,
             amount: bridgeAmount,
-            recipient: publicKey.toBase58(),
+            recipient,
           })
 
-          toast.loading('Submitting to SilentSwap...', { id: toastId })
+          const relayerKeys = await program.account.relayerKeyRegistry.fetch(relayerKeysPda)
+          const encryptedRecipient = silentSwap.sealRecipient(relayerKeys, recipient)
 
-          await program.methods
-            .privateBridge({
-              action: { initiateOutbound: {} },
-              destChain: { [targetChain]: {} },
-              amountCommitment: commitment,
-              bridgeProof,
-              inboundProof: null,
-            })
-            .accountsStrict({
-              user: publicKey,
-              vaultConfig: vaultConfigPda,
-              userPosition: userPositionPda,
-              bridgeRequest: bridgeRequestPda,
-              shieldedMint: vaultConfig.shieldedMint,
-              shieldedVaultAta,
-              silentswapProgram: SILENTSWAP_PROGRAM_ID,
-              token2022Program: TOKEN_2022_PROGRAM_ID,
-              systemProgram: SystemProgram.programId,
-            })
-            .rpc({ commitment: 'confirmed' })
-
-          toast.loading('Waiting for bridge confirmation...', { id: toastId })
-          await new Promise((resolve) => setTimeout(resolve, 3000))
+          toast.loading('Submitting to SilentSwap...', { id: toastId })
 
           txSignature = await program.methods
             .privateBridge({
-              action: { verifyCompletion: {} },
-              destChain: { [targetChain]: {} },
+              action: { initiateOutbound: {} },
+              destChainId: new BN(destChainId),
+              requestNonce,
+              amount: bridgeAmount,
               amountCommitment: commitment,
+              encryptedRecipient,
               bridgeProof,
-              inboundProof: null,
             })
             .accountsStrict({
               user: publicKey,
               vaultConfig: vaultConfigPda,
               userPosition: userPositionPda,
               bridgeRequest: bridgeRequestPda,
+              chainConfig: chainConfigPda,
+              bridgeStats: bridgeStatsPda,
+              relayerKeys: relayerKeysPda,
               shieldedMint: vaultConfig.shieldedMint,
+              userTokenAccount,
               shieldedVaultAta,
+              bridgeEscrow: bridgeEscrowPda,
+              adapterRegistry: adapterRegistryPda,
               silentswapProgram: SILENTSWAP_PROGRAM_ID,
+              complianceAttestation,
+              compliancePolicy,
+              jurisdictions,
               token2022Program: TOKEN_2022_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
             })
             .rpc({ commitment: 'confirmed' })
 
-          outcome = `Bridged ${parsedIntent.amount || '~'} ${parsedIntent.inputAsset || 'SOL'} to ${targetChain} via ${route}`
+          outcome = `Submitted ${parsedIntent.amount || '~'} ${parsedIntent.inputAsset || 'SOL'} bridge to ${targetChain} via ${route}, pending relayer settlement`
           break
         }
 
@@ -346,6 +397,8 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
               amount: depositAmount,
               amountCommitment,
               blindingFactor,
+              screeningProof: null,
+              auditorDisclosure: null,
             })
             .accountsStrict({
               user: publicKey,
@@ -354,7 +407,12 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
               userTokenAccount: ata,
               shieldedVaultAta,
               shieldedMint: vaultConfig.shieldedMint,
-              complianceAttestation: null,
+              complianceAttestation,
+              compliancePolicy,
+              jurisdictions,
+              screening: screeningPda,
+              auditorKey: auditorKeyPda,
+              instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
               token2022Program: TOKEN_2022_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
             })
@@ -389,6 +447,8 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
               ownershipProof,
               nullifier,
               expectedAmount: withdrawAmount,
+              screeningProof: null,
+              auditorDisclosure: null,
             })
             .accountsStrict({
               user: publicKey,
@@ -397,7 +457,12 @@ export function PrivateIntentRouter({ vaultConfig, isVisible, onClose, onSuccess
               userTokenAccount: userAta,
               shieldedVaultAta: shieldedVaultAtaW,
               shieldedMint: vaultConfig.shieldedMint,
-              complianceAttestation: null,
+              complianceAttestation,
+              compliancePolicy,
+              jurisdictions,
+              screening: screeningPda,
+              auditorKey: auditorKeyPda,
+              instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
               token2022Program: TOKEN_2022_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
             })
@@ -11,6 +11,8 @@ import {
   getUserPositionPda,
   getShieldedVaultPda,
   getLendingPositionPda,
+  getAdapterRegistryPda,
+  getComplianceAccounts,
   getSolscanUrl,
 } from '../lib/utils'
 import { privacyCash } from '../lib/sdkIntegrations'
@@ -58,6 +60,12 @@ export function PrivateLendForm({
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
       const [lendingPositionPda] = getLendingPositionPda(vaultConfigPda, publicKey)
       const [shieldedVaultAta] = getShieldedVaultPda(vaultConfig.shieldedMint)
+      const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
+      const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+        vaultConfigPda,
+        publicKey,
+        vaultConfig.complianceRequired
+      )
 
       let actionParam
       let interestRateBps = 0
@@ -104,7 +112,11 @@ export function PrivateLendForm({
           lendingPosition: lendingPositionPda,
           shieldedVaultAta,
           shieldedMint: vaultConfig.shieldedMint,
+          adapterRegistry: adapterRegistryPda,
           privacyCashProgram: PRIVACY_CASH_PROGRAM_ID,
+          complianceAttestation,
+          compliancePolicy,
+          jurisdictions,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
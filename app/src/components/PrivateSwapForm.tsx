@@ -3,6 +3,7 @@ import { useWallet, useAnchorWallet } from '@solana/wallet-adapter-react'
 import { motion } from 'framer-motion'
 import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token'
 import { SystemProgram, PublicKey } from '@solana/web3.js'
+import { BN } from '@coral-xyz/anchor'
 import toast from 'react-hot-toast'
 import { getProvider, getProgram } from '../lib/anchor'
 import {
@@ -11,11 +12,13 @@ import {
   getUserPositionPda,
   getShieldedVaultPda,
   getDarkPoolOrderPda,
+  getAdapterRegistryPda,
+  getComplianceAccounts,
   getSolscanUrl,
 } from '../lib/utils'
 import { starpay, anoncoin } from '../lib/sdkIntegrations'
 import { LoadingSpinner } from './LoadingSpinner'
-import { VaultConfig } from '../types'
+import { VaultConfig, TimeInForce } from '../types'
 
 interface PrivateSwapFormProps {
   vaultConfig: VaultConfig | null
@@ -26,6 +29,14 @@ interface PrivateSwapFormProps {
 const STARPAY_PROGRAM_ID = new PublicKey('STARpay111111111111111111111111111111111111')
 const ANONCOIN_PROGRAM_ID = new PublicKey('ANCn111111111111111111111111111111111111111')
 
+type TimeInForceOption = 'goodTilCancelled' | 'immediateOrCancel' | 'fillOrKill'
+
+const TIME_IN_FORCE_OPTIONS: { value: TimeInForceOption; label: string }[] = [
+  { value: 'goodTilCancelled', label: 'Good til cancelled' },
+  { value: 'immediateOrCancel', label: 'Immediate or cancel' },
+  { value: 'fillOrKill', label: 'Fill or kill' },
+]
+
 export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwapFormProps) {
   const { publicKey } = useWallet()
   const wallet = useAnchorWallet()
@@ -35,6 +46,7 @@ export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwap
   const [route, setRoute] = useState<'starpay' | 'darkpool'>('starpay')
   const [orderSide, setOrderSide] = useState<'buy' | 'sell'>('buy')
   const [limitPrice, setLimitPrice] = useState('')
+  const [timeInForce, setTimeInForce] = useState<TimeInForceOption>('goodTilCancelled')
   const [loading, setLoading] = useState(false)
 
   const handleSwap = async () => {
@@ -60,11 +72,19 @@ export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwap
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
       const [darkPoolOrderPda] = getDarkPoolOrderPda(vaultConfigPda, publicKey)
       const [sourceVault] = getShieldedVaultPda(vaultConfig.shieldedMint)
+      const [destVault] = getShieldedVaultPda(vaultConfig.secondaryMint)
+      const [adapterRegistryPda] = getAdapterRegistryPda(vaultConfigPda)
+      const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+        vaultConfigPda,
+        publicKey,
+        vaultConfig.complianceRequired
+      )
 
       let swapProof: number[]
       let amountInCommitment: number[]
       let minOutCommitment: number[]
       let limitPriceCommitment: number[] | null = null
+      let minOut: BN
 
       if (route === 'starpay') {
         const result = await starpay.generateSwapProof({
@@ -76,8 +96,11 @@ export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwap
         swapProof = result.swapProof
         amountInCommitment = result.amountInCommitment
         minOutCommitment = result.minOutCommitment
+        minOut = parseAmount(minAmountOut)
       } else {
         const price = parseAmount(limitPrice)
+        // Limit price is quoted per whole token
+        minOut = amount.mul(price).div(new BN(1e9))
         const result = anoncoin.createDarkPoolOrder({
           side: orderSide,
           amount,
@@ -92,8 +115,9 @@ export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwap
       toast.loading('Executing private swap...', { id: toastId })
 
       const routeParam = route === 'starpay' ? { starpay: {} } : { anocoinDarkPool: {} }
+      const timeInForceParam = { [timeInForce]: {} } as TimeInForce
       const actionParam =
-        route === 'starpay' ? { execute: {} } : { placeLimitOrder: {} }
+        route === 'starpay' ? { execute: {} } : { placeLimitOrder: { timeInForce: timeInForceParam } }
       const sideParam = orderSide === 'buy' ? { buy: {} } : { sell: {} }
 
       const tx = await program.methods
@@ -102,10 +126,11 @@ export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwap
           route: routeParam,
           amountInCommitment,
           minOutCommitment,
+          amountIn: amount,
+          minAmountOut: minOut,
           limitPriceCommitment,
           side: sideParam,
           swapProof,
-          maxSlippageBps: parseInt(slippage) * 100,
         })
         .accountsStrict({
           user: publicKey,
@@ -115,8 +140,13 @@ export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwap
           sourceMint: vaultConfig.shieldedMint,
           destMint: vaultConfig.secondaryMint,
           sourceVault,
+          destVault,
+          adapterRegistry: adapterRegistryPda,
           starpayProgram: STARPAY_PROGRAM_ID,
           anoncoinProgram: ANONCOIN_PROGRAM_ID,
+          complianceAttestation,
+          compliancePolicy,
+          jurisdictions,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -270,6 +300,23 @@ export function PrivateSwapForm({ vaultConfig, onSuccess, onClose }: PrivateSwap
                 disabled={loading}
               />
             </div>
+            <div>
+              <label className="block text-sm font-medium text-text-secondary mb-2">
+                Time in Force
+              </label>
+              <select
+                value={timeInForce}
+                onChange={(e) => setTimeInForce(e.target.value as TimeInForceOption)}
+                className="input-field"
+                disabled={loading}
+              >
+                {TIME_IN_FORCE_OPTIONS.map((option) => (
+                  <option key={option.value} value={option.value}>
+                    {option.label}
+                  </option>
+                ))}
+              </select>
+            </div>
           </>
         )}
 
@@ -3,10 +3,20 @@ import { useWallet, useAnchorWallet } from '@solana/wallet-adapter-react'
 import { motion } from 'framer-motion'
 import { BN } from '@coral-xyz/anchor'
 import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token'
-import { SystemProgram, PublicKey } from '@solana/web3.js'
+import { SystemProgram, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from '@solana/web3.js'
 import toast from 'react-hot-toast'
 import { getProvider, getProgram } from '../lib/anchor'
-import { parseAmount, getVaultConfigPda, getUserPositionPda, getShieldedVaultPda, getSolscanUrl } from '../lib/utils'
+import {
+  parseAmount,
+  getVaultConfigPda,
+  getUserPositionPda,
+  getShieldedVaultPda,
+  getScreeningPda,
+  getAuditorKeyPda,
+  getComplianceAccounts,
+  getMissingProofReason,
+  getSolscanUrl,
+} from '../lib/utils'
 import { generateWithdrawalProofs } from '../lib/sdkIntegrations'
 import { LoadingSpinner } from './LoadingSpinner'
 import { VaultConfig } from '../types'
@@ -53,6 +63,13 @@ export function PrivateWithdrawForm({
       const [userPositionPda] = getUserPositionPda(vaultConfigPda, publicKey)
       const [shieldedVaultAta] = getShieldedVaultPda(vaultConfig.shieldedMint)
 
+      const missingProof = await getMissingProofReason(
+        provider.connection,
+        vaultConfigPda,
+        vaultConfig.complianceRequired
+      )
+      if (missingProof) throw new Error(missingProof)
+
       const { withdrawalProof, ownershipProof, nullifier } = generateWithdrawalProofs(
         withdrawAmount,
         userPositionPda
@@ -67,6 +84,13 @@ export function PrivateWithdrawForm({
           ? { yieldOnly: {} }
           : { partial: {} }
 
+      const [auditorKeyPda] = getAuditorKeyPda(vaultConfigPda)
+      const { complianceAttestation, compliancePolicy, jurisdictions } = getComplianceAccounts(
+        vaultConfigPda,
+        publicKey,
+        vaultConfig.complianceRequired
+      )
+
       const tx = await program.methods
         .privateWithdraw({
           withdrawType: withdrawTypeParam,
@@ -74,6 +98,8 @@ export function PrivateWithdrawForm({
           ownershipProof,
           nullifier,
           expectedAmount: withdrawAmount,
+          screeningProof: null,
+          auditorDisclosure: null,
         })
         .accountsStrict({
           user: publicKey,
@@ -82,7 +108,12 @@ export function PrivateWithdrawForm({
           userTokenAccount,
           shieldedVaultAta,
           shieldedMint: vaultConfig.shieldedMint,
-          complianceAttestation: null,
+          complianceAttestation,
+          compliancePolicy,
+          jurisdictions,
+          screening: vaultConfig.complianceRequired ? getScreeningPda(vaultConfigPda)[0] : null,
+          auditorKey: auditorKeyPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
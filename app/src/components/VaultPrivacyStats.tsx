@@ -1,5 +1,5 @@
 import { VaultConfig } from '../types'
-import { formatAmount, formatBps } from '../lib/utils'
+import { formatAmount, formatBps, getPausedOperations } from '../lib/utils'
 import { LoadingSpinner } from './LoadingSpinner'
 
 interface VaultPrivacyStatsProps {
@@ -26,6 +26,8 @@ export function VaultPrivacyStats({ vaultConfig, loading }: VaultPrivacyStatsPro
     )
   }
 
+  const pausedOperations = getPausedOperations(vaultConfig.pausedOperations)
+
   const stats = [
     {
       label: 'Total Shielded TVL',
@@ -121,13 +123,17 @@ export function VaultPrivacyStats({ vaultConfig, loading }: VaultPrivacyStatsPro
           </div>
         </div>
 
-        {vaultConfig.isPaused && (
+        {(vaultConfig.emergencyMode || pausedOperations.length > 0) && (
           <div className="mt-2 p-3 bg-danger/10 border border-danger/20 rounded-lg">
             <div className="flex items-center gap-2 text-danger text-sm">
               <svg className="w-4 h-4" viewBox="0 0 24 24" fill="currentColor">
                 <path d="M12 2C6.48 2 2 6.48 2 12s4.48 10 10 10 10-4.48 10-10S17.52 2 12 2zm1 15h-2v-2h2v2zm0-4h-2V7h2v6z" />
               </svg>
-              <span>Vault is paused</span>
+              <span>
+                {vaultConfig.emergencyMode
+                  ? 'Emergency mode: all operations halted'
+                  : `Paused: ${pausedOperations.join(', ')}`}
+              </span>
             </div>
           </div>
         )}
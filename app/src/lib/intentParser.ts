@@ -9,6 +9,7 @@ export interface ParsedIntent {
     minApy?: number
     maxSlippage?: number
     targetChain?: string
+    recipient?: string
     compliance?: boolean
     urgency?: 'low' | 'normal' | 'high'
   }
@@ -108,6 +109,11 @@ function parseWithRules(intentText: string): ParsedIntent {
     result.preferences.targetChain = 'arbitrum'
   }
 
+  const recipientMatch = text.match(/\b0x[0-9a-f]{40}\b/)
+  if (recipientMatch) {
+    result.preferences.recipient = recipientMatch[0]
+  }
+
   if (text.includes('compliant') || text.includes('kyc') || text.includes('regulated')) {
     result.preferences.compliance = true
   }
@@ -1,5 +1,9 @@
 import { PublicKey } from '@solana/web3.js'
 import { BN } from '@coral-xyz/anchor'
+import { x25519 } from '@noble/curves/ed25519'
+import { xchacha20poly1305 } from '@noble/ciphers/chacha'
+import { randomBytes } from '@noble/ciphers/webcrypto'
+import { EncryptedRecipient, ProviderAttestation, ComplianceTier } from '../types'
 
 const HELIUS_API_KEY = import.meta.env.VITE_HELIUS_API_KEY || ''
 
@@ -221,6 +225,33 @@ export class SilentSwapIntegration {
       commitment: Array.from(commitment),
     }
   }
+
+  // Seals a 0x-prefixed EVM recipient to the relayer's X25519 key with XChaCha20-Poly1305,
+  // keyed by the shared secret of a fresh ephemeral key; addresses are zero-padded to 32 bytes
+  sealRecipient(
+    relayer: { encryptionPubkey: number[]; keyVersion: number },
+    recipient: string
+  ): EncryptedRecipient {
+    const hex = recipient.replace(/^0x/i, '')
+    if (!/^[0-9a-fA-F]{40}$/.test(hex)) {
+      throw new Error('Recipient must be a 20-byte hex address')
+    }
+    const padded = new Uint8Array(32)
+    padded.set(Uint8Array.from(Buffer.from(hex, 'hex')))
+
+    const ephemeralSecret = x25519.utils.randomPrivateKey()
+    const sharedSecret = x25519.getSharedSecret(ephemeralSecret, Uint8Array.from(relayer.encryptionPubkey))
+    const nonce = randomBytes(24)
+    const sealed = xchacha20poly1305(sharedSecret, nonce).encrypt(padded)
+
+    return {
+      keyVersion: relayer.keyVersion,
+      ephemeralPubkey: Array.from(x25519.getPublicKey(ephemeralSecret)),
+      nonce: Array.from(nonce),
+      ciphertext: Array.from(sealed.slice(0, 32)),
+      tag: Array.from(sealed.slice(32)),
+    }
+  }
 }
 
 export class HeliusIntegration {
@@ -403,7 +434,63 @@ export class PrivacyCashIntegration {
   }
 }
 
+const COMPLIANCE_ATTESTATION_DOMAIN = Buffer.from('shadowforge:compliance_attestation:v2')
+const COMPLIANCE_TIERS = ['basic', 'verified', 'institutional'] as const
+
 export class RangeComplianceIntegration {
+  private baseUrl = 'https://api.range.org/v1'
+
+  // The program only accepts results an approved provider signed, so there is no local fallback
+  async requestProviderAttestation(params: {
+    userAddress: PublicKey
+    jurisdiction: string
+  }): Promise<{ attestation: ProviderAttestation; signature: Uint8Array }> {
+    const response = await fetch(`${this.baseUrl}/attestations`, {
+      method: 'POST',
+      headers: { 'Content-Type': 'application/json' },
+      body: JSON.stringify({
+        address: params.userAddress.toBase58(),
+        jurisdiction: params.jurisdiction,
+      }),
+    })
+
+    if (!response.ok) {
+      throw new Error('Range attestation API unavailable')
+    }
+
+    const data = await response.json()
+    const tier = data.tier as (typeof COMPLIANCE_TIERS)[number]
+    if (!COMPLIANCE_TIERS.includes(tier)) {
+      throw new Error(`Unknown compliance tier: ${data.tier}`)
+    }
+
+    return {
+      attestation: {
+        provider: new PublicKey(data.provider),
+        issuedAt: new BN(data.issuedAt),
+        riskScore: data.riskScore,
+        tier: { [tier]: {} } as ComplianceTier,
+        expiresAt: new BN(data.expiresAt),
+        jurisdiction: Array.from(Buffer.from(data.jurisdiction, 'ascii')),
+      },
+      signature: Uint8Array.from(Buffer.from(data.signature, 'base64')),
+    }
+  }
+
+  // Mirrors ProviderAttestation::message:
+  // domain tag || user || issued_at (LE) || risk score || tier || expires_at (LE) || jurisdiction
+  attestationMessage(attestation: ProviderAttestation, user: PublicKey): Uint8Array {
+    const tier = COMPLIANCE_TIERS.findIndex((t) => t in attestation.tier)
+    return Buffer.concat([
+      COMPLIANCE_ATTESTATION_DOMAIN,
+      user.toBuffer(),
+      attestation.issuedAt.toArrayLike(Buffer, 'le', 8),
+      Buffer.from([attestation.riskScore, tier]),
+      attestation.expiresAt.toArrayLike(Buffer, 'le', 8),
+      Buffer.from(attestation.jurisdiction),
+    ])
+  }
+
   generateComplianceAttestation(_params: {
     userAddress: PublicKey
     jurisdiction: string
@@ -1,4 +1,4 @@
-import { PublicKey } from '@solana/web3.js'
+import { Connection, PublicKey } from '@solana/web3.js'
 import { BN } from '@coral-xyz/anchor'
 
 export const PROGRAM_ID = new PublicKey('Brejex6T6bCkvVko2qCSW7LGK93anqEWoiuYs5pfu9oA')
@@ -10,6 +10,29 @@ export const COMPLIANCE_SEED = Buffer.from('compliance')
 export const LENDING_POSITION_SEED = Buffer.from('lending_position')
 export const BRIDGE_REQUEST_SEED = Buffer.from('bridge_request')
 export const DARK_POOL_ORDER_SEED = Buffer.from('dark_pool_order')
+export const ADAPTER_REGISTRY_SEED = Buffer.from('adapter_registry')
+export const BRIDGE_ESCROW_SEED = Buffer.from('bridge_escrow')
+export const CHAIN_CONFIG_SEED = Buffer.from('chain_config')
+export const BRIDGE_STATS_SEED = Buffer.from('bridge_stats')
+export const RELAYER_KEY_SEED = Buffer.from('relayer_key')
+export const COMPLIANCE_PROVIDERS_SEED = Buffer.from('compliance_providers')
+export const COMPLIANCE_POLICY_SEED = Buffer.from('compliance_policy')
+export const SCREENING_SEED = Buffer.from('screening')
+export const AUDITOR_KEY_SEED = Buffer.from('auditor_key')
+export const JURISDICTION_REGISTRY_SEED = Buffer.from('jurisdictions')
+
+export const MAX_PENDING_BRIDGE_REQUESTS = 8
+
+// Order of the program's PausableOperation enum; each bit sits one above its index
+export const PAUSABLE_OPERATIONS = ['deposit', 'withdraw', 'lend', 'swap', 'bridge', 'compliance', 'wrap'] as const
+
+export type PausableOperation = (typeof PAUSABLE_OPERATIONS)[number]
+
+export function getPausedOperations(pausedOperations: number): PausableOperation[] {
+  // Bit 0 is the legacy whole-vault pause flag
+  if (pausedOperations & 1) return [...PAUSABLE_OPERATIONS]
+  return PAUSABLE_OPERATIONS.filter((_, bit) => (pausedOperations & (1 << (bit + 1))) !== 0)
+}
 
 export function getVaultConfigPda(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync([VAULT_CONFIG_SEED], PROGRAM_ID)
@@ -43,9 +66,9 @@ export function getLendingPositionPda(vaultConfig: PublicKey, user: PublicKey):
   )
 }
 
-export function getBridgeRequestPda(vaultConfig: PublicKey, user: PublicKey): [PublicKey, number] {
+export function getBridgeRequestPda(vaultConfig: PublicKey, user: PublicKey, nonce: BN): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [BRIDGE_REQUEST_SEED, vaultConfig.toBuffer(), user.toBuffer()],
+    [BRIDGE_REQUEST_SEED, vaultConfig.toBuffer(), user.toBuffer(), nonce.toArrayLike(Buffer, 'le', 8)],
     PROGRAM_ID
   )
 }
@@ -57,6 +80,90 @@ export function getDarkPoolOrderPda(vaultConfig: PublicKey, user: PublicKey): [P
   )
 }
 
+function getVaultScopedPda(seed: Buffer, vaultConfig: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([seed, vaultConfig.toBuffer()], PROGRAM_ID)
+}
+
+export function getAdapterRegistryPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(ADAPTER_REGISTRY_SEED, vaultConfig)
+}
+
+export function getBridgeStatsPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(BRIDGE_STATS_SEED, vaultConfig)
+}
+
+export function getRelayerKeyPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(RELAYER_KEY_SEED, vaultConfig)
+}
+
+export function getComplianceProvidersPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(COMPLIANCE_PROVIDERS_SEED, vaultConfig)
+}
+
+export function getCompliancePolicyPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(COMPLIANCE_POLICY_SEED, vaultConfig)
+}
+
+export function getScreeningPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(SCREENING_SEED, vaultConfig)
+}
+
+export function getAuditorKeyPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(AUDITOR_KEY_SEED, vaultConfig)
+}
+
+export function getJurisdictionsPda(vaultConfig: PublicKey): [PublicKey, number] {
+  return getVaultScopedPda(JURISDICTION_REGISTRY_SEED, vaultConfig)
+}
+
+export function getChainConfigPda(vaultConfig: PublicKey, chainId: number): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [CHAIN_CONFIG_SEED, vaultConfig.toBuffer(), new BN(chainId).toArrayLike(Buffer, 'le', 8)],
+    PROGRAM_ID
+  )
+}
+
+export function getBridgeEscrowPda(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([BRIDGE_ESCROW_SEED, mint.toBuffer()], PROGRAM_ID)
+}
+
+// The program only reads these when the vault requires compliance
+export function getComplianceAccounts(
+  vaultConfig: PublicKey,
+  user: PublicKey,
+  complianceRequired: boolean
+): {
+  complianceAttestation: PublicKey | null
+  compliancePolicy: PublicKey | null
+  jurisdictions: PublicKey | null
+} {
+  if (!complianceRequired) {
+    return { complianceAttestation: null, compliancePolicy: null, jurisdictions: null }
+  }
+  return {
+    complianceAttestation: getCompliancePda(vaultConfig, user)[0],
+    compliancePolicy: getCompliancePolicyPda(vaultConfig)[0],
+    jurisdictions: getJurisdictionsPda(vaultConfig)[0],
+  }
+}
+
+// Deposits and withdrawals can need proofs this app cannot build yet; returns why the program
+// would reject one so the flow stops before any funds move
+export async function getMissingProofReason(
+  connection: Connection,
+  vaultConfig: PublicKey,
+  complianceRequired: boolean
+): Promise<string | null> {
+  if (complianceRequired) {
+    return 'This vault screens deposits and withdrawals against a sanctions denylist, and this app cannot build the non-membership proof yet'
+  }
+  // The program requires an auditor disclosure once a key is registered
+  if (await connection.getAccountInfo(getAuditorKeyPda(vaultConfig)[0])) {
+    return 'This vault has a registered auditor, and this app cannot build the auditor disclosure proofs yet'
+  }
+  return null
+}
+
 export function formatAmount(amount: BN | number | string, decimals: number = 9): string {
   const value = typeof amount === 'string' ? parseFloat(amount) :
                 BN.isBN(amount) ? amount.toNumber() : amount
@@ -14,7 +14,7 @@ export interface VaultConfig {
   swapFeeBps: number
   bridgeFeeBps: number
   currentYieldBps: number
-  isPaused: boolean
+  pausedOperations: number
   emergencyMode: boolean
   complianceRequired: boolean
   arciumEnabled: boolean
@@ -42,6 +42,8 @@ export interface UserEncryptedPosition {
   lastActionAt: BN
   createdAt: BN
   bump: number
+  pendingBridgeCount: number
+  bridgeNonce: BN
 }
 
 export interface EncryptedCiphertext {
@@ -60,17 +62,31 @@ export interface LendingPosition {
   bump: number
 }
 
+export interface EncryptedRecipient {
+  keyVersion: number
+  ephemeralPubkey: number[]
+  nonce: number[]
+  ciphertext: number[]
+  tag: number[]
+}
+
 export interface BridgeRequest {
   user: PublicKey
   destChainId: BN
+  nonce: BN
   amountCommitment: number[]
+  encryptedRecipient: EncryptedRecipient
+  escrowedAmount: BN
+  feeAmount: BN
   status: BridgeStatus
   createdAt: BN
+  expiresAt: BN
   bump: number
 }
 
 export type BridgeStatus =
   | { pending: Record<string, never> }
+  | { confirmed: Record<string, never> }
   | { completed: Record<string, never> }
   | { failed: Record<string, never> }
 
@@ -97,13 +113,31 @@ export type OrderStatus =
 
 export interface ComplianceAttestation {
   user: PublicKey
-  vaultConfig: PublicKey
+  provider: PublicKey
   attestationHash: number[]
+  attestedAt: BN
+  expiresAt: BN
   riskScore: number
+  tier: ComplianceTier
+  jurisdiction: number[]
   isValid: boolean
-  expiresAt: BN
-  createdAt: BN
   bump: number
+  issuedAt: BN
+  revokedAt: BN
+}
+
+export type ComplianceTier =
+  | { basic: Record<string, never> }
+  | { verified: Record<string, never> }
+  | { institutional: Record<string, never> }
+
+export interface ProviderAttestation {
+  provider: PublicKey
+  issuedAt: BN
+  riskScore: number
+  tier: ComplianceTier
+  expiresAt: BN
+  jurisdiction: number[]
 }
 
 export interface ShieldedAsset {
@@ -123,23 +157,20 @@ export interface PrivacyAction {
   status: 'pending' | 'confirmed' | 'failed'
 }
 
-export type DestinationChain =
-  | { ethereum: Record<string, never> }
-  | { polygon: Record<string, never> }
-  | { arbitrum: Record<string, never> }
-  | { optimism: Record<string, never> }
-  | { base: Record<string, never> }
-  | { avalanche: Record<string, never> }
-  | { bsc: Record<string, never> }
-
 export type SwapRoute =
   | { starpay: Record<string, never> }
   | { anocoinDarkPool: Record<string, never> }
   | { split: { starpayWeightBps: number } }
 
+export type TimeInForce =
+  | { goodTilCancelled: Record<string, never> }
+  | { goodTilTime: { expiresAt: BN } }
+  | { immediateOrCancel: Record<string, never> }
+  | { fillOrKill: Record<string, never> }
+
 export type SwapAction =
   | { execute: Record<string, never> }
-  | { placeLimitOrder: Record<string, never> }
+  | { placeLimitOrder: { timeInForce: TimeInForce } }
   | { cancelOrder: Record<string, never> }
   | { matchDarkPool: Record<string, never> }
 
@@ -151,7 +182,7 @@ export type LendAction =
 export type ComplianceAction =
   | { submit: Record<string, never> }
   | { verify: Record<string, never> }
-  | { revoke: Record<string, never> }
+  | { renew: Record<string, never> }
 
 export type WithdrawType =
   | { partial: Record<string, never> }
@@ -160,6 +191,4 @@ export type WithdrawType =
 
 export type BridgeAction =
   | { initiateOutbound: Record<string, never> }
-  | { claimInbound: Record<string, never> }
   | { cancelRequest: Record<string, never> }
-  | { verifyCompletion: Record<string, never> }
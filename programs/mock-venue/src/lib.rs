@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+declare_id!("GSFchGcRwrwoj4tdJi2evk52DP9bTBRr926P2r19XsJ6");
+
+/// Fee the mock venue keeps on every fill
+pub const VENUE_FEE_BPS: u64 = 30;
+
+pub const ZK_ELGAMAL_PROOF_PROGRAM_ID: Pubkey =
+    pubkey!("ZkE1Gama1Proof11111111111111111111111111111");
+
+/// Mock swap venue for local testing
+///
+/// Implements the venue interface ShadowForge's CPI adapters speak:
+/// `swap(amount_in, min_amount_out) -> amount_out`. The input is moved out of the
+/// source vault and the output paid into the destination vault, since ShadowForge
+/// measures fills from those balances. The same binary is loaded at both the
+/// Starpay and Anoncoin program IDs in tests, and at the ZK ElGamal proof program ID,
+/// where it accepts proof instructions unchecked so tests can place proof contexts.
+#[program]
+pub mod mock_venue {
+    use super::*;
+
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64) -> Result<u64> {
+        require!(amount_in > 0, MockVenueError::InvalidAmount);
+
+        let amount_out = amount_in
+            .checked_mul(10_000 - VENUE_FEE_BPS)
+            .ok_or(MockVenueError::InvalidAmount)?
+            / 10_000;
+        require!(amount_out >= min_amount_out, MockVenueError::SlippageExceeded);
+
+        let token_program = ctx.accounts.token_program.to_account_info();
+        let vault_authority = ctx.accounts.vault_authority.to_account_info();
+
+        transfer_checked(
+            CpiContext::new(
+                token_program.clone(),
+                TransferChecked {
+                    from: ctx.accounts.source_vault.to_account_info(),
+                    mint: ctx.accounts.source_mint.to_account_info(),
+                    to: ctx.accounts.source_reserve.to_account_info(),
+                    authority: vault_authority.clone(),
+                },
+            ),
+            amount_in,
+            ctx.accounts.source_mint.decimals,
+        )?;
+
+        transfer_checked(
+            CpiContext::new(
+                token_program,
+                TransferChecked {
+                    from: ctx.accounts.dest_reserve.to_account_info(),
+                    mint: ctx.accounts.dest_mint.to_account_info(),
+                    to: ctx.accounts.dest_vault.to_account_info(),
+                    authority: vault_authority,
+                },
+            ),
+            amount_out,
+            ctx.accounts.dest_mint.decimals,
+        )?;
+
+        msg!(
+            "Mock venue fill: authority={}, in={}, out={}",
+            ctx.accounts.vault_authority.key(),
+            amount_in,
+            amount_out
+        );
+
+        Ok(amount_out)
+    }
+
+    /// Proof instructions carry no Anchor discriminator; accept them only at the proof program ID
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        _accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        require_keys_eq!(
+            *program_id,
+            ZK_ELGAMAL_PROOF_PROGRAM_ID,
+            MockVenueError::UnknownInstruction
+        );
+        require!(!data.is_empty(), MockVenueError::UnknownInstruction);
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub vault_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub source_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub dest_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub source_mint: InterfaceAccount<'info, Mint>,
+
+    pub dest_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Collects the swap input
+    #[account(mut, token::mint = source_mint)]
+    pub source_reserve: InterfaceAccount<'info, TokenAccount>,
+
+    /// Liquidity the output is paid from; owned by the caller's vault authority so the
+    /// mock needs no signer of its own
+    #[account(mut, token::mint = dest_mint, token::authority = vault_authority)]
+    pub dest_reserve: InterfaceAccount<'info, TokenAccount>,
+}
+
+#[error_code]
+pub enum MockVenueError {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+    #[msg("Unknown instruction")]
+    UnknownInstruction,
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::error::ShadowForgeError;
+
+/// Swap venues reachable through a CPI adapter
+#[derive(Clone, Copy, PartialEq)]
+pub enum Venue {
+    Starpay,
+    Anoncoin,
+}
+
+/// Both venues expose the same Anchor `swap` instruction
+const VENUE_SWAP_IX_NAME: &str = "global:swap";
+
+impl Venue {
+    fn swap_failed(&self) -> ShadowForgeError {
+        match self {
+            Venue::Starpay => ShadowForgeError::StarpaySwapFailed,
+            Venue::Anoncoin => ShadowForgeError::DarkPoolFailed,
+        }
+    }
+}
+
+/// Accounts handed to a venue for a swap leg
+/// The vault authority signs so the venue can pull the input from the source vault;
+/// the venue pays the output into the destination vault
+pub struct VenueSwapAccounts<'info> {
+    pub vault_authority: AccountInfo<'info>,
+    pub source_vault: AccountInfo<'info>,
+    pub dest_vault: AccountInfo<'info>,
+    pub source_mint: AccountInfo<'info>,
+    pub dest_mint: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    /// Venue-specific accounts (pools, order books), forwarded as passed to the instruction
+    pub remaining_accounts: Vec<AccountInfo<'info>>,
+}
+
+/// What a venue actually moved in one swap leg
+pub struct LegFill {
+    /// Taken from the source vault; below the requested amount on a partial fill
+    pub amount_in: u64,
+    /// Paid into the destination vault
+    pub amount_out: u64,
+}
+
+/// Invoke `swap(amount_in, min_amount_out)` on a venue program and return the fill
+/// The fill is measured from the vault balances around the CPI rather than the venue's return data:
+/// the source vault may lose at most `amount_in`, and the output is what the destination vault gained
+pub fn swap<'info>(
+    venue: Venue,
+    venue_program: &AccountInfo<'info>,
+    accounts: &VenueSwapAccounts<'info>,
+    amount_in: u64,
+    min_amount_out: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<LegFill> {
+    let mut data = hash(VENUE_SWAP_IX_NAME.as_bytes()).to_bytes()[..8].to_vec();
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&min_amount_out.to_le_bytes());
+
+    let mut metas = vec![
+        AccountMeta::new_readonly(accounts.vault_authority.key(), true),
+        AccountMeta::new(accounts.source_vault.key(), false),
+        AccountMeta::new(accounts.dest_vault.key(), false),
+        AccountMeta::new_readonly(accounts.source_mint.key(), false),
+        AccountMeta::new_readonly(accounts.dest_mint.key(), false),
+        AccountMeta::new_readonly(accounts.token_program.key(), false),
+    ];
+    let mut infos = vec![
+        accounts.vault_authority.clone(),
+        accounts.source_vault.clone(),
+        accounts.dest_vault.clone(),
+        accounts.source_mint.clone(),
+        accounts.dest_mint.clone(),
+        accounts.token_program.clone(),
+    ];
+    for account in accounts.remaining_accounts.iter() {
+        metas.push(if account.is_writable {
+            AccountMeta::new(account.key(), account.is_signer)
+        } else {
+            AccountMeta::new_readonly(account.key(), account.is_signer)
+        });
+        infos.push(account.clone());
+    }
+    infos.push(venue_program.clone());
+
+    let instruction = Instruction {
+        program_id: venue_program.key(),
+        accounts: metas,
+        data,
+    };
+    let source_before = token_balance(&accounts.source_vault)?;
+    let dest_before = token_balance(&accounts.dest_vault)?;
+
+    invoke_signed(&instruction, &infos, signer_seeds)
+        .map_err(|_| error!(venue.swap_failed()))?;
+
+    let debited = source_before.saturating_sub(token_balance(&accounts.source_vault)?);
+    require!(debited <= amount_in, ShadowForgeError::SwapLegOverdrawn);
+
+    let amount_out = token_balance(&accounts.dest_vault)?
+        .checked_sub(dest_before)
+        .ok_or(ShadowForgeError::SdkResponseInvalid)?;
+
+    Ok(LegFill { amount_in: debited, amount_out })
+}
+
+fn token_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    Ok(TokenAccount::try_deserialize(&mut &data[..])?.amount)
+}
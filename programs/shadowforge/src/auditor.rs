@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+use crate::zk_proofs::{
+    proof_contexts, proves_ciphertext_amount, BATCHED_RANGE_CONTEXT_LEN,
+    CIPHERTEXT_EQUALITY_CONTEXT_LEN, CIPHERTEXT_EQUALITY_PROOF_LEN, RANGE_PROOF_U64_LEN,
+    VERIFY_BATCHED_RANGE_PROOF_U64, VERIFY_CIPHERTEXT_CIPHERTEXT_EQUALITY,
+};
+
+/// The flow amount encrypted twice: under the user's key and under the vault auditor's key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuditorDisclosure {
+    /// User ElGamal key `amount_ciphertext` is encrypted under
+    pub elgamal_pubkey: [u8; 32],
+    pub amount_ciphertext: [u8; ELGAMAL_CIPHERTEXT_LEN],
+    pub auditor_ciphertext: [u8; ELGAMAL_CIPHERTEXT_LEN],
+}
+
+/// The vault's registered auditor key, or None while no auditor is registered
+pub fn load_auditor_key(account: &AccountInfo) -> Result<Option<AuditorKey>> {
+    if account.data_is_empty() {
+        return Ok(None);
+    }
+    require_keys_eq!(*account.owner, crate::ID, ShadowForgeError::SelectiveDisclosureFailed);
+
+    let data = account.try_borrow_data()?;
+    Ok(Some(AuditorKey::try_deserialize(&mut &data[..])?))
+}
+
+/// Require earlier ZK ElGamal proof instructions in this transaction showing that both
+/// ciphertexts of `disclosure` encrypt `amount`, the second under the auditor key:
+/// a zero-ciphertext proof that the user ciphertext minus `amount` encrypts 0, a 64-bit range
+/// proof on its commitment, and an equality proof between the user and auditor ciphertexts
+pub fn verify_auditor_disclosure(
+    instructions_sysvar: &AccountInfo,
+    auditor_key: &AuditorKey,
+    disclosure: &AuditorDisclosure,
+    amount: u64,
+) -> Result<()> {
+    let opens_to_amount = proves_ciphertext_amount(
+        instructions_sysvar,
+        &disclosure.elgamal_pubkey,
+        &disclosure.amount_ciphertext,
+        amount,
+    )?;
+
+    // Context layout: eight commitments, then their bit lengths
+    let in_range = proof_contexts(
+        instructions_sysvar,
+        VERIFY_BATCHED_RANGE_PROOF_U64,
+        BATCHED_RANGE_CONTEXT_LEN,
+        RANGE_PROOF_U64_LEN,
+    )?
+    .iter()
+    .any(|context| {
+        context[..32] == disclosure.amount_ciphertext[..32] && context[32 * 8] == 64
+    });
+
+    // Context layout: first pubkey, second pubkey, first ciphertext, second ciphertext
+    let disclosed = proof_contexts(
+        instructions_sysvar,
+        VERIFY_CIPHERTEXT_CIPHERTEXT_EQUALITY,
+        CIPHERTEXT_EQUALITY_CONTEXT_LEN,
+        CIPHERTEXT_EQUALITY_PROOF_LEN,
+    )?
+    .iter()
+    .any(|context| {
+        context[..32] == disclosure.elgamal_pubkey &&
+            context[32..64] == auditor_key.elgamal_pubkey &&
+            context[64..128] == disclosure.amount_ciphertext &&
+            context[128..192] == disclosure.auditor_ciphertext
+    });
+
+    require!(
+        opens_to_amount && in_range && disclosed,
+        ShadowForgeError::SelectiveDisclosureFailed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::{hash, hashv};
+
+use crate::ed25519::signers_of;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Screening result a compliance provider signs for one user
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProviderAttestation {
+    pub provider: Pubkey,
+    /// When the provider produced the result; binds the signature to a point in time
+    pub issued_at: i64,
+    pub risk_score: u8,
+    pub tier: ComplianceTier,
+    pub expires_at: i64,
+    /// ISO 3166-1 alpha-2 country code
+    pub jurisdiction: [u8; 2],
+}
+
+impl ProviderAttestation {
+    /// Canonical byte encoding providers sign:
+    /// domain tag || user || issued_at (LE) || risk score || tier || expires_at (LE) || jurisdiction
+    pub fn message(&self, user: &Pubkey) -> Vec<u8> {
+        let mut message = Vec::with_capacity(COMPLIANCE_ATTESTATION_DOMAIN.len() + 32 + 8 + 1 + 1 + 8 + 2);
+        message.extend_from_slice(COMPLIANCE_ATTESTATION_DOMAIN);
+        message.extend_from_slice(user.as_ref());
+        message.extend_from_slice(&self.issued_at.to_le_bytes());
+        message.push(self.risk_score);
+        message.push(self.tier as u8);
+        message.extend_from_slice(&self.expires_at.to_le_bytes());
+        message.extend_from_slice(&self.jurisdiction);
+        message
+    }
+}
+
+/// Check that an approved provider signed `attestation` for `user` in this transaction
+/// and issued it after `issued_after`, so a result predating a revocation cannot be replayed
+/// Returns the hash of the signed message, stored as the attestation hash
+pub fn verify_provider_attestation(
+    instructions_sysvar: &AccountInfo,
+    registry: &ComplianceProviderRegistry,
+    user: &Pubkey,
+    attestation: &ProviderAttestation,
+    issued_after: i64,
+    current_time: i64,
+) -> Result<[u8; 32]> {
+    require!(
+        registry.is_approved(&attestation.provider),
+        ShadowForgeError::UnapprovedComplianceProvider
+    );
+    require!(
+        attestation.issued_at > issued_after && attestation.issued_at <= current_time,
+        ShadowForgeError::ComplianceAttestationStale
+    );
+    require!(
+        attestation.expires_at > current_time &&
+        attestation.expires_at <= current_time.saturating_add(MAX_COMPLIANCE_VALIDITY_SECS),
+        ShadowForgeError::ComplianceExpired
+    );
+    require!(
+        attestation.jurisdiction.iter().all(|b| b.is_ascii_uppercase()),
+        ShadowForgeError::InvalidComplianceAttestation
+    );
+
+    let message = attestation.message(user);
+    require!(
+        signers_of(instructions_sysvar, &message)?.contains(&attestation.provider),
+        ShadowForgeError::InvalidComplianceAttestation
+    );
+
+    Ok(hash(&message).to_bytes())
+}
+
+/// Charge `amount` against the attestation's tier limits, tightened by its jurisdiction rule
+pub fn enforce_tier_limits(
+    policy: &CompliancePolicy,
+    jurisdiction_rule: Option<&JurisdictionRule>,
+    attestation: &mut ComplianceAttestation,
+    operation: ComplianceOperation,
+    amount: u64,
+    current_time: i64,
+) -> Result<()> {
+    let mut limits = *policy.limits(attestation.tier);
+
+    if let Some(rule) = jurisdiction_rule {
+        limits = limits.tightened(&rule.limits);
+    }
+
+    require!(
+        attestation.usage.try_record(&limits, operation, amount, current_time, policy.period_secs),
+        ShadowForgeError::ComplianceThresholdExceeded
+    );
+    Ok(())
+}
+
+/// Compliance gate shared by every value-moving instruction
+/// A no-op unless the vault requires compliance; then the user's attestation must be present,
+/// valid and unexpired, its jurisdiction must permit the operation, and a plaintext amount when
+/// given is charged against its tier and jurisdiction limits
+pub fn enforce_compliance(
+    vault_config: &VaultConfig,
+    attestation: Option<&mut ComplianceAttestation>,
+    policy: Option<&CompliancePolicy>,
+    jurisdictions: Option<&JurisdictionRegistry>,
+    user: &Pubkey,
+    (operation, charge): (ComplianceOperation, Option<u64>),
+    current_time: i64,
+) -> Result<()> {
+    if !vault_config.compliance_required {
+        return Ok(());
+    }
+
+    let attestation = attestation.ok_or(ShadowForgeError::KycRequired)?;
+    require!(attestation.user == *user, ShadowForgeError::ComplianceFailed);
+    require!(
+        attestation.is_valid && attestation.expires_at > current_time,
+        ShadowForgeError::ComplianceExpired
+    );
+
+    let jurisdictions = jurisdictions.ok_or(ShadowForgeError::ComplianceFailed)?;
+    let rule = jurisdictions.rule(&attestation.jurisdiction);
+    if let Some(rule) = rule {
+        require!(rule.allows(operation), ShadowForgeError::JurisdictionBlocked);
+    }
+
+    if let Some(amount) = charge {
+        let policy = policy.ok_or(ShadowForgeError::ComplianceFailed)?;
+        enforce_tier_limits(policy, rule, attestation, operation, amount, current_time)?;
+    }
+
+    Ok(())
+}
+
+/// Reject bridging to a chain the user's jurisdiction is blocked from
+/// Runs after `enforce_compliance`, which already required both accounts
+pub fn enforce_chain_jurisdiction(
+    vault_config: &VaultConfig,
+    attestation: Option<&ComplianceAttestation>,
+    jurisdictions: Option<&JurisdictionRegistry>,
+    dest_chain_id: u64,
+) -> Result<()> {
+    if !vault_config.compliance_required {
+        return Ok(());
+    }
+
+    let attestation = attestation.ok_or(ShadowForgeError::KycRequired)?;
+    let jurisdictions = jurisdictions.ok_or(ShadowForgeError::ComplianceFailed)?;
+    if let Some(rule) = jurisdictions.rule(&attestation.jurisdiction) {
+        require!(
+            !rule.blocked_chains.contains(&dest_chain_id),
+            ShadowForgeError::JurisdictionBlocked
+        );
+    }
+
+    Ok(())
+}
+
+/// Proof that an address is absent from the sorted denylist: two adjacent leaves bracketing it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NonMembershipProof {
+    pub low: Pubkey,
+    pub high: Pubkey,
+    /// Leaf index of `low`; `high` sits at the next index
+    pub low_index: u32,
+    pub low_siblings: Vec<[u8; 32]>,
+    pub high_siblings: Vec<[u8; 32]>,
+}
+
+fn screening_leaf(address: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0u8], address.as_ref()]).to_bytes()
+}
+
+fn merkle_root(leaf: [u8; 32], index: u32, siblings: &[[u8; 32]]) -> [u8; 32] {
+    let mut node = leaf;
+    for (level, sibling) in siblings.iter().enumerate() {
+        node = if (index >> level) & 1 == 0 {
+            hashv(&[&[1u8], &node, sibling]).to_bytes()
+        } else {
+            hashv(&[&[1u8], sibling, &node]).to_bytes()
+        };
+    }
+    node
+}
+
+/// Fail unless `address` provably sits between two adjacent leaves of a fresh screening root
+pub fn screen_address(
+    screening: &ScreeningRoot,
+    address: &Pubkey,
+    proof: &NonMembershipProof,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        screening.version > 0 && !screening.is_stale(current_time),
+        ShadowForgeError::ScreeningDataStale
+    );
+
+    let depth = proof.low_siblings.len();
+    let high_index = proof.low_index
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AddressScreeningFailed)?;
+    require!(
+        depth == proof.high_siblings.len() &&
+        depth <= MAX_SCREENING_TREE_DEPTH &&
+        (high_index as u64) < (1u64 << depth),
+        ShadowForgeError::AddressScreeningFailed
+    );
+    require!(
+        proof.low < *address && *address < proof.high,
+        ShadowForgeError::AddressScreeningFailed
+    );
+    require!(
+        merkle_root(screening_leaf(&proof.low), proof.low_index, &proof.low_siblings) == screening.root &&
+        merkle_root(screening_leaf(&proof.high), high_index, &proof.high_siblings) == screening.root,
+        ShadowForgeError::AddressScreeningFailed
+    );
+
+    Ok(())
+}
+
+/// Sanctions screening for deposits and withdrawals, enforced whenever the vault requires compliance
+pub fn enforce_screening(
+    vault_config: &VaultConfig,
+    screening: Option<&ScreeningRoot>,
+    address: &Pubkey,
+    proof: Option<&NonMembershipProof>,
+    current_time: i64,
+) -> Result<()> {
+    if !vault_config.compliance_required {
+        return Ok(());
+    }
+
+    let screening = screening.ok_or(ShadowForgeError::AddressScreeningFailed)?;
+    let proof = proof.ok_or(ShadowForgeError::AddressScreeningFailed)?;
+    screen_address(screening, address, proof, current_time)
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::instructions::apply_admin_action;
+use crate::state::*;
+
+/// Whether an admin council has been configured for the vault
+pub fn council_is_active(account: &AccountInfo) -> bool {
+    !account.data_is_empty() && *account.owner == crate::ID
+}
+
+/// Number of distinct council members that signed the transaction among `accounts`
+pub fn member_signatures(council: &AdminCouncil, accounts: &[AccountInfo]) -> usize {
+    let mut signed = vec![false; council.members.len()];
+    for account in accounts.iter().filter(|a| a.is_signer) {
+        if let Some(position) = council.members.iter().position(|m| m == account.key) {
+            signed[position] = true;
+        }
+    }
+    signed.iter().filter(|&&s| s).count()
+}
+
+/// Once a council is configured, require `threshold` of its members to co-sign,
+/// passed as remaining accounts; a no-op before then
+pub fn require_council_approval(council: &AccountInfo, co_signers: &[AccountInfo]) -> Result<()> {
+    if !council_is_active(council) {
+        return Ok(());
+    }
+
+    let data = council.try_borrow_data()?;
+    let council = AdminCouncil::try_deserialize(&mut &data[..])?;
+    require!(
+        member_signatures(&council, co_signers) >= council.threshold as usize,
+        ShadowForgeError::CouncilApprovalRequired
+    );
+
+    Ok(())
+}
+
+/// Record `member`'s approval and execute the proposal once enough current members approved
+/// Approvals from keys since removed from the council no longer count
+pub fn approve_and_maybe_execute(
+    council: &AdminCouncil,
+    proposal: &mut CouncilProposal,
+    vault_config: &mut VaultConfig,
+    member: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    require!(
+        proposal.approvals.len() < MAX_COUNCIL_MEMBERS,
+        ShadowForgeError::InvalidAdminOperation
+    );
+    proposal.approvals.push(member);
+
+    let approvals = proposal.approvals
+        .iter()
+        .filter(|a| council.is_member(a))
+        .count();
+
+    if approvals >= council.threshold as usize {
+        apply_admin_action(vault_config, &proposal.action)?;
+        proposal.executed = true;
+    }
+
+    emit!(CouncilProposalEvent {
+        id: proposal.id,
+        action: proposal.action.clone(),
+        member,
+        approvals: approvals as u8,
+        executed: proposal.executed,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Council: Proposal {} has {}/{} approvals{}",
+        proposal.id,
+        approvals,
+        council.threshold,
+        if proposal.executed { ", executed" } else { "" }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::error::ShadowForgeError;
+
+/// Header of an ed25519 program instruction: signature count and padding
+const ED25519_HEADER_LEN: usize = 2;
+/// Each signature entry is seven little-endian u16 offsets
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+/// Offsets pointing at this index refer to data inside the ed25519 instruction itself
+const ED25519_CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Public keys that signed exactly `message` through ed25519 program instructions
+/// placed before the current instruction in the same transaction
+pub fn signers_of(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<Vec<Pubkey>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let mut signers = Vec::new();
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+
+        signers.extend(ed25519_signers(&instruction.data, message)?);
+    }
+
+    Ok(signers)
+}
+
+/// Public keys of every signature in an ed25519 instruction that covers exactly `message`
+/// The runtime has already verified the signatures; only self-contained entries are accepted
+/// so the signed bytes cannot be swapped out via another instruction
+fn ed25519_signers(data: &[u8], message: &[u8]) -> Result<Vec<Pubkey>> {
+    require!(data.len() >= ED25519_HEADER_LEN, ShadowForgeError::InvalidAttestation);
+
+    let count = data[0] as usize;
+    let mut signers = Vec::with_capacity(count);
+
+    for i in 0..count {
+        let start = ED25519_HEADER_LEN + i * ED25519_OFFSETS_LEN;
+        let offsets = data
+            .get(start..start + ED25519_OFFSETS_LEN)
+            .ok_or(ShadowForgeError::InvalidAttestation)?;
+        let read = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+        let signature_instruction_index = read(2);
+        let public_key_offset = read(4) as usize;
+        let public_key_instruction_index = read(6);
+        let message_offset = read(8) as usize;
+        let message_size = read(10) as usize;
+        let message_instruction_index = read(12);
+
+        if signature_instruction_index != ED25519_CURRENT_INSTRUCTION ||
+            public_key_instruction_index != ED25519_CURRENT_INSTRUCTION ||
+            message_instruction_index != ED25519_CURRENT_INSTRUCTION
+        {
+            continue;
+        }
+
+        let signed_message = data
+            .get(message_offset..message_offset + message_size)
+            .ok_or(ShadowForgeError::InvalidAttestation)?;
+        if signed_message != message {
+            continue;
+        }
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + ED25519_PUBKEY_LEN)
+            .ok_or(ShadowForgeError::InvalidAttestation)?;
+        signers.push(
+            Pubkey::try_from(public_key).map_err(|_| error!(ShadowForgeError::InvalidAttestation))?,
+        );
+    }
+
+    Ok(signers)
+}
@@ -63,6 +63,18 @@ pub enum ShadowForgeError {
     KycRequired,
     #[msg("Transaction exceeds compliance threshold")]
     ComplianceThresholdExceeded,
+    #[msg("Compliance provider is not in the approved registry")]
+    UnapprovedComplianceProvider,
+    #[msg("Compliance attestation is not signed by the provider or is malformed")]
+    InvalidComplianceAttestation,
+    #[msg("Only the attesting provider or the compliance officer can revoke")]
+    UnauthorizedRevocation,
+    #[msg("Sanctions screening data is too old")]
+    ScreeningDataStale,
+    #[msg("Operation not permitted for the attested jurisdiction")]
+    JurisdictionBlocked,
+    #[msg("Compliance attestation was issued before the latest revocation or renewal")]
+    ComplianceAttestationStale,
 
     // Transfer/Bridge errors (6090-6109)
     #[msg("Private transfer failed")]
@@ -75,8 +87,32 @@ pub enum ShadowForgeError {
     SilentSwapFailed,
     #[msg("Invalid destination chain")]
     InvalidDestinationChain,
+    #[msg("Bridging to this chain is disabled")]
+    ChainDisabled,
+    #[msg("Bridge amount is outside the chain's limits")]
+    BridgeAmountOutOfRange,
+    #[msg("Bridge request has not timed out yet")]
+    BridgeRequestNotExpired,
+    #[msg("Bridge outflow limit reached for the rolling window")]
+    BridgeRateLimitExceeded,
+    #[msg("Bridge is paused by its circuit breaker")]
+    BridgePaused,
+    #[msg("Destination address is missing or sealed to a stale relayer key")]
+    InvalidEncryptedRecipient,
     #[msg("Bridge liquidity insufficient")]
     BridgeLiquidityInsufficient,
+    #[msg("Guardian set is empty or its threshold is invalid")]
+    InvalidGuardianSet,
+    #[msg("Not enough guardian signatures over the bridge attestation")]
+    InsufficientGuardianSignatures,
+    #[msg("Bridge attestation does not match the request")]
+    InvalidAttestation,
+    #[msg("Inbound bridge message has already been claimed")]
+    BridgeMessageAlreadyConsumed,
+    #[msg("Bridge request was picked up by the relayer and awaits a guardian attestation")]
+    BridgeRequestInFlight,
+    #[msg("Bridge request timed out before the relayer picked it up")]
+    BridgeRequestExpired,
 
     // Lending errors (6110-6129)
     #[msg("Privacy Cash lending operation failed")]
@@ -103,6 +139,18 @@ pub enum ShadowForgeError {
     NoLiquidity,
     #[msg("Invalid swap path")]
     InvalidSwapPath,
+    #[msg("Dark pool order has expired")]
+    OrderExpired,
+    #[msg("Dark pool order has not expired yet")]
+    OrderNotExpired,
+    #[msg("Recurring swap order is not active")]
+    RecurringSwapInactive,
+    #[msg("Recurring swap slice is not due yet")]
+    RecurringSwapNotDue,
+    #[msg("Swap venue debited more than the leg amount")]
+    SwapLegOverdrawn,
+    #[msg("Immediate order could not be filled in full")]
+    OrderNotFilled,
 
     // Account/State errors (6150-6169)
     #[msg("User position not found")]
@@ -115,6 +163,10 @@ pub enum ShadowForgeError {
     CorruptedAccountData,
     #[msg("Timestamp manipulation detected")]
     InvalidTimestamp,
+    #[msg("Position already uses the current layout")]
+    PositionAlreadyMigrated,
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
 
     // Admin errors (6170-6189)
     #[msg("Unauthorized - admin only")]
@@ -125,6 +177,12 @@ pub enum ShadowForgeError {
     InvalidAdminOperation,
     #[msg("Emergency mode is active")]
     EmergencyMode,
+    #[msg("Timelocked change is not executable yet")]
+    TimelockNotElapsed,
+    #[msg("Action requires admin council approval")]
+    CouncilApprovalRequired,
+    #[msg("Council proposal has expired")]
+    ProposalExpired,
 
     // SDK integration errors (6190-6209)
     #[msg("Helius RPC query failed")]
@@ -137,4 +195,6 @@ pub enum ShadowForgeError {
     CpiInvocationFailed,
     #[msg("SDK response validation failed")]
     SdkResponseInvalid,
+    #[msg("Program account does not match the adapter registry")]
+    InvalidAdapterProgram,
 }
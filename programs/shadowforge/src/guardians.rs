@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::ed25519::signers_of;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// What a guardian attestation vouches for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum AttestationKind {
+    /// Funds were locked on the source chain for a Solana recipient
+    InboundTransfer,
+    /// An outbound request was released on the destination chain
+    OutboundCompletion,
+    /// An outbound request could not be delivered and will never be released
+    OutboundFailure,
+}
+
+/// Fields guardians sign for a bridge transfer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BridgeAttestation {
+    pub kind: AttestationKind,
+    pub chain_id: u64,
+    pub nonce: u64,
+    pub recipient_commitment: [u8; 32],
+    pub amount: u64,
+}
+
+impl BridgeAttestation {
+    /// Canonical byte encoding guardians sign:
+    /// domain tag || program id || vault || kind || chain id (LE) || nonce (LE) || recipient commitment || amount (LE)
+    /// The program id and vault pin the signature to one deployment so it cannot be replayed elsewhere
+    pub fn message(&self, vault: &Pubkey) -> Vec<u8> {
+        let mut message = Vec::with_capacity(BRIDGE_ATTESTATION_DOMAIN.len() + 32 * 3 + 1 + 8 * 3);
+        message.extend_from_slice(BRIDGE_ATTESTATION_DOMAIN);
+        message.extend_from_slice(crate::ID.as_ref());
+        message.extend_from_slice(vault.as_ref());
+        message.push(self.kind as u8);
+        message.extend_from_slice(&self.chain_id.to_le_bytes());
+        message.extend_from_slice(&self.nonce.to_le_bytes());
+        message.extend_from_slice(&self.recipient_commitment);
+        message.extend_from_slice(&self.amount.to_le_bytes());
+        message
+    }
+}
+
+/// Recipient commitment binding a bridged amount commitment to a Solana owner
+pub fn recipient_commitment(owner: &Pubkey, amount_commitment: &[u8; 32]) -> [u8; 32] {
+    hashv(&[owner.as_ref(), amount_commitment]).to_bytes()
+}
+
+/// Check an attestation against the guardian set's ed25519 signatures in this transaction
+pub fn verify_attestation(
+    instructions_sysvar: &AccountInfo,
+    guardian_set: &GuardianSet,
+    attestation: &BridgeAttestation,
+) -> Result<()> {
+    verify_guardian_signatures(instructions_sysvar, guardian_set, &attestation.message(&guardian_set.vault))
+}
+
+/// Require at least `guardian_set.threshold` distinct guardians to have signed `message`
+/// through ed25519 program instructions earlier in the same transaction
+pub fn verify_guardian_signatures(
+    instructions_sysvar: &AccountInfo,
+    guardian_set: &GuardianSet,
+    message: &[u8],
+) -> Result<()> {
+    require!(guardian_set.threshold > 0, ShadowForgeError::InvalidGuardianSet);
+
+    let mut signed = vec![false; guardian_set.guardians.len()];
+
+    for signer in signers_of(instructions_sysvar, message)? {
+        if let Some(position) = guardian_set.guardians.iter().position(|g| *g == signer) {
+            signed[position] = true;
+        }
+    }
+
+    let signatures = signed.iter().filter(|&&s| s).count();
+    require!(
+        signatures >= guardian_set.threshold as usize,
+        ShadowForgeError::InsufficientGuardianSignatures
+    );
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Second step of an admin handover: the proposed successor signs to take over
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub pending_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.has_pending_admin() @ ShadowForgeError::InvalidAdminOperation,
+        constraint = vault_config.pending_admin == pending_admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+pub fn handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    let clock = Clock::get()?;
+
+    let previous_admin = vault_config.admin;
+    vault_config.admin = vault_config.pending_admin;
+    vault_config.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferAcceptedEvent {
+        previous_admin,
+        new_admin: vault_config.admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin: Handover accepted, admin={}", vault_config.admin);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Relayer instruction recording that an outbound request has been picked up for delivery
+/// From this point the owner can no longer cancel and the timeout refund no longer applies;
+/// only a guardian attestation settles it. Pickup must land before the request times out
+#[derive(Accounts)]
+pub struct AcknowledgeBridgeRequest<'info> {
+    pub relayer: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.is_operational(PausableOperation::Bridge) @ ShadowForgeError::VaultPaused,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [RELAYER_KEY_SEED, vault_config.key().as_ref()],
+        bump = relayer_keys.bump,
+        constraint = relayer_keys.relayer == relayer.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub relayer_keys: Account<'info, RelayerKeyRegistry>,
+
+    /// CHECK: Request owner, only used to derive the request address
+    #[account(address = bridge_request.user @ ShadowForgeError::InvalidAuthority)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BRIDGE_REQUEST_SEED,
+            vault_config.key().as_ref(),
+            owner.key().as_ref(),
+            &bridge_request.nonce.to_le_bytes(),
+        ],
+        bump = bridge_request.bump,
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+}
+
+pub fn handler(ctx: Context<AcknowledgeBridgeRequest>) -> Result<()> {
+    let bridge_request = &mut ctx.accounts.bridge_request;
+    let clock = Clock::get()?;
+
+    require!(
+        bridge_request.status == BridgeStatus::Pending,
+        ShadowForgeError::BridgeFailed
+    );
+    require!(
+        !bridge_request.is_expired(clock.unix_timestamp),
+        ShadowForgeError::BridgeRequestExpired
+    );
+
+    bridge_request.status = BridgeStatus::Confirmed;
+
+    emit!(BridgeAcknowledgedEvent {
+        user: bridge_request.user,
+        nonce: bridge_request.nonce,
+        relayer: ctx.accounts.relayer.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("SilentSwap: Bridge request {} picked up by relayer", bridge_request.nonce);
+
+    Ok(())
+}
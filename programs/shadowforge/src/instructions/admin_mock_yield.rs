@@ -2,34 +2,51 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked};
 
+use crate::council::council_is_active;
 use crate::error::ShadowForgeError;
 use crate::state::*;
 
 /// Admin instruction to mock yield distribution for demo purposes
 /// Deposits rewards into the vault and updates yield tracking
 /// Used for hackathon demonstration of yield accrual
+/// Signed by the admin, or by a key holding the role the action requires
+/// Once an admin council exists, unpausing, leaving emergency mode and compliance changes go
+/// through council proposals
 #[derive(Accounts)]
 pub struct AdminMockYield<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     /// Vault configuration
     #[account(
         mut,
         seeds = [VAULT_CONFIG_SEED],
         bump = vault_config.bump,
-        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
     )]
     pub vault_config: Account<'info, VaultConfig>,
 
-    /// Admin's token account (source of reward tokens)
+    /// Required when a role holder, rather than the admin, signs
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    /// Signer's token account (source of reward tokens), required for `DepositRewards`
     #[account(
         mut,
         token::mint = shielded_mint,
-        token::authority = admin,
+        token::authority = authority,
         token::token_program = token_2022_program,
     )]
-    pub admin_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub admin_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     /// Shielded vault token account (receives rewards)
     #[account(
@@ -50,38 +67,6 @@ pub struct AdminMockYield<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub enum AdminAction {
-    /// Deposit reward tokens into vault
-    DepositRewards { amount: u64 },
-    /// Update yield rate
-    UpdateYieldRate { new_rate_bps: u16 },
-    /// Pause/unpause vault
-    SetPaused { paused: bool },
-    /// Toggle emergency mode
-    SetEmergencyMode { enabled: bool },
-    /// Update fee configuration
-    UpdateFees {
-        deposit_fee_bps: Option<u16>,
-        withdrawal_fee_bps: Option<u16>,
-        lending_fee_bps: Option<u16>,
-        swap_fee_bps: Option<u16>,
-        bridge_fee_bps: Option<u16>,
-    },
-    /// Toggle SDK features
-    ToggleSdk {
-        arcium: Option<bool>,
-        shadowwire: Option<bool>,
-        anoncoin: Option<bool>,
-        privacy_cash: Option<bool>,
-        silentswap: Option<bool>,
-        starpay: Option<bool>,
-        range: Option<bool>,
-    },
-    /// Toggle compliance requirement
-    SetComplianceRequired { required: bool },
-}
-
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct AdminMockYieldParams {
     /// Admin action to perform
@@ -89,15 +74,29 @@ pub struct AdminMockYieldParams {
 }
 
 pub fn handler(ctx: Context<AdminMockYield>, params: AdminMockYieldParams) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let is_admin = ctx.accounts.vault_config.admin == authority;
+    let has_role = ctx.accounts.role_registry
+        .as_ref()
+        .is_some_and(|r| r.has_role(&authority, params.action.required_role()));
+    require!(is_admin || has_role, ShadowForgeError::Unauthorized);
+    // Holding a role does not bypass the council
+    require!(
+        !(params.action.requires_council() && council_is_active(&ctx.accounts.council)),
+        ShadowForgeError::CouncilApprovalRequired
+    );
+
     let vault_config = &mut ctx.accounts.vault_config;
-    let clock = Clock::get()?;
 
     match params.action {
         AdminAction::DepositRewards { amount } => {
+            let admin_token_account = ctx.accounts.admin_token_account
+                .as_ref()
+                .ok_or(ShadowForgeError::InvalidAdminOperation)?;
             deposit_rewards(
                 vault_config,
-                &ctx.accounts.admin,
-                &ctx.accounts.admin_token_account,
+                &ctx.accounts.authority,
+                admin_token_account,
                 &ctx.accounts.shielded_vault_ata,
                 &ctx.accounts.shielded_mint,
                 &ctx.accounts.token_2022_program,
@@ -105,54 +104,29 @@ pub fn handler(ctx: Context<AdminMockYield>, params: AdminMockYieldParams) -> Re
             )?;
         }
 
-        AdminAction::UpdateYieldRate { new_rate_bps } => {
-            update_yield_rate(vault_config, new_rate_bps, clock.unix_timestamp)?;
-        }
+        action => apply_admin_action(vault_config, &action)?,
+    }
 
-        AdminAction::SetPaused { paused } => {
-            set_paused(vault_config, paused)?;
+    Ok(())
+}
+
+/// Apply an action that moves no tokens; shared with admin council proposals
+pub fn apply_admin_action(vault_config: &mut VaultConfig, action: &AdminAction) -> Result<()> {
+    match *action {
+        AdminAction::DepositRewards { .. } => {
+            return err!(ShadowForgeError::InvalidAdminOperation);
         }
 
-        AdminAction::SetEmergencyMode { enabled } => {
-            set_emergency_mode(vault_config, enabled)?;
+        AdminAction::SetPaused { paused } => {
+            set_paused(vault_config, PausableOperation::ALL, paused)?;
         }
 
-        AdminAction::UpdateFees {
-            deposit_fee_bps,
-            withdrawal_fee_bps,
-            lending_fee_bps,
-            swap_fee_bps,
-            bridge_fee_bps,
-        } => {
-            update_fees(
-                vault_config,
-                deposit_fee_bps,
-                withdrawal_fee_bps,
-                lending_fee_bps,
-                swap_fee_bps,
-                bridge_fee_bps,
-            )?;
+        AdminAction::SetOperationPaused { operation, paused } => {
+            set_paused(vault_config, operation.bit(), paused)?;
         }
 
-        AdminAction::ToggleSdk {
-            arcium,
-            shadowwire,
-            anoncoin,
-            privacy_cash,
-            silentswap,
-            starpay,
-            range,
-        } => {
-            toggle_sdk_features(
-                vault_config,
-                arcium,
-                shadowwire,
-                anoncoin,
-                privacy_cash,
-                silentswap,
-                starpay,
-                range,
-            )?;
+        AdminAction::SetEmergencyMode { enabled } => {
+            set_emergency_mode(vault_config, enabled)?;
         }
 
         AdminAction::SetComplianceRequired { required } => {
@@ -166,7 +140,7 @@ pub fn handler(ctx: Context<AdminMockYield>, params: AdminMockYieldParams) -> Re
 /// Deposit reward tokens into the vault for yield distribution
 fn deposit_rewards<'info>(
     vault_config: &mut Account<'info, VaultConfig>,
-    admin: &Signer<'info>,
+    authority: &Signer<'info>,
     admin_token_account: &InterfaceAccount<'info, TokenAccount>,
     shielded_vault_ata: &InterfaceAccount<'info, TokenAccount>,
     shielded_mint: &InterfaceAccount<'info, Mint>,
@@ -186,7 +160,7 @@ fn deposit_rewards<'info>(
             from: admin_token_account.to_account_info(),
             mint: shielded_mint.to_account_info(),
             to: shielded_vault_ata.to_account_info(),
-            authority: admin.to_account_info(),
+            authority: authority.to_account_info(),
         },
     );
     transfer_checked(transfer_ctx, amount, shielded_mint.decimals)?;
@@ -205,35 +179,19 @@ fn deposit_rewards<'info>(
     Ok(())
 }
 
-/// Update the vault yield rate
-fn update_yield_rate(
-    vault_config: &mut VaultConfig,
-    new_rate_bps: u16,
-    current_time: i64,
-) -> Result<()> {
-    require!(
-        new_rate_bps <= 5000, // Max 50% APY
-        ShadowForgeError::InvalidAmount
-    );
-
-    let old_rate = vault_config.current_yield_bps;
-    vault_config.current_yield_bps = new_rate_bps;
-    vault_config.last_yield_update = current_time;
-
-    msg!(
-        "Admin: Updated yield rate from {} bps to {} bps",
-        old_rate,
-        new_rate_bps
-    );
-
-    Ok(())
-}
+/// Set or clear the pause bits in `operations`
+fn set_paused(vault_config: &mut VaultConfig, operations: u8, paused: bool) -> Result<()> {
+    if vault_config.paused_operations & PausableOperation::LEGACY_PAUSED != 0 {
+        vault_config.paused_operations = PausableOperation::ALL;
+    }
 
-/// Pause or unpause the vault
-fn set_paused(vault_config: &mut VaultConfig, paused: bool) -> Result<()> {
-    vault_config.is_paused = paused;
+    if paused {
+        vault_config.paused_operations |= operations;
+    } else {
+        vault_config.paused_operations &= !operations;
+    }
 
-    msg!("Admin: Vault paused = {}", paused);
+    msg!("Admin: Paused operations = {:#010b}", vault_config.paused_operations);
 
     Ok(())
 }
@@ -244,7 +202,7 @@ fn set_emergency_mode(vault_config: &mut VaultConfig, enabled: bool) -> Result<(
 
     if enabled {
         // In emergency mode, also pause the vault
-        vault_config.is_paused = true;
+        vault_config.paused_operations = PausableOperation::ALL;
         msg!("Admin: EMERGENCY MODE ENABLED - vault paused");
     } else {
         msg!("Admin: Emergency mode disabled");
@@ -253,97 +211,6 @@ fn set_emergency_mode(vault_config: &mut VaultConfig, enabled: bool) -> Result<(
     Ok(())
 }
 
-/// Update fee configuration
-fn update_fees(
-    vault_config: &mut VaultConfig,
-    deposit_fee_bps: Option<u16>,
-    withdrawal_fee_bps: Option<u16>,
-    lending_fee_bps: Option<u16>,
-    swap_fee_bps: Option<u16>,
-    bridge_fee_bps: Option<u16>,
-) -> Result<()> {
-    if let Some(fee) = deposit_fee_bps {
-        require!(fee <= MAX_BASIS_POINTS, ShadowForgeError::InvalidAmount);
-        vault_config.deposit_fee_bps = fee;
-        msg!("Admin: Deposit fee updated to {} bps", fee);
-    }
-
-    if let Some(fee) = withdrawal_fee_bps {
-        require!(fee <= MAX_BASIS_POINTS, ShadowForgeError::InvalidAmount);
-        vault_config.withdrawal_fee_bps = fee;
-        msg!("Admin: Withdrawal fee updated to {} bps", fee);
-    }
-
-    if let Some(fee) = lending_fee_bps {
-        require!(fee <= MAX_BASIS_POINTS, ShadowForgeError::InvalidAmount);
-        vault_config.lending_fee_bps = fee;
-        msg!("Admin: Lending fee updated to {} bps", fee);
-    }
-
-    if let Some(fee) = swap_fee_bps {
-        require!(fee <= MAX_BASIS_POINTS, ShadowForgeError::InvalidAmount);
-        vault_config.swap_fee_bps = fee;
-        msg!("Admin: Swap fee updated to {} bps", fee);
-    }
-
-    if let Some(fee) = bridge_fee_bps {
-        require!(fee <= MAX_BASIS_POINTS, ShadowForgeError::InvalidAmount);
-        vault_config.bridge_fee_bps = fee;
-        msg!("Admin: Bridge fee updated to {} bps", fee);
-    }
-
-    Ok(())
-}
-
-/// Toggle SDK feature flags
-fn toggle_sdk_features(
-    vault_config: &mut VaultConfig,
-    arcium: Option<bool>,
-    shadowwire: Option<bool>,
-    anoncoin: Option<bool>,
-    privacy_cash: Option<bool>,
-    silentswap: Option<bool>,
-    starpay: Option<bool>,
-    range: Option<bool>,
-) -> Result<()> {
-    if let Some(enabled) = arcium {
-        vault_config.arcium_enabled = enabled;
-        msg!("Admin: Arcium MXE = {}", enabled);
-    }
-
-    if let Some(enabled) = shadowwire {
-        vault_config.shadowwire_enabled = enabled;
-        msg!("Admin: ShadowWire = {}", enabled);
-    }
-
-    if let Some(enabled) = anoncoin {
-        vault_config.anoncoin_enabled = enabled;
-        msg!("Admin: Anoncoin = {}", enabled);
-    }
-
-    if let Some(enabled) = privacy_cash {
-        vault_config.privacy_cash_enabled = enabled;
-        msg!("Admin: Privacy Cash = {}", enabled);
-    }
-
-    if let Some(enabled) = silentswap {
-        vault_config.silentswap_enabled = enabled;
-        msg!("Admin: SilentSwap = {}", enabled);
-    }
-
-    if let Some(enabled) = starpay {
-        vault_config.starpay_enabled = enabled;
-        msg!("Admin: Starpay = {}", enabled);
-    }
-
-    if let Some(enabled) = range {
-        vault_config.range_enabled = enabled;
-        msg!("Admin: Range Compliance = {}", enabled);
-    }
-
-    Ok(())
-}
-
 /// Set compliance requirement
 fn set_compliance_required(vault_config: &mut VaultConfig, required: bool) -> Result<()> {
     vault_config.compliance_required = required;
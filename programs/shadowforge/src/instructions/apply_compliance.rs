@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::compliance::{verify_provider_attestation, ProviderAttestation};
 use crate::error::ShadowForgeError;
 use crate::state::*;
 
@@ -11,7 +12,7 @@ pub struct ApplyCompliance<'info> {
     #[account(
         seeds = [VAULT_CONFIG_SEED],
         bump = vault_config.bump,
-        constraint = vault_config.is_operational() @ ShadowForgeError::VaultPaused,
+        constraint = vault_config.is_operational(PausableOperation::Compliance) @ ShadowForgeError::VaultPaused,
         constraint = vault_config.range_enabled @ ShadowForgeError::ExternalSdkFailed,
     )]
     pub vault_config: Account<'info, VaultConfig>,
@@ -33,9 +34,28 @@ pub struct ApplyCompliance<'info> {
     )]
     pub compliance_attestation: Account<'info, ComplianceAttestation>,
 
-    /// CHECK: Range Protocol program for CPI (address verified at runtime if needed)
+    #[account(
+        seeds = [COMPLIANCE_PROVIDERS_SEED, vault_config.key().as_ref()],
+        bump = compliance_providers.bump,
+    )]
+    pub compliance_providers: Account<'info, ComplianceProviderRegistry>,
+
+    #[account(
+        seeds = [ADAPTER_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = adapter_registry.bump,
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    /// CHECK: Range Protocol program for CPI, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::Range, range_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
     pub range_program: UncheckedAccount<'info>,
 
+    /// CHECK: Instructions sysvar, read to find the provider's ed25519 signature
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -43,16 +63,15 @@ pub struct ApplyCompliance<'info> {
 pub enum ComplianceAction {
     Submit,
     Verify,
-    Revoke,
     Renew,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ApplyComplianceParams {
     pub action: ComplianceAction,
-    pub attestation_hash: [u8; 32],
     pub disclosure_proof: [u8; PROOF_DATA_LEN],
-    pub validity_days: u16,
+    /// Provider-signed screening result, required for Submit and Renew
+    pub provider_attestation: Option<ProviderAttestation>,
 }
 
 pub fn handler(ctx: Context<ApplyCompliance>, params: ApplyComplianceParams) -> Result<()> {
@@ -71,11 +90,6 @@ pub fn handler(ctx: Context<ApplyCompliance>, params: ApplyComplianceParams) ->
         ShadowForgeError::InvalidProof
     );
 
-    require!(
-        params.validity_days > 0 && params.validity_days <= 365,
-        ShadowForgeError::ComplianceExpired
-    );
-
     match params.action {
         ComplianceAction::Submit => {
             require!(
@@ -83,22 +97,34 @@ pub fn handler(ctx: Context<ApplyCompliance>, params: ApplyComplianceParams) ->
                 ShadowForgeError::ComplianceExpired
             );
 
-            let risk_score = compute_risk_score(&params.attestation_hash);
+            let attestation = params.provider_attestation
+                .as_ref()
+                .ok_or(ShadowForgeError::InvalidComplianceAttestation)?;
+            let attestation_hash = verify_provider_attestation(
+                &ctx.accounts.instructions.to_account_info(),
+                &ctx.accounts.compliance_providers,
+                &ctx.accounts.user.key(),
+                attestation,
+                compliance.issued_after(),
+                clock.unix_timestamp,
+            )?;
             require!(
-                risk_score <= 75,
+                attestation.risk_score <= MAX_ACCEPTED_RISK_SCORE,
                 ShadowForgeError::ComplianceFailed
             );
 
-            let expiry = clock.unix_timestamp
-                .checked_add((params.validity_days as i64) * 86400)
-                .ok_or(ShadowForgeError::AmountOverflow)?;
+            let risk_score = attestation.risk_score;
+            let expiry = attestation.expires_at;
 
             compliance.user = ctx.accounts.user.key();
-            compliance.provider = RANGE_PROGRAM_ID;
-            compliance.attestation_hash = params.attestation_hash;
+            compliance.provider = attestation.provider;
+            compliance.attestation_hash = attestation_hash;
             compliance.attested_at = clock.unix_timestamp;
+            compliance.issued_at = attestation.issued_at;
             compliance.expires_at = expiry;
             compliance.risk_score = risk_score;
+            compliance.tier = attestation.tier;
+            compliance.jurisdiction = attestation.jurisdiction;
             compliance.is_valid = true;
             compliance.bump = ctx.bumps.compliance_attestation;
 
@@ -127,39 +153,39 @@ pub fn handler(ctx: Context<ApplyCompliance>, params: ApplyComplianceParams) ->
             msg!("Range: Compliance verified, expires_at={}", compliance.expires_at);
         }
 
-        ComplianceAction::Revoke => {
-            require!(
-                compliance.is_valid,
-                ShadowForgeError::ComplianceFailed
-            );
-
-            compliance.is_valid = false;
-            user_position.compliance_verified = false;
-            user_position.compliance_expiry = 0;
-
-            msg!("Range: Compliance attestation revoked");
-        }
-
         ComplianceAction::Renew => {
             require!(
                 compliance.is_valid || compliance.expires_at <= clock.unix_timestamp,
                 ShadowForgeError::ComplianceFailed
             );
 
-            let risk_score = compute_risk_score(&params.attestation_hash);
+            let attestation = params.provider_attestation
+                .as_ref()
+                .ok_or(ShadowForgeError::InvalidComplianceAttestation)?;
+            let attestation_hash = verify_provider_attestation(
+                &ctx.accounts.instructions.to_account_info(),
+                &ctx.accounts.compliance_providers,
+                &ctx.accounts.user.key(),
+                attestation,
+                compliance.issued_after(),
+                clock.unix_timestamp,
+            )?;
             require!(
-                risk_score <= 75,
+                attestation.risk_score <= MAX_ACCEPTED_RISK_SCORE,
                 ShadowForgeError::ComplianceFailed
             );
 
-            let expiry = clock.unix_timestamp
-                .checked_add((params.validity_days as i64) * 86400)
-                .ok_or(ShadowForgeError::AmountOverflow)?;
+            let risk_score = attestation.risk_score;
+            let expiry = attestation.expires_at;
 
-            compliance.attestation_hash = params.attestation_hash;
+            compliance.provider = attestation.provider;
+            compliance.attestation_hash = attestation_hash;
             compliance.attested_at = clock.unix_timestamp;
+            compliance.issued_at = attestation.issued_at;
             compliance.expires_at = expiry;
             compliance.risk_score = risk_score;
+            compliance.tier = attestation.tier;
+            compliance.jurisdiction = attestation.jurisdiction;
             compliance.is_valid = true;
 
             user_position.compliance_verified = true;
@@ -173,15 +199,11 @@ pub fn handler(ctx: Context<ApplyCompliance>, params: ApplyComplianceParams) ->
 
     emit!(ComplianceEvent {
         user: ctx.accounts.user.key(),
-        provider: RANGE_PROGRAM_ID,
+        provider: compliance.provider,
         risk_score: compliance.risk_score,
+        tier: compliance.tier,
         expires_at: compliance.expires_at,
     });
 
     Ok(())
 }
-
-fn compute_risk_score(attestation_hash: &[u8; 32]) -> u8 {
-    let sum: u32 = attestation_hash.iter().map(|&b| b as u32).sum();
-    ((sum % 100) as u8).min(100)
-}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Bridge-only pause and global outflow limit
+/// Any bridge guardian may trip the breaker; only the admin may reset it or change the limit,
+/// with council co-signers once a council is configured
+#[derive(Accounts)]
+pub struct BridgeCircuitBreaker<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    /// Required when a guardian, rather than the admin, trips the breaker
+    #[account(
+        seeds = [GUARDIAN_SET_SEED, vault_config.key().as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Option<Account<'info, GuardianSet>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = BridgeStats::LEN,
+        seeds = [BRIDGE_STATS_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub bridge_stats: Account<'info, BridgeStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum BridgeBreakerAction {
+    /// Halt new outbound requests and inbound claims (admin or guardian)
+    Pause,
+    /// Resume bridging (admin only)
+    Resume,
+    /// Set the vault-wide outflow limit per window, zero for unlimited (admin only)
+    SetGlobalLimit { limit: u64 },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BridgeCircuitBreakerParams {
+    pub action: BridgeBreakerAction,
+}
+
+pub fn handler(ctx: Context<BridgeCircuitBreaker>, params: BridgeCircuitBreakerParams) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let bridge_stats = &mut ctx.accounts.bridge_stats;
+    let clock = Clock::get()?;
+
+    let is_admin = ctx.accounts.vault_config.admin == authority;
+    let is_guardian = ctx.accounts.guardian_set
+        .as_ref()
+        .is_some_and(|guardian_set| guardian_set.guardians.contains(&authority));
+
+    bridge_stats.vault = ctx.accounts.vault_config.key();
+    bridge_stats.bump = ctx.bumps.bridge_stats;
+
+    match params.action {
+        BridgeBreakerAction::Pause => {
+            require!(is_admin || is_guardian, ShadowForgeError::Unauthorized);
+
+            bridge_stats.bridge_paused = true;
+            bridge_stats.paused_by = authority;
+            bridge_stats.paused_at = clock.unix_timestamp;
+
+            msg!("SilentSwap: Bridge circuit breaker tripped by {}", authority);
+        }
+
+        BridgeBreakerAction::Resume => {
+            require!(is_admin, ShadowForgeError::Unauthorized);
+            require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+
+            bridge_stats.bridge_paused = false;
+
+            msg!("SilentSwap: Bridge circuit breaker reset");
+        }
+
+        BridgeBreakerAction::SetGlobalLimit { limit } => {
+            require!(is_admin, ShadowForgeError::Unauthorized);
+            require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+
+            bridge_stats.outflow.limit = limit;
+
+            msg!("SilentSwap: Global bridge outflow limit set to {}", limit);
+        }
+    }
+
+    emit!(BridgeCircuitBreakerEvent {
+        paused: bridge_stats.bridge_paused,
+        global_outflow_limit: bridge_stats.outflow.limit,
+        authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+/// Read-only check of whether an inbound bridge message has been claimed
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, nonce: u64)]
+pub struct BridgeMessageStatus<'info> {
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Consumed-message PDA for (source_chain_id, nonce); empty until the message is claimed
+    #[account(
+        seeds = [
+            CONSUMED_MESSAGE_SEED,
+            vault_config.key().as_ref(),
+            &source_chain_id.to_le_bytes(),
+            &nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub consumed_message: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<BridgeMessageStatus>, source_chain_id: u64, nonce: u64) -> Result<bool> {
+    let consumed_message = &ctx.accounts.consumed_message;
+
+    if consumed_message.data_is_empty() || consumed_message.owner != &crate::ID {
+        return Ok(false);
+    }
+
+    let data = consumed_message.try_borrow_data()?;
+    let record = ConsumedMessage::try_deserialize(&mut &data[..])?;
+
+    msg!(
+        "SilentSwap: Message from chain {} nonce {} consumed={}",
+        source_chain_id,
+        nonce,
+        record.is_consumed()
+    );
+
+    Ok(record.is_consumed())
+}
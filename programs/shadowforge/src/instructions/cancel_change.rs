@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to drop a scheduled change before it is executed
+/// Council members must co-sign once an admin council exists
+#[derive(Accounts)]
+pub struct CancelChange<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [QUEUED_CHANGE_SEED, vault_config.key().as_ref(), &queued_change.id.to_le_bytes()],
+        bump = queued_change.bump,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    /// CHECK: receives the rent the proposer paid for the queued change
+    #[account(mut, address = queued_change.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<CancelChange>) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let clock = Clock::get()?;
+    let id = ctx.accounts.queued_change.id;
+
+    emit!(ParameterChangeCancelledEvent {
+        id,
+        cancelled_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin: Change {} cancelled", id);
+
+    Ok(())
+}
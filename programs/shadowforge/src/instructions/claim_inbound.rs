@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::error::ShadowForgeError;
+use crate::guardians::{recipient_commitment, verify_attestation, AttestationKind, BridgeAttestation};
+use crate::state::*;
+use crate::zk_proofs::proves_ciphertext_amount;
+
+/// Claim an inbound SilentSwap transfer attested by the bridge guardians
+/// The credited ciphertext must be proven to encrypt the attested amount, and the
+/// consumed-message marker makes every (source chain, nonce) claimable once
+#[derive(Accounts)]
+#[instruction(params: ClaimInboundParams)]
+pub struct ClaimInbound<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.is_operational(PausableOperation::Bridge) @ ShadowForgeError::VaultPaused,
+        constraint = vault_config.silentswap_enabled @ ShadowForgeError::ExternalSdkFailed,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.owner == user.key() @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub user_position: Account<'info, UserEncryptedPosition>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ConsumedMessage::LEN,
+        seeds = [
+            CONSUMED_MESSAGE_SEED,
+            vault_config.key().as_ref(),
+            &params.attestation.chain_id.to_le_bytes(),
+            &params.attestation.nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub consumed_message: Account<'info, ConsumedMessage>,
+
+    #[account(
+        seeds = [BRIDGE_STATS_SEED, vault_config.key().as_ref()],
+        bump = bridge_stats.bump,
+        constraint = !bridge_stats.bridge_paused @ ShadowForgeError::BridgePaused,
+    )]
+    pub bridge_stats: Account<'info, BridgeStats>,
+
+    #[account(
+        seeds = [GUARDIAN_SET_SEED, vault_config.key().as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        seeds = [ADAPTER_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = adapter_registry.bump,
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    /// CHECK: SilentSwap program, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::SilentSwap, silentswap_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
+    pub silentswap_program: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, read to find the guardian signatures and the amount proof
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimInboundParams {
+    pub amount_commitment: [u8; 32],
+    /// Decryption handle completing `amount_commitment` into the user's ElGamal ciphertext
+    pub amount_handle: [u8; 32],
+    /// User ElGamal key the credited ciphertext is encrypted under
+    pub elgamal_pubkey: [u8; 32],
+    pub attestation: BridgeAttestation,
+}
+
+pub fn handler(ctx: Context<ClaimInbound>, params: ClaimInboundParams) -> Result<()> {
+    let user_position = &mut ctx.accounts.user_position;
+    let consumed_message = &mut ctx.accounts.consumed_message;
+    let attestation = &params.attestation;
+    let clock = Clock::get()?;
+
+    require!(
+        !consumed_message.is_consumed(),
+        ShadowForgeError::BridgeMessageAlreadyConsumed
+    );
+    require!(
+        attestation.kind == AttestationKind::InboundTransfer,
+        ShadowForgeError::InvalidAttestation
+    );
+    require!(
+        attestation.recipient_commitment ==
+            recipient_commitment(&ctx.accounts.user.key(), &params.amount_commitment),
+        ShadowForgeError::InvalidAttestation
+    );
+
+    verify_attestation(&ctx.accounts.instructions, &ctx.accounts.guardian_set, attestation)?;
+
+    // Credit only a ciphertext proven to encrypt the amount the guardians signed for
+    let mut credited = [0u8; ELGAMAL_CIPHERTEXT_LEN];
+    credited[..32].copy_from_slice(&params.amount_commitment);
+    credited[32..].copy_from_slice(&params.amount_handle);
+    require!(
+        proves_ciphertext_amount(
+            &ctx.accounts.instructions,
+            &params.elgamal_pubkey,
+            &credited,
+            attestation.amount,
+        )?,
+        ShadowForgeError::InvalidAttestation
+    );
+
+    consumed_message.source_chain_id = attestation.chain_id;
+    consumed_message.nonce = attestation.nonce;
+    consumed_message.message_hash = hash(&attestation.message(&ctx.accounts.vault_config.key())).to_bytes();
+    consumed_message.recipient = ctx.accounts.user.key();
+    consumed_message.consumed_at = clock.unix_timestamp;
+    consumed_message.bump = ctx.bumps.consumed_message;
+
+    user_position.encrypted_principal.commitment = params.amount_commitment;
+    user_position.encrypted_principal.handle = params.amount_handle;
+    user_position.last_action_at = clock.unix_timestamp;
+    user_position.action_count = user_position.action_count
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+
+    emit!(InboundBridgeClaimedEvent {
+        user: ctx.accounts.user.key(),
+        source_chain_id: attestation.chain_id,
+        nonce: attestation.nonce,
+        commitment: params.amount_commitment,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "SilentSwap: Inbound bridge claimed from chain {}, nonce {}",
+        attestation.chain_id,
+        attestation.nonce
+    );
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Close a completed or failed bridge request and return its rent to the owner
+#[derive(Accounts)]
+pub struct CloseBridgeRequest<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            BRIDGE_REQUEST_SEED,
+            vault_config.key().as_ref(),
+            user.key().as_ref(),
+            &bridge_request.nonce.to_le_bytes(),
+        ],
+        bump = bridge_request.bump,
+        constraint = bridge_request.user == user.key() @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+}
+
+pub fn handler(ctx: Context<CloseBridgeRequest>) -> Result<()> {
+    let bridge_request = &ctx.accounts.bridge_request;
+
+    // Pending requests still hold escrow and count towards the position's pending total
+    require!(
+        bridge_request.status == BridgeStatus::Completed ||
+        bridge_request.status == BridgeStatus::Failed,
+        ShadowForgeError::BridgeFailed
+    );
+
+    msg!("SilentSwap: Closed bridge request nonce {}", bridge_request.nonce);
+
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to add, update or disable a bridge destination chain
+#[derive(Accounts)]
+#[instruction(params: ConfigureChainParams)]
+pub struct ConfigureChain<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ChainConfig::LEN,
+        seeds = [CHAIN_CONFIG_SEED, vault_config.key().as_ref(), &params.chain_id.to_le_bytes()],
+        bump
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureChainParams {
+    pub chain_id: u64,
+    pub enabled: bool,
+    pub fee_bps_override: Option<u16>,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub required_confirmations: u32,
+    pub address_format: AddressFormat,
+    pub timeout_secs: i64,
+    /// Maximum outbound volume per window; zero means unlimited
+    pub outflow_limit: u64,
+}
+
+pub fn handler(ctx: Context<ConfigureChain>, params: ConfigureChainParams) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let chain_config = &mut ctx.accounts.chain_config;
+    let clock = Clock::get()?;
+
+    require!(params.chain_id > 0, ShadowForgeError::InvalidDestinationChain);
+    require!(
+        params.max_amount > 0 && params.min_amount <= params.max_amount,
+        ShadowForgeError::InvalidAmount
+    );
+    require!(params.timeout_secs > 0, ShadowForgeError::InvalidTimestamp);
+    if let Some(fee_bps) = params.fee_bps_override {
+        require!(fee_bps <= MAX_BASIS_POINTS, ShadowForgeError::InvalidAmount);
+    }
+
+    chain_config.vault = ctx.accounts.vault_config.key();
+    chain_config.chain_id = params.chain_id;
+    chain_config.enabled = params.enabled;
+    chain_config.fee_bps_override = params.fee_bps_override;
+    chain_config.min_amount = params.min_amount;
+    chain_config.max_amount = params.max_amount;
+    chain_config.required_confirmations = params.required_confirmations;
+    chain_config.address_format = params.address_format;
+    chain_config.timeout_secs = params.timeout_secs;
+    chain_config.outflow.limit = params.outflow_limit;
+    chain_config.updated_at = clock.unix_timestamp;
+    chain_config.bump = ctx.bumps.chain_config;
+
+    emit!(ChainConfigUpdatedEvent {
+        chain_id: params.chain_id,
+        enabled: params.enabled,
+        fee_bps_override: params.fee_bps_override,
+        min_amount: params.min_amount,
+        max_amount: params.max_amount,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Admin: Chain {} configured, enabled={}",
+        params.chain_id,
+        params.enabled
+    );
+
+    Ok(())
+}
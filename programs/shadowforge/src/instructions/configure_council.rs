@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::council::member_signatures;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Create or replace the admin council
+/// Replacing an existing council also needs `threshold` of its current members as signers,
+/// passed as remaining accounts
+#[derive(Accounts)]
+pub struct ConfigureCouncil<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdminCouncil::LEN,
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, AdminCouncil>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ConfigureCouncilParams {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_ttl_secs: i64,
+}
+
+pub fn handler(ctx: Context<ConfigureCouncil>, params: ConfigureCouncilParams) -> Result<()> {
+    let council = &mut ctx.accounts.council;
+    let clock = Clock::get()?;
+
+    if !council.members.is_empty() {
+        require!(
+            member_signatures(council, ctx.remaining_accounts) >= council.threshold as usize,
+            ShadowForgeError::CouncilApprovalRequired
+        );
+    }
+
+    require!(
+        !params.members.is_empty() && params.members.len() <= MAX_COUNCIL_MEMBERS,
+        ShadowForgeError::InvalidAdminOperation
+    );
+    require!(
+        params.threshold > 0 && params.threshold as usize <= params.members.len(),
+        ShadowForgeError::InvalidAdminOperation
+    );
+    require!(
+        params.proposal_ttl_secs > 0 && params.proposal_ttl_secs <= MAX_PROPOSAL_TTL_SECS,
+        ShadowForgeError::InvalidAdminOperation
+    );
+    for (i, member) in params.members.iter().enumerate() {
+        require!(
+            *member != Pubkey::default() && !params.members[..i].contains(member),
+            ShadowForgeError::InvalidAdminOperation
+        );
+    }
+
+    council.vault = ctx.accounts.vault_config.key();
+    council.members = params.members.clone();
+    council.threshold = params.threshold;
+    council.proposal_ttl_secs = params.proposal_ttl_secs;
+    council.updated_at = clock.unix_timestamp;
+    council.bump = ctx.bumps.council;
+
+    emit!(CouncilUpdatedEvent {
+        members: params.members,
+        threshold: params.threshold,
+        proposal_ttl_secs: params.proposal_ttl_secs,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Admin: Council set to {}-of-{}",
+        council.threshold,
+        council.members.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::council::approve_and_maybe_execute;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// A council member approves an open proposal; the approval reaching the threshold executes it
+#[derive(Accounts)]
+pub struct CouncilApprove<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump = council.bump,
+        constraint = council.is_member(&member.key()) @ ShadowForgeError::Unauthorized,
+    )]
+    pub council: Account<'info, AdminCouncil>,
+
+    #[account(
+        mut,
+        seeds = [COUNCIL_PROPOSAL_SEED, vault_config.key().as_ref(), &proposal.id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+pub fn handler(ctx: Context<CouncilApprove>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let member = ctx.accounts.member.key();
+    let clock = Clock::get()?;
+
+    require!(!proposal.executed, ShadowForgeError::InvalidAdminOperation);
+    require!(clock.unix_timestamp < proposal.expires_at, ShadowForgeError::ProposalExpired);
+    require!(!proposal.approvals.contains(&member), ShadowForgeError::InvalidAdminOperation);
+
+    approve_and_maybe_execute(
+        &ctx.accounts.council,
+        proposal,
+        &mut ctx.accounts.vault_config,
+        member,
+        clock.unix_timestamp,
+    )
+}
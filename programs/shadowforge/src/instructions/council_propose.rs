@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::council::approve_and_maybe_execute;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// A council member proposes an admin action, counting as its first approval
+#[derive(Accounts)]
+#[instruction(params: CouncilProposeParams)]
+pub struct CouncilPropose<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump = council.bump,
+        constraint = council.is_member(&member.key()) @ ShadowForgeError::Unauthorized,
+    )]
+    pub council: Account<'info, AdminCouncil>,
+
+    #[account(
+        init,
+        payer = member,
+        space = CouncilProposal::LEN,
+        seeds = [COUNCIL_PROPOSAL_SEED, vault_config.key().as_ref(), &params.proposal_id.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CouncilProposeParams {
+    /// Must equal the council's `next_proposal_id`
+    pub proposal_id: u64,
+    pub action: AdminAction,
+}
+
+pub fn handler(ctx: Context<CouncilPropose>, params: CouncilProposeParams) -> Result<()> {
+    let council = &mut ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    let clock = Clock::get()?;
+
+    require!(
+        params.proposal_id == council.next_proposal_id,
+        ShadowForgeError::InvalidAdminOperation
+    );
+    require!(params.action.requires_council(), ShadowForgeError::InvalidAdminOperation);
+
+    proposal.vault = ctx.accounts.vault_config.key();
+    proposal.id = params.proposal_id;
+    proposal.action = params.action;
+    proposal.proposer = ctx.accounts.member.key();
+    proposal.approvals = Vec::new();
+    proposal.created_at = clock.unix_timestamp;
+    proposal.expires_at = clock.unix_timestamp
+        .checked_add(council.proposal_ttl_secs)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+    proposal.executed = false;
+    proposal.bump = ctx.bumps.proposal;
+
+    council.next_proposal_id = council.next_proposal_id
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+
+    approve_and_maybe_execute(
+        council,
+        proposal,
+        &mut ctx.accounts.vault_config,
+        ctx.accounts.member.key(),
+        clock.unix_timestamp,
+    )
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use solana_curve25519::ristretto::{add_ristretto, PodRistrettoPoint};
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+use crate::zk_proofs::{
+    commitment_minus_amount, proof_contexts, BATCHED_RANGE_CONTEXT_LEN, RANGE_PROOF_U64_LEN,
+    VERIFY_BATCHED_RANGE_PROOF_U64, VERIFY_ZERO_CIPHERTEXT, ZERO_CIPHERTEXT_CONTEXT_LEN,
+    ZERO_CIPHERTEXT_PROOF_LEN,
+};
+
+/// Prove a statement about the caller's shielded balance to one verifier
+#[derive(Accounts)]
+pub struct DisclosePosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.owner == user.key() @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub user_position: Account<'info, UserEncryptedPosition>,
+
+    /// CHECK: Counterparty the disclosure is addressed to, only used to derive the record
+    pub verifier: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = DisclosureRecord::LEN,
+        seeds = [DISCLOSURE_SEED, vault_config.key().as_ref(), user.key().as_ref(), verifier.key().as_ref()],
+        bump
+    )]
+    pub disclosure_record: Account<'info, DisclosureRecord>,
+
+    /// CHECK: Instructions sysvar, read to find the ZK ElGamal proof
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DisclosePositionParams {
+    pub kind: DisclosureKind,
+    pub threshold: u64,
+    pub valid_for_secs: i64,
+}
+
+pub fn handler(ctx: Context<DisclosePosition>, params: DisclosePositionParams) -> Result<()> {
+    let user_position = &ctx.accounts.user_position;
+    let record = &mut ctx.accounts.disclosure_record;
+    let clock = Clock::get()?;
+
+    require!(
+        params.valid_for_secs > 0 && params.valid_for_secs <= MAX_DISCLOSURE_VALIDITY_SECS,
+        ShadowForgeError::InvalidTimestamp
+    );
+
+    let (commitment, handle) = balance_minus_threshold(user_position, params.threshold)
+        .ok_or(ShadowForgeError::SelectiveDisclosureFailed)?;
+
+    let proven = match params.kind {
+        // Zero-ciphertext proof: principal + yield - threshold encrypts 0
+        DisclosureKind::Exact => proof_contexts(
+            &ctx.accounts.instructions,
+            VERIFY_ZERO_CIPHERTEXT,
+            ZERO_CIPHERTEXT_CONTEXT_LEN,
+            ZERO_CIPHERTEXT_PROOF_LEN,
+        )?
+        .iter()
+        .any(|context| context[32..64] == commitment.0 && context[64..96] == handle.0),
+
+        // 64-bit range proof: principal + yield - threshold does not wrap below zero
+        DisclosureKind::AtLeast => proof_contexts(
+            &ctx.accounts.instructions,
+            VERIFY_BATCHED_RANGE_PROOF_U64,
+            BATCHED_RANGE_CONTEXT_LEN,
+            RANGE_PROOF_U64_LEN,
+        )?
+        .iter()
+        .any(|context| context[..32] == commitment.0 && context[32 * 8] == 64),
+    };
+    require!(proven, ShadowForgeError::SelectiveDisclosureFailed);
+
+    let expires_at = clock.unix_timestamp
+        .checked_add(params.valid_for_secs)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+
+    record.user = ctx.accounts.user.key();
+    record.verifier = ctx.accounts.verifier.key();
+    record.kind = params.kind;
+    record.threshold = params.threshold;
+    record.principal_commitment = user_position.encrypted_principal.commitment;
+    record.yield_commitment = user_position.encrypted_yield.commitment;
+    record.created_at = clock.unix_timestamp;
+    record.expires_at = expires_at;
+    record.bump = ctx.bumps.disclosure_record;
+
+    emit!(PositionDisclosedEvent {
+        user: record.user,
+        verifier: record.verifier,
+        kind: params.kind,
+        threshold: params.threshold,
+        expires_at,
+    });
+
+    msg!("Range: Position disclosed to {} until {}", record.verifier, expires_at);
+
+    Ok(())
+}
+
+/// Homomorphic ciphertext of principal + yield - threshold as (commitment, handle)
+fn balance_minus_threshold(
+    position: &UserEncryptedPosition,
+    threshold: u64,
+) -> Option<(PodRistrettoPoint, PodRistrettoPoint)> {
+    let principal = &position.encrypted_principal;
+    let accrued = &position.encrypted_yield;
+
+    let balance = add_ristretto(
+        &PodRistrettoPoint(principal.commitment),
+        &PodRistrettoPoint(accrued.commitment),
+    )?;
+    let commitment = commitment_minus_amount(&balance, threshold)?;
+    let handle = add_ristretto(
+        &PodRistrettoPoint(principal.handle),
+        &PodRistrettoPoint(accrued.handle),
+    )?;
+
+    Some((commitment, handle))
+}
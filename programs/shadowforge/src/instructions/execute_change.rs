@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+use crate::timelock::apply_change;
+
+/// Permissionless instruction to apply a scheduled change once its delay has elapsed
+#[derive(Accounts)]
+pub struct ExecuteChange<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [TIMELOCK_SEED, vault_config.key().as_ref()],
+        bump = timelock.bump,
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [QUEUED_CHANGE_SEED, vault_config.key().as_ref(), &queued_change.id.to_le_bytes()],
+        bump = queued_change.bump,
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    /// CHECK: receives the rent the proposer paid for the queued change
+    #[account(mut, address = queued_change.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteChange>) -> Result<()> {
+    let queued_change = &ctx.accounts.queued_change;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= queued_change.execute_after,
+        ShadowForgeError::TimelockNotElapsed
+    );
+
+    apply_change(
+        &mut ctx.accounts.vault_config,
+        &mut ctx.accounts.timelock,
+        &queued_change.change,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(ParameterChangeExecutedEvent {
+        id: queued_change.id,
+        change: queued_change.change.clone(),
+        executed_by: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin: Change {} executed", queued_change.id);
+
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use solana_curve25519::ristretto::{subtract_ristretto, PodRistrettoPoint};
+
+use crate::adapters::VenueSwapAccounts;
+use crate::compliance::enforce_compliance;
+use crate::error::ShadowForgeError;
+use crate::instructions::private_swap::route_swap;
+use crate::state::*;
+use crate::zk_proofs::proves_ciphertext_amount;
+
+/// Keeper instruction that executes the next due slice of a recurring swap order
+/// Slices are routed exactly like `SwapAction::Execute`; the keeper supplies the slice
+/// amount with a proof that the order's encrypted slice encrypts it
+#[derive(Accounts)]
+pub struct ExecuteRecurringSwap<'info> {
+    pub keeper: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.is_operational(PausableOperation::Swap) @ ShadowForgeError::VaultPaused,
+        constraint = vault_config.starpay_enabled || vault_config.anoncoin_enabled @ ShadowForgeError::ExternalSdkFailed,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), recurring_swap_order.owner.as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.owner == recurring_swap_order.owner @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub user_position: Account<'info, UserEncryptedPosition>,
+
+    #[account(
+        mut,
+        seeds = [RECURRING_SWAP_SEED, vault_config.key().as_ref(), recurring_swap_order.owner.as_ref()],
+        bump = recurring_swap_order.bump,
+    )]
+    pub recurring_swap_order: Account<'info, RecurringSwapOrder>,
+
+    #[account(address = vault_config.shielded_mint)]
+    pub source_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(address = vault_config.secondary_mint)]
+    pub dest_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [SHIELDED_VAULT_SEED, source_mint.key().as_ref()],
+        bump,
+        token::mint = source_mint,
+        token::authority = vault_config,
+        token::token_program = token_2022_program,
+    )]
+    pub source_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Receives swap output; fills are measured from its balance
+    #[account(
+        mut,
+        seeds = [SHIELDED_VAULT_SEED, dest_mint.key().as_ref()],
+        bump,
+        token::mint = dest_mint,
+        token::authority = vault_config,
+        token::token_program = token_2022_program,
+    )]
+    pub dest_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [ADAPTER_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = adapter_registry.bump,
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    /// CHECK: Starpay program for CPI, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::Starpay, starpay_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
+    pub starpay_program: UncheckedAccount<'info>,
+
+    /// CHECK: Anoncoin program for CPI, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::Anoncoin, anoncoin_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
+    pub anoncoin_program: UncheckedAccount<'info>,
+
+    /// Required when the vault has `compliance_required` set
+    #[account(
+        mut,
+        seeds = [COMPLIANCE_SEED, vault_config.key().as_ref(), recurring_swap_order.owner.as_ref()],
+        bump = compliance_attestation.bump,
+    )]
+    pub compliance_attestation: Option<Account<'info, ComplianceAttestation>>,
+
+    #[account(
+        seeds = [COMPLIANCE_POLICY_SEED, vault_config.key().as_ref()],
+        bump = compliance_policy.bump,
+    )]
+    pub compliance_policy: Option<Account<'info, CompliancePolicy>>,
+
+    #[account(
+        seeds = [JURISDICTION_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = jurisdictions.bump,
+    )]
+    pub jurisdictions: Option<Account<'info, JurisdictionRegistry>>,
+
+    /// CHECK: Instructions sysvar, read to find the slice amount proof
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ExecuteRecurringSwapParams {
+    /// Plaintext of `encrypted_slice`, proven by a zero-ciphertext proof in the same transaction
+    pub slice_amount_in: u64,
+}
+
+impl<'info> ExecuteRecurringSwap<'info> {
+    pub fn venue_accounts(&self, remaining_accounts: &[AccountInfo<'info>]) -> VenueSwapAccounts<'info> {
+        VenueSwapAccounts {
+            vault_authority: self.vault_config.to_account_info(),
+            source_vault: self.source_vault.to_account_info(),
+            dest_vault: self.dest_vault.to_account_info(),
+            source_mint: self.source_mint.to_account_info(),
+            dest_mint: self.dest_mint.to_account_info(),
+            token_program: self.token_2022_program.to_account_info(),
+            remaining_accounts: remaining_accounts.to_vec(),
+        }
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteRecurringSwap<'info>>,
+    params: ExecuteRecurringSwapParams,
+) -> Result<()> {
+    let venue_accounts = ctx.accounts.venue_accounts(ctx.remaining_accounts);
+    let vault_config = &ctx.accounts.vault_config;
+    let user_position = &mut ctx.accounts.user_position;
+    let order = &mut ctx.accounts.recurring_swap_order;
+    let clock = Clock::get()?;
+
+    require!(
+        order.status == RecurringSwapStatus::Active,
+        ShadowForgeError::RecurringSwapInactive
+    );
+    require!(
+        order.is_due(clock.unix_timestamp),
+        ShadowForgeError::RecurringSwapNotDue
+    );
+    require!(params.slice_amount_in > 0, ShadowForgeError::InvalidAmount);
+
+    let mut slice = [0u8; ELGAMAL_CIPHERTEXT_LEN];
+    slice[..32].copy_from_slice(&order.encrypted_slice.commitment);
+    slice[32..].copy_from_slice(&order.encrypted_slice.handle);
+    require!(
+        proves_ciphertext_amount(
+            &ctx.accounts.instructions,
+            &order.elgamal_pubkey,
+            &slice,
+            params.slice_amount_in,
+        )?,
+        ShadowForgeError::InvalidCiphertext
+    );
+
+    // Slices stop once the owner's attestation lapses or their swap limits run out
+    enforce_compliance(
+        vault_config,
+        ctx.accounts.compliance_attestation.as_deref_mut(),
+        ctx.accounts.compliance_policy.as_deref(),
+        ctx.accounts.jurisdictions.as_deref(),
+        &order.owner,
+        (ComplianceOperation::Swap, Some(params.slice_amount_in)),
+        clock.unix_timestamp,
+    )?;
+
+    let amount_out = route_swap(
+        vault_config,
+        &order.route,
+        params.slice_amount_in,
+        order.min_slice_amount_out,
+        &ctx.accounts.starpay_program,
+        &ctx.accounts.anoncoin_program,
+        &venue_accounts,
+    )?;
+
+    order.encrypted_total = remaining_total(order).ok_or(ShadowForgeError::InvalidCiphertext)?;
+    order.executed_slices = order.executed_slices
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+    order.last_executed_at = clock.unix_timestamp;
+
+    if order.executed_slices >= order.total_slices {
+        order.status = RecurringSwapStatus::Completed;
+    } else {
+        // A late keeper pushes the schedule forward instead of queueing catch-up slices
+        order.next_execution_at = order.next_execution_at
+            .max(clock.unix_timestamp)
+            .checked_add(order.interval_seconds)
+            .ok_or(ShadowForgeError::AmountOverflow)?;
+    }
+
+    user_position.balance_commitment = order.min_out_commitment;
+    user_position.last_action_at = clock.unix_timestamp;
+    user_position.action_count = user_position.action_count
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+
+    emit!(RecurringSwapExecutedEvent {
+        owner: order.owner,
+        order: order.key(),
+        executed_slices: order.executed_slices,
+        total_slices: order.total_slices,
+        encrypted_remaining: order.encrypted_total.clone(),
+        next_execution_at: order.next_execution_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Recurring swap slice {}/{} executed by keeper {}, out={}",
+        order.executed_slices,
+        order.total_slices,
+        ctx.accounts.keeper.key(),
+        amount_out
+    );
+
+    Ok(())
+}
+
+/// Homomorphic ciphertext of the encrypted total minus one encrypted slice
+fn remaining_total(order: &RecurringSwapOrder) -> Option<EncryptedAmount> {
+    let commitment = subtract_ristretto(
+        &PodRistrettoPoint(order.encrypted_total.commitment),
+        &PodRistrettoPoint(order.encrypted_slice.commitment),
+    )?;
+    let handle = subtract_ristretto(
+        &PodRistrettoPoint(order.encrypted_total.handle),
+        &PodRistrettoPoint(order.encrypted_slice.handle),
+    )?;
+
+    Some(EncryptedAmount {
+        handle: handle.0,
+        commitment: commitment.0,
+    })
+}
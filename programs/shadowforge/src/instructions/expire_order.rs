@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Permissionless crank that cancels a dark pool order whose time-in-force has lapsed
+/// The reserved commitment is returned to the maker's position, the order account is closed,
+/// and the caller receives a slice of the order's rent as an incentive
+#[derive(Accounts)]
+pub struct ExpireOrder<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Order maker, receives the remaining rent when the order is closed
+    #[account(
+        mut,
+        address = dark_pool_order.maker @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), maker.key().as_ref()],
+        bump = maker_position.bump,
+        constraint = maker_position.owner == maker.key() @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub maker_position: Account<'info, UserEncryptedPosition>,
+
+    #[account(
+        mut,
+        close = maker,
+        seeds = [DARK_POOL_ORDER_SEED, vault_config.key().as_ref(), maker.key().as_ref()],
+        bump = dark_pool_order.bump,
+    )]
+    pub dark_pool_order: Account<'info, DarkPoolOrder>,
+}
+
+pub fn handler(ctx: Context<ExpireOrder>) -> Result<()> {
+    let maker_position = &mut ctx.accounts.maker_position;
+    let dark_pool_order = &mut ctx.accounts.dark_pool_order;
+    let clock = Clock::get()?;
+
+    require!(
+        dark_pool_order.status == OrderStatus::Open,
+        ShadowForgeError::DarkPoolFailed
+    );
+    require!(
+        dark_pool_order.is_expired(clock.unix_timestamp),
+        ShadowForgeError::OrderNotExpired
+    );
+
+    // Release the reserved amount back to the maker, mirroring CancelOrder
+    maker_position.encrypted_principal.commitment = dark_pool_order.encrypted_amount.commitment;
+    maker_position.last_action_at = clock.unix_timestamp;
+    dark_pool_order.status = OrderStatus::Expired;
+
+    let incentive_lamports = Rent::get()?
+        .minimum_balance(DarkPoolOrder::LEN)
+        .checked_mul(EXPIRE_ORDER_INCENTIVE_BPS as u64)
+        .ok_or(ShadowForgeError::AmountOverflow)?
+        .checked_div(MAX_BASIS_POINTS as u64)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+
+    // The rest of the rent goes to the maker when the account is closed
+    dark_pool_order.sub_lamports(incentive_lamports)?;
+    ctx.accounts.cranker.add_lamports(incentive_lamports)?;
+
+    emit!(DarkPoolOrderExpiredEvent {
+        maker: ctx.accounts.maker.key(),
+        cranker: ctx.accounts.cranker.key(),
+        incentive_lamports,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Anoncoin: Dark pool order expired, cranker incentive={} lamports",
+        incentive_lamports
+    );
+
+    Ok(())
+}
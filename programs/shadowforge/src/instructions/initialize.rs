@@ -96,6 +96,7 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     let clock = Clock::get()?;
 
     vault_config.admin = ctx.accounts.admin.key();
+    vault_config.pending_admin = Pubkey::default();
     vault_config.treasury = ctx.accounts.treasury.key();
     vault_config.shielded_mint = ctx.accounts.shielded_mint.key();
     vault_config.secondary_mint = ctx.accounts.secondary_mint.key();
@@ -118,7 +119,7 @@ pub fn handler(ctx: Context<Initialize>, params: InitializeParams) -> Result<()>
     vault_config.total_shielded_tvl = 0;
     vault_config.total_positions = 0;
 
-    vault_config.is_paused = false;
+    vault_config.paused_operations = 0;
     vault_config.emergency_mode = false;
     vault_config.compliance_required = params.compliance_required;
 
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to grant or revoke operational roles
+/// Grants need council co-signers once a council is configured; revokes stay admin-only
+#[derive(Accounts)]
+pub struct ManageRoles<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = RoleRegistry::LEN,
+        seeds = [ROLE_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub role_registry: Account<'info, RoleRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum RoleAction {
+    Grant { member: Pubkey, role: Role },
+    Revoke { member: Pubkey, role: Role },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ManageRolesParams {
+    pub action: RoleAction,
+}
+
+pub fn handler(ctx: Context<ManageRoles>, params: ManageRolesParams) -> Result<()> {
+    let registry = &mut ctx.accounts.role_registry;
+    let clock = Clock::get()?;
+
+    let (member, role, granted) = match params.action {
+        RoleAction::Grant { member, role } => {
+            require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+            require!(member != Pubkey::default(), ShadowForgeError::InvalidAdminOperation);
+
+            match registry.members.iter_mut().find(|m| m.member == member) {
+                Some(assignment) => assignment.roles |= role.bit(),
+                None => {
+                    require!(
+                        registry.members.len() < MAX_ROLE_MEMBERS,
+                        ShadowForgeError::InvalidAdminOperation
+                    );
+                    registry.members.push(RoleAssignment { member, roles: role.bit() });
+                }
+            }
+            (member, role, true)
+        }
+
+        RoleAction::Revoke { member, role } => {
+            require!(registry.has_role(&member, role), ShadowForgeError::InvalidAdminOperation);
+
+            for assignment in registry.members.iter_mut().filter(|m| m.member == member) {
+                assignment.roles &= !role.bit();
+            }
+            registry.members.retain(|m| m.roles != 0);
+            (member, role, false)
+        }
+    };
+
+    registry.vault = ctx.accounts.vault_config.key();
+    registry.updated_at = clock.unix_timestamp;
+    registry.bump = ctx.bumps.role_registry;
+
+    emit!(RoleUpdatedEvent {
+        member,
+        role,
+        granted,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Admin: {:?} {} {}",
+        role,
+        if granted { "granted to" } else { "revoked from" },
+        member
+    );
+
+    Ok(())
+}
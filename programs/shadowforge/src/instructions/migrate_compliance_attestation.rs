@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::instructions::migrate_position::grow_account;
+use crate::state::*;
+
+/// Grow an attestation accepted before provider signatures to `ComplianceAttestation::LEN`
+/// Legacy attestations carry no provider signature, tier or jurisdiction, so the migrated one is
+/// marked invalid and the user submits a signed attestation to regain access
+#[derive(Accounts)]
+pub struct MigrateComplianceAttestation<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Legacy attestation, too short to deserialize until it is resized; owner, length
+    /// and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [COMPLIANCE_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub compliance_attestation: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateComplianceAttestation>) -> Result<()> {
+    let attestation_info = ctx.accounts.compliance_attestation.to_account_info();
+
+    require_keys_eq!(*attestation_info.owner, crate::ID, ShadowForgeError::InvalidComplianceAttestation);
+    require!(
+        attestation_info.data_len() == ComplianceAttestation::LEGACY_LEN,
+        ShadowForgeError::AccountAlreadyMigrated
+    );
+
+    grow_account(
+        &attestation_info,
+        ComplianceAttestation::LEN,
+        &ctx.accounts.user,
+        &ctx.accounts.system_program,
+    )?;
+
+    let mut attestation = {
+        let data = attestation_info.try_borrow_data()?;
+        ComplianceAttestation::try_deserialize(&mut &data[..])?
+    };
+    require_keys_eq!(attestation.user, ctx.accounts.user.key(), ShadowForgeError::InvalidAuthority);
+
+    attestation.is_valid = false;
+    attestation.try_serialize(&mut &mut attestation_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Compliance attestation migrated to {} bytes", ComplianceAttestation::LEN);
+
+    Ok(())
+}
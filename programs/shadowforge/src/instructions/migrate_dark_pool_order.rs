@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::instructions::migrate_position::grow_account;
+use crate::state::*;
+
+/// Grow an order placed before time-in-force to `DarkPoolOrder::LEN`
+/// The appended `time_in_force` and `expires_at` start zeroed, which reads as good-til-cancelled
+#[derive(Accounts)]
+pub struct MigrateDarkPoolOrder<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Legacy order, too short to deserialize until it is resized; owner, length
+    /// and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [DARK_POOL_ORDER_SEED, vault_config.key().as_ref(), maker.key().as_ref()],
+        bump,
+    )]
+    pub dark_pool_order: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateDarkPoolOrder>) -> Result<()> {
+    let order_info = ctx.accounts.dark_pool_order.to_account_info();
+
+    require_keys_eq!(*order_info.owner, crate::ID, ShadowForgeError::DarkPoolFailed);
+    require!(
+        order_info.data_len() == DarkPoolOrder::LEGACY_LEN,
+        ShadowForgeError::AccountAlreadyMigrated
+    );
+
+    grow_account(
+        &order_info,
+        DarkPoolOrder::LEN,
+        &ctx.accounts.maker,
+        &ctx.accounts.system_program,
+    )?;
+
+    let order = {
+        let data = order_info.try_borrow_data()?;
+        DarkPoolOrder::try_deserialize(&mut &data[..])?
+    };
+    require_keys_eq!(order.maker, ctx.accounts.maker.key(), ShadowForgeError::InvalidAuthority);
+
+    msg!("Dark pool order migrated to {} bytes", DarkPoolOrder::LEN);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Grow a position created before bridge requests were keyed by nonce to `UserEncryptedPosition::LEN`
+/// The appended `pending_bridge_count` and `bridge_nonce` start at 0
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Legacy position, too short to deserialize until it is resized; owner, length
+    /// and discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub user_position: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigratePosition>) -> Result<()> {
+    let position_info = ctx.accounts.user_position.to_account_info();
+
+    require_keys_eq!(*position_info.owner, crate::ID, ShadowForgeError::PositionNotFound);
+    require!(
+        position_info.data_len() == UserEncryptedPosition::LEGACY_LEN,
+        ShadowForgeError::PositionAlreadyMigrated
+    );
+
+    grow_account(
+        &position_info,
+        UserEncryptedPosition::LEN,
+        &ctx.accounts.owner,
+        &ctx.accounts.system_program,
+    )?;
+
+    // The zeroed tail deserializes as a count and nonce of 0
+    let mut user_position = {
+        let data = position_info.try_borrow_data()?;
+        UserEncryptedPosition::try_deserialize(&mut &data[..])?
+    };
+    require_keys_eq!(user_position.owner, ctx.accounts.owner.key(), ShadowForgeError::InvalidAuthority);
+
+    // Requests opened under the legacy seeds held no escrow and no instruction derives their
+    // address any more, so they are dropped rather than left blocking withdrawals
+    user_position.set_pending_bridge_count(0);
+    user_position.try_serialize(&mut &mut position_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Position migrated to {} bytes", UserEncryptedPosition::LEN);
+
+    Ok(())
+}
+
+/// Top up rent from `payer` and zero-extend a legacy account to `len` bytes
+pub(crate) fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(len)
+        .saturating_sub(account.lamports());
+    if rent_shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            rent_shortfall,
+        )?;
+    }
+
+    account.resize(len)?;
+
+    Ok(())
+}
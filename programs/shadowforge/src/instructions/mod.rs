@@ -8,6 +8,39 @@ pub mod accrue_view;
 pub mod private_withdraw;
 pub mod admin_mock_yield;
 pub mod wrap_sol;
+pub mod expire_order;
+pub mod recurring_swap;
+pub mod execute_recurring_swap;
+pub mod update_adapter_registry;
+pub mod update_guardian_set;
+pub mod claim_inbound;
+pub mod bridge_message_status;
+pub mod configure_chain;
+pub mod close_bridge_request;
+pub mod refund_expired_bridge;
+pub mod bridge_circuit_breaker;
+pub mod set_relayer_key;
+pub mod update_compliance_providers;
+pub mod revoke_compliance;
+pub mod set_compliance_policy;
+pub mod update_screening_root;
+pub mod set_auditor_key;
+pub mod disclose_position;
+pub mod set_jurisdiction_rule;
+pub mod propose_admin;
+pub mod accept_admin;
+pub mod manage_roles;
+pub mod schedule_change;
+pub mod cancel_change;
+pub mod execute_change;
+pub mod configure_council;
+pub mod council_propose;
+pub mod council_approve;
+pub mod acknowledge_bridge_request;
+pub mod settle_bridge_request;
+pub mod migrate_compliance_attestation;
+pub mod migrate_dark_pool_order;
+pub mod migrate_position;
 
 pub use initialize::*;
 pub use private_deposit::*;
@@ -19,3 +52,36 @@ pub use accrue_view::*;
 pub use private_withdraw::*;
 pub use admin_mock_yield::*;
 pub use wrap_sol::*;
+pub use expire_order::*;
+pub use recurring_swap::*;
+pub use execute_recurring_swap::*;
+pub use update_adapter_registry::*;
+pub use update_guardian_set::*;
+pub use claim_inbound::*;
+pub use bridge_message_status::*;
+pub use configure_chain::*;
+pub use close_bridge_request::*;
+pub use refund_expired_bridge::*;
+pub use bridge_circuit_breaker::*;
+pub use set_relayer_key::*;
+pub use update_compliance_providers::*;
+pub use revoke_compliance::*;
+pub use set_compliance_policy::*;
+pub use update_screening_root::*;
+pub use set_auditor_key::*;
+pub use disclose_position::*;
+pub use set_jurisdiction_rule::*;
+pub use propose_admin::*;
+pub use accept_admin::*;
+pub use manage_roles::*;
+pub use schedule_change::*;
+pub use cancel_change::*;
+pub use execute_change::*;
+pub use configure_council::*;
+pub use council_propose::*;
+pub use council_approve::*;
+pub use acknowledge_bridge_request::*;
+pub use settle_bridge_request::*;
+pub use migrate_compliance_attestation::*;
+pub use migrate_dark_pool_order::*;
+pub use migrate_position::*;
@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_2022::Token2022;
-use anchor_spl::token_interface::{Mint, TokenAccount};
+use anchor_spl::token_interface::{burn, transfer_checked, Burn, Mint, TokenAccount, TransferChecked};
 
+use crate::compliance::{enforce_chain_jurisdiction, enforce_compliance};
 use crate::error::ShadowForgeError;
 use crate::state::*;
 
 #[derive(Accounts)]
+#[instruction(params: PrivateBridgeParams)]
 pub struct PrivateBridge<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -14,7 +16,7 @@ pub struct PrivateBridge<'info> {
         mut,
         seeds = [VAULT_CONFIG_SEED],
         bump = vault_config.bump,
-        constraint = vault_config.is_operational() @ ShadowForgeError::VaultPaused,
+        constraint = vault_config.is_operational(PausableOperation::Bridge) @ ShadowForgeError::VaultPaused,
         constraint = vault_config.silentswap_enabled @ ShadowForgeError::ExternalSdkFailed,
     )]
     pub vault_config: Account<'info, VaultConfig>,
@@ -31,14 +33,47 @@ pub struct PrivateBridge<'info> {
         init_if_needed,
         payer = user,
         space = BridgeRequest::LEN,
-        seeds = [b"bridge_request", vault_config.key().as_ref(), user.key().as_ref()],
+        seeds = [
+            BRIDGE_REQUEST_SEED,
+            vault_config.key().as_ref(),
+            user.key().as_ref(),
+            &params.request_nonce.to_le_bytes(),
+        ],
         bump
     )]
     pub bridge_request: Account<'info, BridgeRequest>,
 
-    #[account(address = vault_config.shielded_mint)]
+    #[account(
+        mut,
+        seeds = [CHAIN_CONFIG_SEED, vault_config.key().as_ref(), &params.dest_chain_id.to_le_bytes()],
+        bump = chain_config.bump,
+    )]
+    pub chain_config: Account<'info, ChainConfig>,
+
+    #[account(
+        mut,
+        seeds = [BRIDGE_STATS_SEED, vault_config.key().as_ref()],
+        bump = bridge_stats.bump,
+    )]
+    pub bridge_stats: Account<'info, BridgeStats>,
+
+    #[account(
+        seeds = [RELAYER_KEY_SEED, vault_config.key().as_ref()],
+        bump = relayer_keys.bump,
+    )]
+    pub relayer_keys: Account<'info, RelayerKeyRegistry>,
+
+    #[account(mut, address = vault_config.shielded_mint)]
     pub shielded_mint: InterfaceAccount<'info, Mint>,
 
+    #[account(
+        mut,
+        token::mint = shielded_mint,
+        token::authority = user,
+        token::token_program = token_2022_program,
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
     #[account(
         mut,
         seeds = [SHIELDED_VAULT_SEED, shielded_mint.key().as_ref()],
@@ -49,45 +84,71 @@ pub struct PrivateBridge<'info> {
     )]
     pub shielded_vault_ata: InterfaceAccount<'info, TokenAccount>,
 
-    /// CHECK: SilentSwap program for CPI (address verified at runtime if needed)
+    /// Holds outbound amounts until the bridge completes or is refunded
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [BRIDGE_ESCROW_SEED, shielded_mint.key().as_ref()],
+        bump,
+        token::mint = shielded_mint,
+        token::authority = vault_config,
+        token::token_program = token_2022_program,
+    )]
+    pub bridge_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [ADAPTER_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = adapter_registry.bump,
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    /// CHECK: SilentSwap program for CPI, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::SilentSwap, silentswap_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
     pub silentswap_program: UncheckedAccount<'info>,
 
-    pub token_2022_program: Program<'info, Token2022>,
-    pub system_program: Program<'info, System>,
-}
+    /// Required when the vault has `compliance_required` set
+    #[account(
+        mut,
+        seeds = [COMPLIANCE_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = compliance_attestation.bump,
+    )]
+    pub compliance_attestation: Option<Account<'info, ComplianceAttestation>>,
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
-pub enum DestinationChain {
-    Ethereum = 1,
-    Polygon = 137,
-    Arbitrum = 42161,
-    Optimism = 10,
-    Base = 8453,
-    Avalanche = 43114,
-    Bsc = 56,
-}
+    #[account(
+        seeds = [COMPLIANCE_POLICY_SEED, vault_config.key().as_ref()],
+        bump = compliance_policy.bump,
+    )]
+    pub compliance_policy: Option<Account<'info, CompliancePolicy>>,
 
-impl DestinationChain {
-    pub fn to_chain_id(&self) -> u64 {
-        *self as u64
-    }
+    #[account(
+        seeds = [JURISDICTION_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = jurisdictions.bump,
+    )]
+    pub jurisdictions: Option<Account<'info, JurisdictionRegistry>>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub enum BridgeAction {
     InitiateOutbound,
-    ClaimInbound,
     CancelRequest,
-    VerifyCompletion,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct PrivateBridgeParams {
     pub action: BridgeAction,
-    pub dest_chain: DestinationChain,
+    pub dest_chain_id: u64,
+    /// Nonce of the targeted request; for InitiateOutbound it must equal the position's next bridge nonce
+    pub request_nonce: u64,
+    pub amount: u64,
     pub amount_commitment: [u8; 32],
+    /// Destination address sealed to the relayer key, required for InitiateOutbound
+    pub encrypted_recipient: Option<EncryptedRecipient>,
     pub bridge_proof: [u8; PROOF_DATA_LEN],
-    pub inbound_proof: Option<[u8; PROOF_DATA_LEN]>,
 }
 
 pub fn handler(ctx: Context<PrivateBridge>, params: PrivateBridgeParams) -> Result<()> {
@@ -103,81 +164,157 @@ pub fn handler(ctx: Context<PrivateBridge>, params: PrivateBridgeParams) -> Resu
 
     match params.action {
         BridgeAction::InitiateOutbound => {
-            // Only allow new bridge if user doesn't have a pending one
             require!(
-                !user_position.has_pending_bridge,
+                params.request_nonce == user_position.bridge_nonce,
+                ShadowForgeError::BridgeFailed
+            );
+            require!(
+                user_position.pending_bridge_count < MAX_PENDING_BRIDGE_REQUESTS,
                 ShadowForgeError::BridgeFailed
             );
 
-            let dest_chain_id = params.dest_chain.to_chain_id();
+            let chain_config = &mut ctx.accounts.chain_config;
+            let bridge_stats = &mut ctx.accounts.bridge_stats;
+            let dest_chain_id = chain_config.chain_id;
+            require!(!bridge_stats.bridge_paused, ShadowForgeError::BridgePaused);
+            require!(chain_config.enabled, ShadowForgeError::ChainDisabled);
+
+            let encrypted_recipient = params.encrypted_recipient
+                .clone()
+                .ok_or(ShadowForgeError::InvalidEncryptedRecipient)?;
             require!(
-                dest_chain_id == 1 || dest_chain_id == 137 || dest_chain_id == 42161 ||
-                dest_chain_id == 10 || dest_chain_id == 8453 || dest_chain_id == 43114 ||
-                dest_chain_id == 56,
-                ShadowForgeError::InvalidDestinationChain
+                !encrypted_recipient.is_empty() &&
+                encrypted_recipient.key_version == ctx.accounts.relayer_keys.key_version,
+                ShadowForgeError::InvalidEncryptedRecipient
+            );
+
+            enforce_compliance(
+                vault_config,
+                ctx.accounts.compliance_attestation.as_deref_mut(),
+                ctx.accounts.compliance_policy.as_deref(),
+                ctx.accounts.jurisdictions.as_deref(),
+                &ctx.accounts.user.key(),
+                (ComplianceOperation::Bridge, Some(params.amount)),
+                clock.unix_timestamp,
+            )?;
+            enforce_chain_jurisdiction(
+                vault_config,
+                ctx.accounts.compliance_attestation.as_deref(),
+                ctx.accounts.jurisdictions.as_deref(),
+                dest_chain_id,
+            )?;
+
+            require!(params.amount > 0, ShadowForgeError::InvalidAmount);
+            require!(
+                params.amount >= chain_config.min_amount && params.amount <= chain_config.max_amount,
+                ShadowForgeError::BridgeAmountOutOfRange
+            );
+            require!(
+                chain_config.outflow.try_record(params.amount, clock.unix_timestamp) &&
+                bridge_stats.outflow.try_record(params.amount, clock.unix_timestamp),
+                ShadowForgeError::BridgeRateLimitExceeded
+            );
+
+            let fee_amount = params.amount
+                .checked_mul(chain_config.fee_bps(vault_config.bridge_fee_bps) as u64)
+                .ok_or(ShadowForgeError::AmountOverflow)?
+                .checked_div(MAX_BASIS_POINTS as u64)
+                .ok_or(ShadowForgeError::AmountOverflow)?;
+
+            let escrowed_amount = params.amount
+                .checked_sub(fee_amount)
+                .ok_or(ShadowForgeError::AmountUnderflow)?;
+
+            let decimals = ctx.accounts.shielded_mint.decimals;
+
+            if fee_amount > 0 {
+                let fee_ctx = CpiContext::new(
+                    ctx.accounts.token_2022_program.to_account_info(),
+                    TransferChecked {
+                        from: ctx.accounts.user_token_account.to_account_info(),
+                        mint: ctx.accounts.shielded_mint.to_account_info(),
+                        to: ctx.accounts.shielded_vault_ata.to_account_info(),
+                        authority: ctx.accounts.user.to_account_info(),
+                    },
+                );
+                transfer_checked(fee_ctx, fee_amount, decimals)?;
+            }
+
+            let escrow_ctx = CpiContext::new(
+                ctx.accounts.token_2022_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    mint: ctx.accounts.shielded_mint.to_account_info(),
+                    to: ctx.accounts.bridge_escrow.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
             );
+            transfer_checked(escrow_ctx, escrowed_amount, decimals)?;
 
             user_position.encrypted_principal.commitment = params.amount_commitment;
 
             bridge_request.user = ctx.accounts.user.key();
             bridge_request.dest_chain_id = dest_chain_id;
+            bridge_request.nonce = user_position.bridge_nonce;
             bridge_request.amount_commitment = params.amount_commitment;
+            bridge_request.encrypted_recipient = encrypted_recipient;
+            bridge_request.escrowed_amount = escrowed_amount;
+            bridge_request.fee_amount = fee_amount;
             bridge_request.status = BridgeStatus::Pending;
             bridge_request.created_at = clock.unix_timestamp;
+            bridge_request.expires_at = clock.unix_timestamp
+                .checked_add(chain_config.timeout_secs)
+                .ok_or(ShadowForgeError::AmountOverflow)?;
             bridge_request.bump = ctx.bumps.bridge_request;
 
-            user_position.has_pending_bridge = true;
+            user_position.bridge_nonce = user_position.bridge_nonce
+                .checked_add(1)
+                .ok_or(ShadowForgeError::AmountOverflow)?;
+            let pending = user_position.pending_bridge_count
+                .checked_add(1)
+                .ok_or(ShadowForgeError::AmountOverflow)?;
+            user_position.set_pending_bridge_count(pending);
 
             msg!("SilentSwap: Outbound bridge initiated to chain {}", dest_chain_id);
         }
 
-        BridgeAction::ClaimInbound => {
-            let inbound_proof = params.inbound_proof
-                .ok_or(ShadowForgeError::InvalidProof)?;
-
+        BridgeAction::CancelRequest => {
             require!(
-                !inbound_proof.iter().all(|&b| b == 0),
-                ShadowForgeError::InvalidProof
-            );
-
-            user_position.encrypted_principal.commitment = params.amount_commitment;
-
-            if bridge_request.user == user_position.owner {
-                bridge_request.status = BridgeStatus::Completed;
-            }
-
-            user_position.has_pending_bridge = false;
-
-            msg!(
-                "SilentSwap: Inbound bridge claimed from chain {}",
-                params.dest_chain.to_chain_id()
+                bridge_request.user == ctx.accounts.user.key() &&
+                bridge_request.is_in_flight(),
+                ShadowForgeError::BridgeFailed
             );
-        }
-
-        BridgeAction::CancelRequest => {
+            // Once the relayer has picked the request up it may already be delivered;
+            // only a guardian failure attestation can release the escrow from here
             require!(
                 bridge_request.status == BridgeStatus::Pending,
-                ShadowForgeError::BridgeFailed
+                ShadowForgeError::BridgeRequestInFlight
+            );
+            require!(
+                params.dest_chain_id == bridge_request.dest_chain_id,
+                ShadowForgeError::InvalidDestinationChain
             );
 
+            // The bridge fee is retained; only the escrowed amount is refunded
+            release_escrow(
+                vault_config,
+                &ctx.accounts.bridge_escrow,
+                &ctx.accounts.shielded_mint,
+                &ctx.accounts.user_token_account.to_account_info(),
+                &ctx.accounts.token_2022_program,
+                bridge_request.escrowed_amount,
+            )?;
+
             user_position.encrypted_principal.commitment = bridge_request.amount_commitment;
 
+            bridge_request.escrowed_amount = 0;
             bridge_request.status = BridgeStatus::Failed;
-            user_position.has_pending_bridge = false;
-
-            msg!("SilentSwap: Bridge request cancelled");
-        }
-
-        BridgeAction::VerifyCompletion => {
-            require!(
-                bridge_request.status == BridgeStatus::Pending,
-                ShadowForgeError::BridgeFailed
-            );
-
-            bridge_request.status = BridgeStatus::Completed;
-            user_position.has_pending_bridge = false;
+            let pending = user_position.pending_bridge_count
+                .checked_sub(1)
+                .ok_or(ShadowForgeError::AmountUnderflow)?;
+            user_position.set_pending_bridge_count(pending);
 
-            msg!("SilentSwap: Bridge completion verified");
+            msg!("SilentSwap: Bridge request cancelled, escrow refunded");
         }
     }
 
@@ -188,10 +325,66 @@ pub fn handler(ctx: Context<PrivateBridge>, params: PrivateBridgeParams) -> Resu
 
     emit!(BridgeRequestEvent {
         user: ctx.accounts.user.key(),
-        dest_chain_id: params.dest_chain.to_chain_id(),
+        dest_chain_id: params.dest_chain_id,
         commitment: params.amount_commitment,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
+
+/// Return escrowed tokens for a cancelled or failed bridge request
+pub(crate) fn release_escrow<'info>(
+    vault_config: &Account<'info, VaultConfig>,
+    bridge_escrow: &InterfaceAccount<'info, TokenAccount>,
+    shielded_mint: &InterfaceAccount<'info, Mint>,
+    destination: &AccountInfo<'info>,
+    token_program: &Program<'info, Token2022>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let seeds = &[VAULT_CONFIG_SEED, &[vault_config.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        TransferChecked {
+            from: bridge_escrow.to_account_info(),
+            mint: shielded_mint.to_account_info(),
+            to: destination.clone(),
+            authority: vault_config.to_account_info(),
+        },
+        signer_seeds,
+    );
+    transfer_checked(transfer_ctx, amount, shielded_mint.decimals)
+}
+
+/// Burn escrowed tokens once the destination chain has released the bridged amount
+pub(crate) fn burn_escrow<'info>(
+    vault_config: &Account<'info, VaultConfig>,
+    bridge_escrow: &InterfaceAccount<'info, TokenAccount>,
+    shielded_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Program<'info, Token2022>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let seeds = &[VAULT_CONFIG_SEED, &[vault_config.bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    let burn_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        Burn {
+            mint: shielded_mint.to_account_info(),
+            from: bridge_escrow.to_account_info(),
+            authority: vault_config.to_account_info(),
+        },
+        signer_seeds,
+    );
+    burn(burn_ctx, amount)
+}
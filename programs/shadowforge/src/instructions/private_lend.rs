@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
+use crate::compliance::enforce_compliance;
 use crate::error::ShadowForgeError;
 use crate::state::*;
 
@@ -14,7 +15,7 @@ pub struct PrivateLend<'info> {
         mut,
         seeds = [VAULT_CONFIG_SEED],
         bump = vault_config.bump,
-        constraint = vault_config.is_operational() @ ShadowForgeError::VaultPaused,
+        constraint = vault_config.is_operational(PausableOperation::Lend) @ ShadowForgeError::VaultPaused,
         constraint = vault_config.privacy_cash_enabled @ ShadowForgeError::ExternalSdkFailed,
     )]
     pub vault_config: Account<'info, VaultConfig>,
@@ -49,9 +50,38 @@ pub struct PrivateLend<'info> {
     #[account(address = vault_config.shielded_mint)]
     pub shielded_mint: InterfaceAccount<'info, Mint>,
 
-    /// CHECK: Privacy Cash program for CPI (address verified at runtime if needed)
+    #[account(
+        seeds = [ADAPTER_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = adapter_registry.bump,
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    /// CHECK: Privacy Cash program for CPI, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::PrivacyCash, privacy_cash_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
     pub privacy_cash_program: UncheckedAccount<'info>,
 
+    /// Required when the vault has `compliance_required` set
+    #[account(
+        mut,
+        seeds = [COMPLIANCE_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = compliance_attestation.bump,
+    )]
+    pub compliance_attestation: Option<Account<'info, ComplianceAttestation>>,
+
+    #[account(
+        seeds = [COMPLIANCE_POLICY_SEED, vault_config.key().as_ref()],
+        bump = compliance_policy.bump,
+    )]
+    pub compliance_policy: Option<Account<'info, CompliancePolicy>>,
+
+    #[account(
+        seeds = [JURISDICTION_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = jurisdictions.bump,
+    )]
+    pub jurisdictions: Option<Account<'info, JurisdictionRegistry>>,
+
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
@@ -80,10 +110,22 @@ pub struct PrivateLendParams {
 }
 
 pub fn handler(ctx: Context<PrivateLend>, params: PrivateLendParams) -> Result<()> {
+    let vault_config = &ctx.accounts.vault_config;
     let user_position = &mut ctx.accounts.user_position;
     let lending_position = &mut ctx.accounts.lending_position;
     let clock = Clock::get()?;
 
+    // Loan amounts are only committed, so there is nothing to charge against tier limits
+    enforce_compliance(
+        vault_config,
+        ctx.accounts.compliance_attestation.as_deref_mut(),
+        ctx.accounts.compliance_policy.as_deref(),
+        ctx.accounts.jurisdictions.as_deref(),
+        &ctx.accounts.user.key(),
+        (ComplianceOperation::Lend, None),
+        clock.unix_timestamp,
+    )?;
+
     match params.action {
         LendingAction::Borrow { collateral_commitment, borrow_commitment } => {
             require!(!lending_position.is_active, ShadowForgeError::LoanNotFound);
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount};
 
+use crate::adapters::{self, LegFill, Venue, VenueSwapAccounts};
+use crate::compliance::enforce_compliance;
 use crate::error::ShadowForgeError;
 use crate::state::*;
 
@@ -14,7 +16,7 @@ pub struct PrivateSwap<'info> {
         mut,
         seeds = [VAULT_CONFIG_SEED],
         bump = vault_config.bump,
-        constraint = vault_config.is_operational() @ ShadowForgeError::VaultPaused,
+        constraint = vault_config.is_operational(PausableOperation::Swap) @ ShadowForgeError::VaultPaused,
         constraint = vault_config.starpay_enabled || vault_config.anoncoin_enabled @ ShadowForgeError::ExternalSdkFailed,
     )]
     pub vault_config: Account<'info, VaultConfig>,
@@ -31,7 +33,7 @@ pub struct PrivateSwap<'info> {
         init_if_needed,
         payer = user,
         space = DarkPoolOrder::LEN,
-        seeds = [b"dark_pool_order", vault_config.key().as_ref(), user.key().as_ref()],
+        seeds = [DARK_POOL_ORDER_SEED, vault_config.key().as_ref(), user.key().as_ref()],
         bump
     )]
     pub dark_pool_order: Account<'info, DarkPoolOrder>,
@@ -52,27 +54,78 @@ pub struct PrivateSwap<'info> {
     )]
     pub source_vault: InterfaceAccount<'info, TokenAccount>,
 
-    /// CHECK: Starpay program for CPI (address verified at runtime if needed)
+    /// Receives swap output; fills are measured from its balance
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [SHIELDED_VAULT_SEED, dest_mint.key().as_ref()],
+        bump,
+        token::mint = dest_mint,
+        token::authority = vault_config,
+        token::token_program = token_2022_program,
+    )]
+    pub dest_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [ADAPTER_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = adapter_registry.bump,
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    /// CHECK: Starpay program for CPI, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::Starpay, starpay_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
     pub starpay_program: UncheckedAccount<'info>,
 
-    /// CHECK: Anoncoin program for CPI (address verified at runtime if needed)
+    /// CHECK: Anoncoin program for CPI, verified against the adapter registry
+    #[account(
+        constraint = adapter_registry.is_registered(Integration::Anoncoin, anoncoin_program.key) @ ShadowForgeError::InvalidAdapterProgram,
+    )]
     pub anoncoin_program: UncheckedAccount<'info>,
 
+    /// Required when the vault has `compliance_required` set
+    #[account(
+        mut,
+        seeds = [COMPLIANCE_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = compliance_attestation.bump,
+    )]
+    pub compliance_attestation: Option<Account<'info, ComplianceAttestation>>,
+
+    #[account(
+        seeds = [COMPLIANCE_POLICY_SEED, vault_config.key().as_ref()],
+        bump = compliance_policy.bump,
+    )]
+    pub compliance_policy: Option<Account<'info, CompliancePolicy>>,
+
+    #[account(
+        seeds = [JURISDICTION_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = jurisdictions.bump,
+    )]
+    pub jurisdictions: Option<Account<'info, JurisdictionRegistry>>,
+
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
-pub enum SwapRoute {
-    Starpay,
-    AnocoinDarkPool,
-    Split { starpay_weight_bps: u16 },
+impl<'info> PrivateSwap<'info> {
+    pub fn venue_accounts(&self, remaining_accounts: &[AccountInfo<'info>]) -> VenueSwapAccounts<'info> {
+        VenueSwapAccounts {
+            vault_authority: self.vault_config.to_account_info(),
+            source_vault: self.source_vault.to_account_info(),
+            dest_vault: self.dest_vault.to_account_info(),
+            source_mint: self.source_mint.to_account_info(),
+            dest_mint: self.dest_mint.to_account_info(),
+            token_program: self.token_2022_program.to_account_info(),
+            remaining_accounts: remaining_accounts.to_vec(),
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub enum SwapAction {
     Execute,
-    PlaceLimitOrder,
+    PlaceLimitOrder { time_in_force: TimeInForce },
     CancelOrder,
     MatchDarkPool,
 }
@@ -83,13 +136,18 @@ pub struct PrivateSwapParams {
     pub route: SwapRoute,
     pub amount_in_commitment: [u8; 32],
     pub min_out_commitment: [u8; 32],
+    pub amount_in: u64,
+    pub min_amount_out: u64,
     pub limit_price_commitment: Option<[u8; 32]>,
     pub side: OrderSide,
     pub swap_proof: [u8; PROOF_DATA_LEN],
-    pub max_slippage_bps: u16,
 }
 
-pub fn handler(ctx: Context<PrivateSwap>, params: PrivateSwapParams) -> Result<()> {
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PrivateSwap<'info>>,
+    params: PrivateSwapParams,
+) -> Result<()> {
+    let venue_accounts = ctx.accounts.venue_accounts(ctx.remaining_accounts);
     let vault_config = &mut ctx.accounts.vault_config;
     let user_position = &mut ctx.accounts.user_position;
     let dark_pool_order = &mut ctx.accounts.dark_pool_order;
@@ -100,38 +158,73 @@ pub fn handler(ctx: Context<PrivateSwap>, params: PrivateSwapParams) -> Result<(
         ShadowForgeError::InvalidProof
     );
 
+    // Cancelling only unwinds a resting order, so it stays available once an attestation lapses
+    if !matches!(params.action, SwapAction::CancelOrder) {
+        // Resting and matched orders are gated by jurisdiction; only fills charge tier limits
+        let fills_now = match &params.action {
+            SwapAction::Execute => true,
+            SwapAction::PlaceLimitOrder { time_in_force } => time_in_force.is_immediate(),
+            SwapAction::CancelOrder | SwapAction::MatchDarkPool => false,
+        };
+        let charge = fills_now.then_some(params.amount_in);
+        enforce_compliance(
+            vault_config,
+            ctx.accounts.compliance_attestation.as_deref_mut(),
+            ctx.accounts.compliance_policy.as_deref(),
+            ctx.accounts.jurisdictions.as_deref(),
+            &ctx.accounts.user.key(),
+            (ComplianceOperation::Swap, charge),
+            clock.unix_timestamp,
+        )?;
+    }
+
     match params.action {
         SwapAction::Execute => {
-            require!(
-                params.max_slippage_bps <= 1000,
-                ShadowForgeError::SlippageExceeded
-            );
+            let amount_out = route_swap(
+                vault_config,
+                &params.route,
+                params.amount_in,
+                params.min_amount_out,
+                &ctx.accounts.starpay_program,
+                &ctx.accounts.anoncoin_program,
+                &venue_accounts,
+            )?;
 
             user_position.encrypted_principal.commitment = params.amount_in_commitment;
             user_position.balance_commitment = params.min_out_commitment;
 
-            match &params.route {
-                SwapRoute::Starpay => {
-                    msg!("Starpay: Private swap executed");
-                }
-                SwapRoute::AnocoinDarkPool => {
-                    msg!("Anoncoin: Dark pool swap executed");
-                }
-                SwapRoute::Split { starpay_weight_bps } => {
-                    require!(
-                        *starpay_weight_bps <= MAX_BASIS_POINTS,
-                        ShadowForgeError::InvalidSwapPath
-                    );
-                    msg!(
-                        "Split swap: Starpay {}%, Anoncoin {}%",
-                        starpay_weight_bps / 100,
-                        (MAX_BASIS_POINTS - starpay_weight_bps) / 100
-                    );
-                }
+            msg!("Private swap filled: out={}", amount_out);
+        }
+
+        SwapAction::PlaceLimitOrder { time_in_force } if time_in_force.is_immediate() => {
+            require!(
+                vault_config.anoncoin_enabled,
+                ShadowForgeError::ExternalSdkFailed
+            );
+
+            let fill = fill_immediate_order(
+                vault_config,
+                params.amount_in,
+                params.min_amount_out,
+                time_in_force == TimeInForce::FillOrKill,
+                &ctx.accounts.anoncoin_program,
+                &venue_accounts,
+            )?;
+
+            if fill.amount_in > 0 {
+                user_position.encrypted_principal.commitment = params.amount_in_commitment;
+                user_position.balance_commitment = params.min_out_commitment;
             }
+
+            msg!(
+                "Anoncoin: Immediate order filled in={} of {}, out={}",
+                fill.amount_in,
+                params.amount_in,
+                fill.amount_out
+            );
         }
 
-        SwapAction::PlaceLimitOrder => {
+        SwapAction::PlaceLimitOrder { time_in_force } => {
             require!(
                 vault_config.anoncoin_enabled,
                 ShadowForgeError::ExternalSdkFailed
@@ -139,19 +232,33 @@ pub fn handler(ctx: Context<PrivateSwap>, params: PrivateSwapParams) -> Result<(
             require!(
                 dark_pool_order.status == OrderStatus::None ||
                 dark_pool_order.status == OrderStatus::Cancelled ||
-                dark_pool_order.status == OrderStatus::Filled,
+                dark_pool_order.status == OrderStatus::Filled ||
+                dark_pool_order.status == OrderStatus::Expired,
                 ShadowForgeError::DarkPoolFailed
             );
 
             let price_commitment = params.limit_price_commitment
                 .ok_or(ShadowForgeError::InvalidAmount)?;
 
+            let expires_at = match time_in_force {
+                TimeInForce::GoodTilTime { expires_at } => {
+                    require!(
+                        expires_at > clock.unix_timestamp,
+                        ShadowForgeError::InvalidTimestamp
+                    );
+                    expires_at
+                }
+                _ => 0,
+            };
+
             dark_pool_order.maker = ctx.accounts.user.key();
             dark_pool_order.side = params.side.clone();
             dark_pool_order.encrypted_amount.commitment = params.amount_in_commitment;
             dark_pool_order.encrypted_price.commitment = price_commitment;
             dark_pool_order.status = OrderStatus::Open;
+            dark_pool_order.time_in_force = time_in_force;
             dark_pool_order.created_at = clock.unix_timestamp;
+            dark_pool_order.expires_at = expires_at;
             dark_pool_order.bump = ctx.bumps.dark_pool_order;
 
             msg!("Anoncoin: Dark pool limit order placed");
@@ -174,6 +281,10 @@ pub fn handler(ctx: Context<PrivateSwap>, params: PrivateSwapParams) -> Result<(
                 dark_pool_order.status == OrderStatus::Open,
                 ShadowForgeError::DarkPoolFailed
             );
+            require!(
+                !dark_pool_order.is_expired(clock.unix_timestamp),
+                ShadowForgeError::OrderExpired
+            );
 
             dark_pool_order.status = OrderStatus::Filled;
             user_position.balance_commitment = dark_pool_order.encrypted_price.commitment;
@@ -200,3 +311,124 @@ pub fn handler(ctx: Context<PrivateSwap>, params: PrivateSwapParams) -> Result<(
 
     Ok(())
 }
+
+/// Split `amount_in` across venues by route weight, invoke each leg through its CPI adapter,
+/// and enforce `min_amount_out` on the combined output
+/// Shared by immediate execution and scheduled recurring slices
+pub(crate) fn route_swap<'info>(
+    vault_config: &VaultConfig,
+    route: &SwapRoute,
+    amount_in: u64,
+    min_amount_out: u64,
+    starpay_program: &AccountInfo<'info>,
+    anoncoin_program: &AccountInfo<'info>,
+    venue_accounts: &VenueSwapAccounts<'info>,
+) -> Result<u64> {
+    require!(amount_in > 0, ShadowForgeError::InvalidAmount);
+
+    let starpay_weight_bps = match route {
+        SwapRoute::Starpay => MAX_BASIS_POINTS,
+        SwapRoute::AnocoinDarkPool => 0,
+        SwapRoute::Split { starpay_weight_bps } => {
+            require!(
+                *starpay_weight_bps <= MAX_BASIS_POINTS,
+                ShadowForgeError::InvalidSwapPath
+            );
+            *starpay_weight_bps
+        }
+    };
+
+    let starpay_amount_in = (amount_in as u128)
+        .checked_mul(starpay_weight_bps as u128)
+        .ok_or(ShadowForgeError::AmountOverflow)?
+        .checked_div(MAX_BASIS_POINTS as u128)
+        .ok_or(ShadowForgeError::AmountOverflow)? as u64;
+    let anoncoin_amount_in = amount_in
+        .checked_sub(starpay_amount_in)
+        .ok_or(ShadowForgeError::AmountUnderflow)?;
+
+    let bump = [vault_config.bump];
+    let seeds: &[&[u8]] = &[VAULT_CONFIG_SEED, &bump];
+    let signer_seeds = &[seeds];
+
+    // Per-leg minimums are left to the combined check below
+    let mut amount_out: u64 = 0;
+
+    if starpay_amount_in > 0 {
+        require!(vault_config.starpay_enabled, ShadowForgeError::ExternalSdkFailed);
+        let leg_out = adapters::swap(
+            Venue::Starpay,
+            starpay_program,
+            venue_accounts,
+            starpay_amount_in,
+            0,
+            signer_seeds,
+        )?
+        .amount_out;
+        amount_out = amount_out
+            .checked_add(leg_out)
+            .ok_or(ShadowForgeError::AmountOverflow)?;
+        msg!("Starpay: Private swap leg in={}, out={}", starpay_amount_in, leg_out);
+    }
+
+    if anoncoin_amount_in > 0 {
+        require!(vault_config.anoncoin_enabled, ShadowForgeError::ExternalSdkFailed);
+        let leg_out = adapters::swap(
+            Venue::Anoncoin,
+            anoncoin_program,
+            venue_accounts,
+            anoncoin_amount_in,
+            0,
+            signer_seeds,
+        )?
+        .amount_out;
+        amount_out = amount_out
+            .checked_add(leg_out)
+            .ok_or(ShadowForgeError::AmountOverflow)?;
+        msg!("Anoncoin: Dark pool swap leg in={}, out={}", anoncoin_amount_in, leg_out);
+    }
+
+    require!(
+        amount_out >= min_amount_out,
+        ShadowForgeError::SlippageExceeded
+    );
+
+    Ok(amount_out)
+}
+
+/// Match an IOC or FOK order against the Anoncoin dark pool within the placing instruction
+/// Nothing is written to the order book: an IOC order that finds no liquidity leaves its input
+/// in the source vault, and a FOK order reverts. The order's commitments cover the full amount,
+/// so a partial fill would leave the position committed to input the venue never took; both
+/// kinds revert on one
+fn fill_immediate_order<'info>(
+    vault_config: &VaultConfig,
+    amount_in: u64,
+    min_amount_out: u64,
+    fill_or_kill: bool,
+    anoncoin_program: &AccountInfo<'info>,
+    venue_accounts: &VenueSwapAccounts<'info>,
+) -> Result<LegFill> {
+    require!(amount_in > 0, ShadowForgeError::InvalidAmount);
+
+    let bump = [vault_config.bump];
+    let seeds: &[&[u8]] = &[VAULT_CONFIG_SEED, &bump];
+
+    let fill = adapters::swap(
+        Venue::Anoncoin,
+        anoncoin_program,
+        venue_accounts,
+        amount_in,
+        0,
+        &[seeds],
+    )?;
+
+    if fill.amount_in == 0 {
+        require!(!fill_or_kill, ShadowForgeError::OrderNotFilled);
+        return Ok(fill);
+    }
+    require!(fill.amount_in == amount_in, ShadowForgeError::OrderNotFilled);
+    require!(fill.amount_out >= min_amount_out, ShadowForgeError::SlippageExceeded);
+
+    Ok(fill)
+}
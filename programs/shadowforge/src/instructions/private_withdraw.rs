@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, TransferChecked, transfer_checked};
 
+use crate::auditor::{load_auditor_key, verify_auditor_disclosure, AuditorDisclosure};
+use crate::compliance::{enforce_compliance, enforce_screening, NonMembershipProof};
 use crate::error::ShadowForgeError;
 use crate::state::*;
 
@@ -14,7 +16,7 @@ pub struct PrivateWithdraw<'info> {
         mut,
         seeds = [VAULT_CONFIG_SEED],
         bump = vault_config.bump,
-        constraint = vault_config.is_operational() @ ShadowForgeError::VaultPaused,
+        constraint = vault_config.is_operational(PausableOperation::Withdraw) @ ShadowForgeError::VaultPaused,
     )]
     pub vault_config: Account<'info, VaultConfig>,
 
@@ -49,8 +51,43 @@ pub struct PrivateWithdraw<'info> {
     #[account(address = vault_config.shielded_mint)]
     pub shielded_mint: InterfaceAccount<'info, Mint>,
 
+    /// Required when the vault has `compliance_required` set
+    #[account(
+        mut,
+        seeds = [COMPLIANCE_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = compliance_attestation.bump,
+    )]
     pub compliance_attestation: Option<Account<'info, ComplianceAttestation>>,
 
+    #[account(
+        seeds = [COMPLIANCE_POLICY_SEED, vault_config.key().as_ref()],
+        bump = compliance_policy.bump,
+    )]
+    pub compliance_policy: Option<Account<'info, CompliancePolicy>>,
+
+    #[account(
+        seeds = [JURISDICTION_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = jurisdictions.bump,
+    )]
+    pub jurisdictions: Option<Account<'info, JurisdictionRegistry>>,
+
+    #[account(
+        seeds = [SCREENING_SEED, vault_config.key().as_ref()],
+        bump = screening.bump,
+    )]
+    pub screening: Option<Account<'info, ScreeningRoot>>,
+
+    /// CHECK: Auditor key PDA, deserialized in the handler; empty until an auditor is registered
+    #[account(
+        seeds = [AUDITOR_KEY_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub auditor_key: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar, read to find the auditor equality proof
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
     pub token_2022_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
 }
@@ -69,6 +106,10 @@ pub struct PrivateWithdrawParams {
     pub ownership_proof: [u8; PROOF_DATA_LEN],
     pub nullifier: [u8; 32],
     pub expected_amount: u64,
+    /// Non-membership proof against the sanctions denylist, required when compliance is on
+    pub screening_proof: Option<NonMembershipProof>,
+    /// Amount re-encrypted under the auditor key, required once the vault has an auditor
+    pub auditor_disclosure: Option<AuditorDisclosure>,
 }
 
 pub fn handler(ctx: Context<PrivateWithdraw>, params: PrivateWithdrawParams) -> Result<()> {
@@ -76,18 +117,41 @@ pub fn handler(ctx: Context<PrivateWithdraw>, params: PrivateWithdrawParams) ->
     let user_position = &mut ctx.accounts.user_position;
     let clock = Clock::get()?;
 
-    if vault_config.compliance_required {
-        let compliance = ctx.accounts.compliance_attestation.as_ref()
-            .ok_or(ShadowForgeError::KycRequired)?;
-
-        require!(
-            compliance.user == ctx.accounts.user.key(),
-            ShadowForgeError::ComplianceFailed
-        );
-        require!(
-            compliance.is_valid && compliance.expires_at > clock.unix_timestamp,
-            ShadowForgeError::ComplianceExpired
-        );
+    enforce_compliance(
+        vault_config,
+        ctx.accounts.compliance_attestation.as_deref_mut(),
+        ctx.accounts.compliance_policy.as_deref(),
+        ctx.accounts.jurisdictions.as_deref(),
+        &ctx.accounts.user.key(),
+        (ComplianceOperation::Withdraw, Some(params.expected_amount)),
+        clock.unix_timestamp,
+    )?;
+    enforce_screening(
+        vault_config,
+        ctx.accounts.screening.as_deref(),
+        &ctx.accounts.user_token_account.owner,
+        params.screening_proof.as_ref(),
+        clock.unix_timestamp,
+    )?;
+
+    if let Some(auditor_key) = load_auditor_key(&ctx.accounts.auditor_key)? {
+        let disclosure = params.auditor_disclosure
+            .as_ref()
+            .ok_or(ShadowForgeError::SelectiveDisclosureFailed)?;
+        verify_auditor_disclosure(
+            &ctx.accounts.instructions,
+            &auditor_key,
+            disclosure,
+            params.expected_amount,
+        )?;
+
+        emit!(AuditorDisclosureEvent {
+            user: ctx.accounts.user.key(),
+            operation: ComplianceOperation::Withdraw,
+            key_version: auditor_key.key_version,
+            auditor_ciphertext: disclosure.auditor_ciphertext,
+            timestamp: clock.unix_timestamp,
+        });
     }
 
     require!(
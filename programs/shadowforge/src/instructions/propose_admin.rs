@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// First step of an admin handover: the current admin names a successor or withdraws the offer
+/// Naming a successor needs council co-signers once a council is configured
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum AdminHandoverAction {
+    /// Name the key that may accept the admin role, replacing any earlier proposal
+    Propose { new_admin: Pubkey },
+    /// Withdraw the pending proposal
+    Cancel,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeAdminParams {
+    pub action: AdminHandoverAction,
+}
+
+pub fn handler(ctx: Context<ProposeAdmin>, params: ProposeAdminParams) -> Result<()> {
+    let vault_config = &mut ctx.accounts.vault_config;
+    let clock = Clock::get()?;
+
+    match params.action {
+        AdminHandoverAction::Propose { new_admin } => {
+            require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+            require!(
+                new_admin != Pubkey::default() && new_admin != vault_config.admin,
+                ShadowForgeError::InvalidAdminOperation
+            );
+
+            vault_config.pending_admin = new_admin;
+
+            emit!(AdminTransferProposedEvent {
+                admin: vault_config.admin,
+                pending_admin: new_admin,
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!("Admin: Handover proposed to {}", new_admin);
+        }
+
+        AdminHandoverAction::Cancel => {
+            require!(vault_config.has_pending_admin(), ShadowForgeError::InvalidAdminOperation);
+
+            let pending_admin = vault_config.pending_admin;
+            vault_config.pending_admin = Pubkey::default();
+
+            emit!(AdminTransferCancelledEvent {
+                admin: vault_config.admin,
+                pending_admin,
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!("Admin: Handover to {} cancelled", pending_admin);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct RecurringSwap<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), owner.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.owner == owner.key() @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub user_position: Account<'info, UserEncryptedPosition>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = RecurringSwapOrder::LEN,
+        seeds = [RECURRING_SWAP_SEED, vault_config.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub recurring_swap_order: Account<'info, RecurringSwapOrder>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecurringSwapTerms {
+    pub route: SwapRoute,
+    pub encrypted_total: EncryptedAmount,
+    pub encrypted_slice: EncryptedAmount,
+    pub elgamal_pubkey: [u8; 32],
+    pub min_out_commitment: [u8; 32],
+    pub min_slice_amount_out: u64,
+    pub max_slippage_bps: u16,
+    pub interval_seconds: i64,
+    pub total_slices: u32,
+    pub start_at: Option<i64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum RecurringSwapAction {
+    Create { terms: Box<RecurringSwapTerms> },
+    Pause,
+    Resume,
+    Cancel,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RecurringSwapParams {
+    pub action: RecurringSwapAction,
+}
+
+pub fn handler(ctx: Context<RecurringSwap>, params: RecurringSwapParams) -> Result<()> {
+    let vault_config = &ctx.accounts.vault_config;
+    let user_position = &mut ctx.accounts.user_position;
+    let order = &mut ctx.accounts.recurring_swap_order;
+    let clock = Clock::get()?;
+
+    match params.action {
+        RecurringSwapAction::Create { terms } => {
+            let RecurringSwapTerms {
+                route,
+                encrypted_total,
+                encrypted_slice,
+                elgamal_pubkey,
+                min_out_commitment,
+                min_slice_amount_out,
+                max_slippage_bps,
+                interval_seconds,
+                total_slices,
+                start_at,
+            } = *terms;
+
+            require!(vault_config.is_operational(PausableOperation::Swap), ShadowForgeError::VaultPaused);
+            require!(
+                vault_config.starpay_enabled || vault_config.anoncoin_enabled,
+                ShadowForgeError::ExternalSdkFailed
+            );
+            require!(
+                order.status == RecurringSwapStatus::None ||
+                order.status == RecurringSwapStatus::Completed ||
+                order.status == RecurringSwapStatus::Cancelled,
+                ShadowForgeError::SwapFailed
+            );
+            require!(
+                !encrypted_total.is_zero() && !encrypted_slice.is_zero(),
+                ShadowForgeError::InvalidCiphertext
+            );
+            require!(total_slices > 0, ShadowForgeError::InvalidAmount);
+            require!(
+                interval_seconds >= MIN_RECURRING_INTERVAL_SECS,
+                ShadowForgeError::InvalidTimestamp
+            );
+            require!(
+                max_slippage_bps <= MAX_SLIPPAGE_BPS,
+                ShadowForgeError::SlippageExceeded
+            );
+            if let SwapRoute::Split { starpay_weight_bps } = route {
+                require!(
+                    starpay_weight_bps <= MAX_BASIS_POINTS,
+                    ShadowForgeError::InvalidSwapPath
+                );
+            }
+
+            let first_execution_at = start_at.unwrap_or(clock.unix_timestamp);
+            require!(
+                first_execution_at >= clock.unix_timestamp,
+                ShadowForgeError::InvalidTimestamp
+            );
+
+            order.owner = ctx.accounts.owner.key();
+            order.vault = vault_config.key();
+            order.route = route;
+            order.encrypted_total = encrypted_total;
+            order.encrypted_slice = encrypted_slice;
+            order.elgamal_pubkey = elgamal_pubkey;
+            order.min_out_commitment = min_out_commitment;
+            order.min_slice_amount_out = min_slice_amount_out;
+            order.max_slippage_bps = max_slippage_bps;
+            order.interval_seconds = interval_seconds;
+            order.next_execution_at = first_execution_at;
+            order.total_slices = total_slices;
+            order.executed_slices = 0;
+            order.status = RecurringSwapStatus::Active;
+            order.created_at = clock.unix_timestamp;
+            order.last_executed_at = 0;
+            order.bump = ctx.bumps.recurring_swap_order;
+
+            msg!(
+                "Recurring swap created: {} slices every {}s",
+                total_slices,
+                interval_seconds
+            );
+        }
+
+        RecurringSwapAction::Pause => {
+            require!(
+                order.status == RecurringSwapStatus::Active,
+                ShadowForgeError::RecurringSwapInactive
+            );
+
+            order.status = RecurringSwapStatus::Paused;
+
+            msg!("Recurring swap paused");
+        }
+
+        RecurringSwapAction::Resume => {
+            require!(
+                order.status == RecurringSwapStatus::Paused,
+                ShadowForgeError::RecurringSwapInactive
+            );
+
+            // Missed intervals are skipped rather than executed in a burst
+            order.next_execution_at = order.next_execution_at.max(clock.unix_timestamp);
+            order.status = RecurringSwapStatus::Active;
+
+            msg!("Recurring swap resumed, next execution at {}", order.next_execution_at);
+        }
+
+        RecurringSwapAction::Cancel => {
+            require!(
+                order.status == RecurringSwapStatus::Active ||
+                order.status == RecurringSwapStatus::Paused,
+                ShadowForgeError::RecurringSwapInactive
+            );
+
+            order.status = RecurringSwapStatus::Cancelled;
+
+            msg!(
+                "Recurring swap cancelled after {}/{} slices",
+                order.executed_slices,
+                order.total_slices
+            );
+        }
+    }
+
+    user_position.last_action_at = clock.unix_timestamp;
+
+    Ok(())
+}
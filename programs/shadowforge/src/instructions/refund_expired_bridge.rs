@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::error::ShadowForgeError;
+use crate::instructions::private_bridge::release_escrow;
+use crate::state::*;
+
+/// Permissionless crank that fails a bridge request once its chain timeout has passed
+/// Only requests the relayer never picked up qualify; those may already be delivered and
+/// settle through a guardian attestation instead. The escrow goes back to the owner
+#[derive(Accounts)]
+pub struct RefundExpiredBridge<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Request owner, only used to derive their position and token account
+    #[account(address = bridge_request.user @ ShadowForgeError::InvalidAuthority)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), owner.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.owner == owner.key() @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub user_position: Account<'info, UserEncryptedPosition>,
+
+    #[account(
+        mut,
+        seeds = [
+            BRIDGE_REQUEST_SEED,
+            vault_config.key().as_ref(),
+            owner.key().as_ref(),
+            &bridge_request.nonce.to_le_bytes(),
+        ],
+        bump = bridge_request.bump,
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+
+    #[account(address = vault_config.shielded_mint)]
+    pub shielded_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = shielded_mint,
+        token::authority = owner,
+        token::token_program = token_2022_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [BRIDGE_ESCROW_SEED, shielded_mint.key().as_ref()],
+        bump,
+        token::mint = shielded_mint,
+        token::authority = vault_config,
+        token::token_program = token_2022_program,
+    )]
+    pub bridge_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+pub fn handler(ctx: Context<RefundExpiredBridge>) -> Result<()> {
+    let user_position = &mut ctx.accounts.user_position;
+    let bridge_request = &mut ctx.accounts.bridge_request;
+    let clock = Clock::get()?;
+
+    require!(bridge_request.is_in_flight(), ShadowForgeError::BridgeFailed);
+    require!(
+        bridge_request.status == BridgeStatus::Pending,
+        ShadowForgeError::BridgeRequestInFlight
+    );
+    require!(
+        bridge_request.is_expired(clock.unix_timestamp),
+        ShadowForgeError::BridgeRequestNotExpired
+    );
+
+    let refunded_amount = bridge_request.escrowed_amount;
+
+    // Same settlement as a user-initiated cancel: the fee is kept, the escrow is returned
+    release_escrow(
+        &ctx.accounts.vault_config,
+        &ctx.accounts.bridge_escrow,
+        &ctx.accounts.shielded_mint,
+        &ctx.accounts.owner_token_account.to_account_info(),
+        &ctx.accounts.token_2022_program,
+        refunded_amount,
+    )?;
+
+    user_position.encrypted_principal.commitment = bridge_request.amount_commitment;
+    let pending = user_position.pending_bridge_count
+        .checked_sub(1)
+        .ok_or(ShadowForgeError::AmountUnderflow)?;
+    user_position.set_pending_bridge_count(pending);
+    user_position.last_action_at = clock.unix_timestamp;
+
+    bridge_request.escrowed_amount = 0;
+    bridge_request.status = BridgeStatus::Failed;
+
+    emit!(BridgeRefundedEvent {
+        user: bridge_request.user,
+        nonce: bridge_request.nonce,
+        refunded_amount,
+        cranker: ctx.accounts.cranker.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "SilentSwap: Expired bridge request {} refunded {} to owner",
+        bridge_request.nonce,
+        refunded_amount
+    );
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Revoke a user's compliance attestation, signed by the attesting provider, the admin or a
+/// holder of the `ComplianceOfficer` role
+#[derive(Accounts)]
+pub struct RevokeCompliance<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// Required when a compliance officer, rather than the provider, signs
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    /// CHECK: Owner of the attestation, only used to derive PDAs
+    pub user: UncheckedAccount<'info>,
+
+    /// Cleared of its compliance flag when the user has opened a position
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+    )]
+    pub user_position: Option<Account<'info, UserEncryptedPosition>>,
+
+    #[account(
+        mut,
+        seeds = [COMPLIANCE_SEED, vault_config.key().as_ref(), user.key().as_ref()],
+        bump = compliance_attestation.bump,
+        constraint = compliance_attestation.is_valid @ ShadowForgeError::ComplianceFailed,
+    )]
+    pub compliance_attestation: Account<'info, ComplianceAttestation>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevokeComplianceParams {
+    pub reason: RevocationReason,
+}
+
+pub fn handler(ctx: Context<RevokeCompliance>, params: RevokeComplianceParams) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let compliance = &mut ctx.accounts.compliance_attestation;
+    let clock = Clock::get()?;
+
+    // A provider dropped from the registry can still pull attestations it issued
+    let is_provider = authority == compliance.provider;
+    let is_officer = authority == ctx.accounts.vault_config.admin ||
+        ctx.accounts.role_registry
+            .as_ref()
+            .is_some_and(|r| r.has_role(&authority, Role::ComplianceOfficer));
+    require!(is_provider || is_officer, ShadowForgeError::UnauthorizedRevocation);
+
+    compliance.is_valid = false;
+    compliance.revoked_at = clock.unix_timestamp;
+    if let Some(user_position) = ctx.accounts.user_position.as_mut() {
+        user_position.compliance_verified = false;
+        user_position.compliance_expiry = 0;
+    }
+
+    emit!(ComplianceRevokedEvent {
+        user: ctx.accounts.user.key(),
+        provider: compliance.provider,
+        revoked_by: authority,
+        reason: params.reason,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Range: Compliance attestation revoked by {}", authority);
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+use crate::timelock::validate_change;
+
+/// Queue a fee, yield or SDK change that anyone may apply once the timelock delay has passed
+/// Signed by the admin, or by a key holding the role the change requires; once an admin council
+/// exists its members must co-sign, since anyone may execute the change after the delay
+#[derive(Accounts)]
+#[instruction(params: ScheduleChangeParams)]
+pub struct ScheduleChange<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// Required when a role holder, rather than the admin, signs
+    #[account(
+        seeds = [ROLE_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump = role_registry.bump,
+    )]
+    pub role_registry: Option<Account<'info, RoleRegistry>>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = Timelock::LEN,
+        seeds = [TIMELOCK_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub timelock: Account<'info, Timelock>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = QueuedChange::LEN,
+        seeds = [QUEUED_CHANGE_SEED, vault_config.key().as_ref(), &params.change_id.to_le_bytes()],
+        bump
+    )]
+    pub queued_change: Account<'info, QueuedChange>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ScheduleChangeParams {
+    /// Must equal the timelock's `next_change_id`
+    pub change_id: u64,
+    pub change: ParameterChange,
+}
+
+pub fn handler(ctx: Context<ScheduleChange>, params: ScheduleChangeParams) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let is_admin = ctx.accounts.vault_config.admin == authority;
+    let has_role = match params.change.required_role() {
+        Some(role) => ctx.accounts.role_registry
+            .as_ref()
+            .is_some_and(|r| r.has_role(&authority, role)),
+        None => false,
+    };
+    require!(is_admin || has_role, ShadowForgeError::Unauthorized);
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+
+    validate_change(&params.change)?;
+
+    let timelock = &mut ctx.accounts.timelock;
+    let queued_change = &mut ctx.accounts.queued_change;
+    let clock = Clock::get()?;
+
+    if timelock.vault == Pubkey::default() {
+        timelock.vault = ctx.accounts.vault_config.key();
+        timelock.delay_secs = DEFAULT_TIMELOCK_DELAY_SECS;
+        timelock.bump = ctx.bumps.timelock;
+    }
+
+    require!(
+        params.change_id == timelock.next_change_id,
+        ShadowForgeError::InvalidAdminOperation
+    );
+
+    queued_change.vault = timelock.vault;
+    queued_change.id = params.change_id;
+    queued_change.change = params.change.clone();
+    queued_change.proposer = authority;
+    queued_change.queued_at = clock.unix_timestamp;
+    queued_change.execute_after = clock.unix_timestamp
+        .checked_add(timelock.delay_secs)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+    queued_change.bump = ctx.bumps.queued_change;
+
+    timelock.next_change_id = timelock.next_change_id
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+
+    emit!(ParameterChangeScheduledEvent {
+        id: queued_change.id,
+        change: params.change,
+        proposer: authority,
+        execute_after: queued_change.execute_after,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Admin: Change {} scheduled, executable after {}",
+        queued_change.id,
+        queued_change.execute_after
+    );
+
+    Ok(())
+}
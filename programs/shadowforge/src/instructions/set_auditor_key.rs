@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to register or rotate the vault auditor's ElGamal key
+#[derive(Accounts)]
+pub struct SetAuditorKey<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditorKey::LEN,
+        seeds = [AUDITOR_KEY_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub auditor_key: Account<'info, AuditorKey>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetAuditorKeyParams {
+    pub auditor: Pubkey,
+    /// Twisted ElGamal public key the auditor decrypts with
+    pub elgamal_pubkey: [u8; 32],
+}
+
+pub fn handler(ctx: Context<SetAuditorKey>, params: SetAuditorKeyParams) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let auditor_key = &mut ctx.accounts.auditor_key;
+    let clock = Clock::get()?;
+
+    require!(params.auditor != Pubkey::default(), ShadowForgeError::InvalidAdminOperation);
+    require!(
+        !params.elgamal_pubkey.iter().all(|&b| b == 0),
+        ShadowForgeError::InvalidAdminOperation
+    );
+
+    auditor_key.vault = ctx.accounts.vault_config.key();
+    auditor_key.auditor = params.auditor;
+    auditor_key.elgamal_pubkey = params.elgamal_pubkey;
+    auditor_key.key_version = auditor_key.key_version
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+    auditor_key.updated_at = clock.unix_timestamp;
+    auditor_key.bump = ctx.bumps.auditor_key;
+
+    emit!(AuditorKeyUpdatedEvent {
+        auditor: params.auditor,
+        elgamal_pubkey: params.elgamal_pubkey,
+        key_version: auditor_key.key_version,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin: Auditor key set to version {}", auditor_key.key_version);
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to replace the per-tier transaction limits
+#[derive(Accounts)]
+pub struct SetCompliancePolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CompliancePolicy::LEN,
+        seeds = [COMPLIANCE_POLICY_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_policy: Account<'info, CompliancePolicy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCompliancePolicyParams {
+    pub period_secs: i64,
+    /// Limits for Basic, Verified and Institutional, in that order
+    pub tiers: [TierLimits; COMPLIANCE_TIER_COUNT],
+}
+
+pub fn handler(ctx: Context<SetCompliancePolicy>, params: SetCompliancePolicyParams) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let policy = &mut ctx.accounts.compliance_policy;
+    let clock = Clock::get()?;
+
+    require!(params.period_secs > 0, ShadowForgeError::InvalidAdminOperation);
+
+    for tier in params.tiers.iter() {
+        for limit in [&tier.deposit, &tier.withdraw, &tier.swap, &tier.bridge] {
+            require!(
+                limit.per_transaction == 0 ||
+                limit.per_period == 0 ||
+                limit.per_transaction <= limit.per_period,
+                ShadowForgeError::InvalidAdminOperation
+            );
+        }
+    }
+
+    policy.vault = ctx.accounts.vault_config.key();
+    policy.period_secs = params.period_secs;
+    policy.tiers = params.tiers;
+    policy.updated_at = clock.unix_timestamp;
+    policy.bump = ctx.bumps.compliance_policy;
+
+    emit!(CompliancePolicyUpdatedEvent {
+        period_secs: params.period_secs,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin: Compliance tier policy updated, period={}s", params.period_secs);
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to add, replace or remove the rule for one jurisdiction
+#[derive(Accounts)]
+pub struct SetJurisdictionRule<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = JurisdictionRegistry::LEN,
+        seeds = [JURISDICTION_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub jurisdictions: Account<'info, JurisdictionRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum JurisdictionAction {
+    Upsert { rule: JurisdictionRule },
+    Remove { jurisdiction: [u8; 2] },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetJurisdictionRuleParams {
+    pub action: JurisdictionAction,
+}
+
+pub fn handler(ctx: Context<SetJurisdictionRule>, params: SetJurisdictionRuleParams) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let registry = &mut ctx.accounts.jurisdictions;
+    let clock = Clock::get()?;
+
+    let (jurisdiction, removed) = match params.action {
+        JurisdictionAction::Upsert { rule } => {
+            require!(
+                rule.jurisdiction.iter().all(|b| b.is_ascii_uppercase()),
+                ShadowForgeError::InvalidAdminOperation
+            );
+            require!(
+                rule.blocked_chains.len() <= MAX_BLOCKED_CHAINS,
+                ShadowForgeError::InvalidAdminOperation
+            );
+
+            let jurisdiction = rule.jurisdiction;
+            match registry.rules.iter().position(|r| r.jurisdiction == jurisdiction) {
+                Some(index) => registry.rules[index] = rule,
+                None => {
+                    require!(
+                        registry.rules.len() < MAX_JURISDICTION_RULES,
+                        ShadowForgeError::InvalidAdminOperation
+                    );
+                    registry.rules.push(rule);
+                }
+            }
+            (jurisdiction, false)
+        }
+
+        JurisdictionAction::Remove { jurisdiction } => {
+            let before = registry.rules.len();
+            registry.rules.retain(|r| r.jurisdiction != jurisdiction);
+            require!(
+                registry.rules.len() < before,
+                ShadowForgeError::InvalidAdminOperation
+            );
+            (jurisdiction, true)
+        }
+    };
+
+    registry.vault = ctx.accounts.vault_config.key();
+    registry.updated_at = clock.unix_timestamp;
+    registry.bump = ctx.bumps.jurisdictions;
+
+    emit!(JurisdictionRuleUpdatedEvent {
+        jurisdiction,
+        removed,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Admin: Jurisdiction {}{} rule {}",
+        jurisdiction[0] as char,
+        jurisdiction[1] as char,
+        if removed { "removed" } else { "updated" }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to publish or rotate the relayer's destination-address encryption key
+#[derive(Accounts)]
+pub struct SetRelayerKey<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = RelayerKeyRegistry::LEN,
+        seeds = [RELAYER_KEY_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub relayer_keys: Account<'info, RelayerKeyRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRelayerKeyParams {
+    /// Key allowed to acknowledge outbound requests
+    pub relayer: Pubkey,
+    /// X25519 public key
+    pub encryption_pubkey: [u8; 32],
+}
+
+pub fn handler(ctx: Context<SetRelayerKey>, params: SetRelayerKeyParams) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let relayer_keys = &mut ctx.accounts.relayer_keys;
+    let clock = Clock::get()?;
+
+    require!(
+        !params.encryption_pubkey.iter().all(|&b| b == 0),
+        ShadowForgeError::InvalidEncryptedRecipient
+    );
+    require!(params.relayer != Pubkey::default(), ShadowForgeError::InvalidAuthority);
+
+    relayer_keys.vault = ctx.accounts.vault_config.key();
+    relayer_keys.relayer = params.relayer;
+    relayer_keys.encryption_pubkey = params.encryption_pubkey;
+    relayer_keys.key_version = relayer_keys.key_version
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+    relayer_keys.updated_at = clock.unix_timestamp;
+    relayer_keys.bump = ctx.bumps.relayer_keys;
+
+    emit!(RelayerKeyRotatedEvent {
+        relayer: params.relayer,
+        encryption_pubkey: params.encryption_pubkey,
+        key_version: relayer_keys.key_version,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin: Relayer key rotated to version {}", relayer_keys.key_version);
+
+    Ok(())
+}
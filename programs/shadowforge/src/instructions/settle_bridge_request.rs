@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+use crate::error::ShadowForgeError;
+use crate::guardians::{recipient_commitment, verify_attestation, AttestationKind, BridgeAttestation};
+use crate::instructions::private_bridge::{burn_escrow, release_escrow};
+use crate::state::*;
+
+/// Permissionless settlement of an outbound request from a guardian-signed attestation
+/// A completion attestation retires the escrow; a failure attestation returns it to the owner
+#[derive(Accounts)]
+pub struct SettleBridgeRequest<'info> {
+    pub settler: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Request owner, only used to derive their position and token account
+    #[account(address = bridge_request.user @ ShadowForgeError::InvalidAuthority)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, vault_config.key().as_ref(), owner.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.owner == owner.key() @ ShadowForgeError::InvalidAuthority,
+    )]
+    pub user_position: Account<'info, UserEncryptedPosition>,
+
+    #[account(
+        mut,
+        seeds = [
+            BRIDGE_REQUEST_SEED,
+            vault_config.key().as_ref(),
+            owner.key().as_ref(),
+            &bridge_request.nonce.to_le_bytes(),
+        ],
+        bump = bridge_request.bump,
+    )]
+    pub bridge_request: Account<'info, BridgeRequest>,
+
+    #[account(mut, address = vault_config.shielded_mint)]
+    pub shielded_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = shielded_mint,
+        token::authority = owner,
+        token::token_program = token_2022_program,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [BRIDGE_ESCROW_SEED, shielded_mint.key().as_ref()],
+        bump,
+        token::mint = shielded_mint,
+        token::authority = vault_config,
+        token::token_program = token_2022_program,
+    )]
+    pub bridge_escrow: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [GUARDIAN_SET_SEED, vault_config.key().as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    /// CHECK: Instructions sysvar, read to find the guardians' ed25519 signatures
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SettleBridgeRequestParams {
+    pub attestation: BridgeAttestation,
+}
+
+pub fn handler(ctx: Context<SettleBridgeRequest>, params: SettleBridgeRequestParams) -> Result<()> {
+    let user_position = &mut ctx.accounts.user_position;
+    let bridge_request = &mut ctx.accounts.bridge_request;
+    let attestation = &params.attestation;
+    let clock = Clock::get()?;
+
+    require!(bridge_request.is_in_flight(), ShadowForgeError::BridgeFailed);
+    require!(
+        attestation.chain_id == bridge_request.dest_chain_id &&
+        attestation.nonce == bridge_request.nonce &&
+        attestation.amount == bridge_request.escrowed_amount,
+        ShadowForgeError::InvalidAttestation
+    );
+    require!(
+        attestation.recipient_commitment ==
+            recipient_commitment(&bridge_request.user, &bridge_request.amount_commitment),
+        ShadowForgeError::InvalidAttestation
+    );
+
+    verify_attestation(&ctx.accounts.instructions, &ctx.accounts.guardian_set, attestation)?;
+
+    let settled_amount = bridge_request.escrowed_amount;
+
+    match attestation.kind {
+        AttestationKind::OutboundCompletion => {
+            // Funds were released on the destination chain, so the escrowed supply is retired here
+            burn_escrow(
+                &ctx.accounts.vault_config,
+                &ctx.accounts.bridge_escrow,
+                &ctx.accounts.shielded_mint,
+                &ctx.accounts.token_2022_program,
+                settled_amount,
+            )?;
+
+            bridge_request.status = BridgeStatus::Completed;
+
+            emit!(BridgeCompletedEvent {
+                user: bridge_request.user,
+                nonce: bridge_request.nonce,
+                burned_amount: settled_amount,
+                settler: ctx.accounts.settler.key(),
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!("SilentSwap: Bridge request {} completed", bridge_request.nonce);
+        }
+
+        AttestationKind::OutboundFailure => {
+            // Guardians confirmed nothing was released on the destination chain, so the escrow goes back
+            release_escrow(
+                &ctx.accounts.vault_config,
+                &ctx.accounts.bridge_escrow,
+                &ctx.accounts.shielded_mint,
+                &ctx.accounts.owner_token_account.to_account_info(),
+                &ctx.accounts.token_2022_program,
+                settled_amount,
+            )?;
+
+            user_position.encrypted_principal.commitment = bridge_request.amount_commitment;
+            bridge_request.status = BridgeStatus::Failed;
+
+            emit!(BridgeRefundedEvent {
+                user: bridge_request.user,
+                nonce: bridge_request.nonce,
+                refunded_amount: settled_amount,
+                cranker: ctx.accounts.settler.key(),
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!(
+                "SilentSwap: Bridge request {} failed on the destination chain, refunded {}",
+                bridge_request.nonce,
+                settled_amount
+            );
+        }
+
+        AttestationKind::InboundTransfer => return err!(ShadowForgeError::InvalidAttestation),
+    }
+
+    let pending = user_position.pending_bridge_count
+        .checked_sub(1)
+        .ok_or(ShadowForgeError::AmountUnderflow)?;
+    user_position.set_pending_bridge_count(pending);
+    user_position.last_action_at = clock.unix_timestamp;
+
+    bridge_request.escrowed_amount = 0;
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to pin the program ID accepted for each external integration
+#[derive(Accounts)]
+pub struct UpdateAdapterRegistry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AdapterRegistry::LEN,
+        seeds = [ADAPTER_REGISTRY_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub adapter_registry: Account<'info, AdapterRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AdapterUpdate {
+    pub integration: Integration,
+    pub program_id: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateAdapterRegistryParams {
+    pub updates: Vec<AdapterUpdate>,
+}
+
+pub fn handler(ctx: Context<UpdateAdapterRegistry>, params: UpdateAdapterRegistryParams) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let adapter_registry = &mut ctx.accounts.adapter_registry;
+    let clock = Clock::get()?;
+
+    require!(!params.updates.is_empty(), ShadowForgeError::InvalidAdminOperation);
+
+    adapter_registry.vault = ctx.accounts.vault_config.key();
+    adapter_registry.bump = ctx.bumps.adapter_registry;
+
+    for update in params.updates.iter() {
+        require!(
+            update.program_id != Pubkey::default(),
+            ShadowForgeError::InvalidAdapterProgram
+        );
+
+        let previous_program_id = adapter_registry.program_id(update.integration);
+        adapter_registry.set_program_id(update.integration, update.program_id);
+
+        emit!(AdapterRegistryUpdatedEvent {
+            integration: update.integration,
+            previous_program_id,
+            new_program_id: update.program_id,
+            updated_by: ctx.accounts.admin.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Admin: Adapter program updated from {} to {}",
+            previous_program_id,
+            update.program_id
+        );
+    }
+
+    adapter_registry.updated_at = clock.unix_timestamp;
+
+    Ok(())
+}
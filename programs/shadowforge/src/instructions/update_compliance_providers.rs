@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to replace the approved compliance provider keys
+#[derive(Accounts)]
+pub struct UpdateComplianceProviders<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ComplianceProviderRegistry::LEN,
+        seeds = [COMPLIANCE_PROVIDERS_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_providers: Account<'info, ComplianceProviderRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateComplianceProvidersParams {
+    pub providers: Vec<Pubkey>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateComplianceProviders>,
+    params: UpdateComplianceProvidersParams,
+) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let registry = &mut ctx.accounts.compliance_providers;
+    let clock = Clock::get()?;
+
+    require!(
+        params.providers.len() <= MAX_COMPLIANCE_PROVIDERS,
+        ShadowForgeError::UnapprovedComplianceProvider
+    );
+
+    for (i, provider) in params.providers.iter().enumerate() {
+        require!(
+            *provider != Pubkey::default() && !params.providers[..i].contains(provider),
+            ShadowForgeError::UnapprovedComplianceProvider
+        );
+    }
+
+    registry.vault = ctx.accounts.vault_config.key();
+    registry.providers = params.providers.clone();
+    registry.updated_at = clock.unix_timestamp;
+    registry.bump = ctx.bumps.compliance_providers;
+
+    emit!(ComplianceProvidersUpdatedEvent {
+        providers: params.providers,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin: Compliance providers updated, {} approved", registry.providers.len());
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Admin instruction to replace the bridge guardian keys and signature threshold
+#[derive(Accounts)]
+pub struct UpdateGuardianSet<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+        constraint = vault_config.admin == admin.key() @ ShadowForgeError::Unauthorized,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = GuardianSet::LEN,
+        seeds = [GUARDIAN_SET_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateGuardianSetParams {
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+pub fn handler(ctx: Context<UpdateGuardianSet>, params: UpdateGuardianSetParams) -> Result<()> {
+    require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    let clock = Clock::get()?;
+
+    require!(
+        !params.guardians.is_empty() && params.guardians.len() <= MAX_GUARDIANS,
+        ShadowForgeError::InvalidGuardianSet
+    );
+    require!(
+        params.threshold > 0 && params.threshold as usize <= params.guardians.len(),
+        ShadowForgeError::InvalidGuardianSet
+    );
+
+    for (i, guardian) in params.guardians.iter().enumerate() {
+        require!(*guardian != Pubkey::default(), ShadowForgeError::InvalidGuardianSet);
+        // Duplicates would let one key count towards the threshold more than once
+        require!(
+            !params.guardians[..i].contains(guardian),
+            ShadowForgeError::InvalidGuardianSet
+        );
+    }
+
+    guardian_set.vault = ctx.accounts.vault_config.key();
+    guardian_set.guardians = params.guardians.clone();
+    guardian_set.threshold = params.threshold;
+    guardian_set.updated_at = clock.unix_timestamp;
+    guardian_set.bump = ctx.bumps.guardian_set;
+
+    emit!(GuardianSetUpdatedEvent {
+        guardians: params.guardians,
+        threshold: params.threshold,
+        updated_by: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Admin: Guardian set updated, {}-of-{}",
+        guardian_set.threshold,
+        guardian_set.guardians.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::council::require_council_approval;
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Publish a new sanctions denylist root, signed by the admin or an approved compliance provider
+/// The admin path needs council co-signers once a council is configured; providers publish roots
+/// directly, but changing the staleness window always takes the admin path
+#[derive(Accounts)]
+pub struct UpdateScreeningRoot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [VAULT_CONFIG_SEED],
+        bump = vault_config.bump,
+    )]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    /// CHECK: Admin council PDA, empty until a council is configured
+    #[account(
+        seeds = [COUNCIL_SEED, vault_config.key().as_ref()],
+        bump,
+    )]
+    pub council: UncheckedAccount<'info>,
+
+    /// Required when a provider, rather than the admin, signs
+    #[account(
+        seeds = [COMPLIANCE_PROVIDERS_SEED, vault_config.key().as_ref()],
+        bump = compliance_providers.bump,
+    )]
+    pub compliance_providers: Option<Account<'info, ComplianceProviderRegistry>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ScreeningRoot::LEN,
+        seeds = [SCREENING_SEED, vault_config.key().as_ref()],
+        bump
+    )]
+    pub screening: Account<'info, ScreeningRoot>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateScreeningRootParams {
+    pub root: [u8; 32],
+    /// Admin only; providers must pass None
+    pub max_staleness_secs: Option<i64>,
+}
+
+pub fn handler(ctx: Context<UpdateScreeningRoot>, params: UpdateScreeningRootParams) -> Result<()> {
+    let screening = &mut ctx.accounts.screening;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    let is_admin = authority == ctx.accounts.vault_config.admin;
+    let is_provider = ctx.accounts.compliance_providers
+        .as_ref()
+        .is_some_and(|r| r.is_approved(&authority));
+    require!(is_admin || is_provider, ShadowForgeError::Unauthorized);
+    require!(params.root != [0u8; 32], ShadowForgeError::InvalidAdminOperation);
+
+    // An admin that is also a provider still needs the council to change staleness
+    if !is_provider || params.max_staleness_secs.is_some() {
+        require_council_approval(&ctx.accounts.council, ctx.remaining_accounts)?;
+    }
+
+    if let Some(max_staleness_secs) = params.max_staleness_secs {
+        require!(is_admin, ShadowForgeError::Unauthorized);
+        require!(max_staleness_secs > 0, ShadowForgeError::InvalidAdminOperation);
+        screening.max_staleness_secs = max_staleness_secs;
+    }
+    require!(screening.max_staleness_secs > 0, ShadowForgeError::InvalidAdminOperation);
+
+    screening.vault = ctx.accounts.vault_config.key();
+    screening.root = params.root;
+    screening.version = screening.version
+        .checked_add(1)
+        .ok_or(ShadowForgeError::AmountOverflow)?;
+    screening.updated_at = clock.unix_timestamp;
+    screening.updated_by = authority;
+    screening.bump = ctx.bumps.screening;
+
+    emit!(ScreeningRootUpdatedEvent {
+        root: params.root,
+        version: screening.version,
+        updated_by: authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Range: Screening root updated to version {}", screening.version);
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use anchor_lang::system_program;
 use anchor_spl::token_2022::Token2022;
 use anchor_spl::token_interface::{Mint, TokenAccount, MintTo, mint_to};
 
+use crate::error::ShadowForgeError;
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -14,6 +15,7 @@ pub struct WrapSol<'info> {
         mut,
         seeds = [VAULT_CONFIG_SEED],
         bump = vault_config.bump,
+        constraint = vault_config.is_operational(PausableOperation::Wrap) @ ShadowForgeError::VaultPaused,
     )]
     pub vault_config: Account<'info, VaultConfig>,
 
@@ -1,8 +1,16 @@
 use anchor_lang::prelude::*;
 
+pub mod adapters;
+pub mod auditor;
+pub mod compliance;
+pub mod council;
+pub mod ed25519;
 pub mod error;
+pub mod guardians;
 pub mod instructions;
 pub mod state;
+pub mod timelock;
+pub mod zk_proofs;
 
 use instructions::*;
 
@@ -47,19 +55,65 @@ pub mod shadowforge {
     }
 
     /// Private swap execution via Starpay + Anoncoin dark pools
-    /// Supports: immediate execution, limit orders, dark pool matching
+    /// Supports: immediate execution with weighted split routing, limit orders, dark pool matching
     /// All amounts and prices encrypted
-    pub fn private_swap(ctx: Context<PrivateSwap>, params: PrivateSwapParams) -> Result<()> {
+    pub fn private_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PrivateSwap<'info>>,
+        params: PrivateSwapParams,
+    ) -> Result<()> {
         instructions::private_swap::handler(ctx, params)
     }
 
     /// Private cross-chain bridging via SilentSwap
-    /// Supports: outbound bridge, inbound claim, cancellation
+    /// Supports: outbound bridge and cancellation before relayer pickup
     /// Non-custodial with encrypted destinations
     pub fn private_bridge(ctx: Context<PrivateBridge>, params: PrivateBridgeParams) -> Result<()> {
         instructions::private_bridge::handler(ctx, params)
     }
 
+    /// Claim an inbound cross-chain transfer via SilentSwap
+    /// Requires a guardian attestation; each (source chain, nonce) message is claimable once
+    pub fn claim_inbound(ctx: Context<ClaimInbound>, params: ClaimInboundParams) -> Result<()> {
+        instructions::claim_inbound::handler(ctx, params)
+    }
+
+    /// Close a finalized bridge request
+    /// Only completed or failed requests can be closed; rent returns to the owner
+    pub fn close_bridge_request(ctx: Context<CloseBridgeRequest>) -> Result<()> {
+        instructions::close_bridge_request::handler(ctx)
+    }
+
+    /// Refund a bridge request that outlived its chain timeout (permissionless crank)
+    /// Returns the escrowed amount to the owner and clears the pending request
+    pub fn refund_expired_bridge(ctx: Context<RefundExpiredBridge>) -> Result<()> {
+        instructions::refund_expired_bridge::handler(ctx)
+    }
+
+    /// Record that the relayer picked up an outbound request
+    /// The owner can no longer cancel it; only a guardian attestation settles it afterwards
+    pub fn acknowledge_bridge_request(ctx: Context<AcknowledgeBridgeRequest>) -> Result<()> {
+        instructions::acknowledge_bridge_request::handler(ctx)
+    }
+
+    /// Settle an outbound request from a guardian-signed attestation (permissionless)
+    /// Completion burns the escrowed amount; failure refunds it to the owner
+    pub fn settle_bridge_request(
+        ctx: Context<SettleBridgeRequest>,
+        params: SettleBridgeRequestParams,
+    ) -> Result<()> {
+        instructions::settle_bridge_request::handler(ctx, params)
+    }
+
+    /// View function reporting whether an inbound bridge message was already claimed
+    /// Returns false until `claim_inbound` records the (source chain, nonce) pair
+    pub fn bridge_message_status(
+        ctx: Context<BridgeMessageStatus>,
+        source_chain_id: u64,
+        nonce: u64,
+    ) -> Result<bool> {
+        instructions::bridge_message_status::handler(ctx, source_chain_id, nonce)
+    }
+
     /// Apply compliance checks via Range protocol
     /// Supports: screening, selective disclosure, attestation verification
     /// Privacy-preserving KYC without revealing sensitive data
@@ -82,7 +136,7 @@ pub mod shadowforge {
     }
 
     /// Admin operations for demo/hackathon purposes
-    /// Supports: deposit rewards, update yield rate, pause, fees, SDK toggles
+    /// Supports: deposit rewards, pause, emergency mode, compliance toggle
     pub fn admin_mock_yield(ctx: Context<AdminMockYield>, params: AdminMockYieldParams) -> Result<()> {
         instructions::admin_mock_yield::handler(ctx, params)
     }
@@ -92,4 +146,196 @@ pub mod shadowforge {
     pub fn wrap_sol(ctx: Context<WrapSol>, params: WrapSolParams) -> Result<()> {
         instructions::wrap_sol::handler(ctx, params)
     }
+
+    /// Expire a stale dark pool order (permissionless crank)
+    /// Refunds the reserved commitment to the maker and pays the caller a rent-based incentive
+    pub fn expire_order(ctx: Context<ExpireOrder>) -> Result<()> {
+        instructions::expire_order::handler(ctx)
+    }
+
+    /// Manage a scheduled TWAP/DCA swap order
+    /// Supports: create, pause, resume, cancel
+    pub fn recurring_swap(ctx: Context<RecurringSwap>, params: RecurringSwapParams) -> Result<()> {
+        instructions::recurring_swap::handler(ctx, params)
+    }
+
+    /// Execute the next due slice of a recurring swap order (keeper crank)
+    /// Routes through the same venues as an immediate private swap
+    pub fn execute_recurring_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteRecurringSwap<'info>>,
+        params: ExecuteRecurringSwapParams,
+    ) -> Result<()> {
+        instructions::execute_recurring_swap::handler(ctx, params)
+    }
+
+    /// Pin the accepted program ID for external integrations
+    /// Every instruction that receives an integration program account checks it against this registry
+    pub fn update_adapter_registry(
+        ctx: Context<UpdateAdapterRegistry>,
+        params: UpdateAdapterRegistryParams,
+    ) -> Result<()> {
+        instructions::update_adapter_registry::handler(ctx, params)
+    }
+
+    /// Add, update or disable a bridge destination chain
+    /// Holds the per-chain enabled flag, fee override, amount limits and address format
+    pub fn configure_chain(ctx: Context<ConfigureChain>, params: ConfigureChainParams) -> Result<()> {
+        instructions::configure_chain::handler(ctx, params)
+    }
+
+    /// Bridge circuit breaker and global outflow limit
+    /// Supports: pause (admin or guardian), resume and limit changes (admin)
+    pub fn bridge_circuit_breaker(
+        ctx: Context<BridgeCircuitBreaker>,
+        params: BridgeCircuitBreakerParams,
+    ) -> Result<()> {
+        instructions::bridge_circuit_breaker::handler(ctx, params)
+    }
+
+    /// Publish or rotate the relayer's X25519 key
+    /// Outbound requests seal their destination address to the current key version
+    pub fn set_relayer_key(ctx: Context<SetRelayerKey>, params: SetRelayerKeyParams) -> Result<()> {
+        instructions::set_relayer_key::handler(ctx, params)
+    }
+
+    /// Replace the bridge guardian set
+    /// Inbound claims and outbound completions need signatures from `threshold` of these keys
+    pub fn update_guardian_set(
+        ctx: Context<UpdateGuardianSet>,
+        params: UpdateGuardianSetParams,
+    ) -> Result<()> {
+        instructions::update_guardian_set::handler(ctx, params)
+    }
+
+    /// Replace the approved compliance providers
+    /// Submit and Renew need an attestation signed by one of these keys
+    pub fn update_compliance_providers(
+        ctx: Context<UpdateComplianceProviders>,
+        params: UpdateComplianceProvidersParams,
+    ) -> Result<()> {
+        instructions::update_compliance_providers::handler(ctx, params)
+    }
+
+    /// Revoke a user's compliance attestation
+    /// Signed by the provider that issued it, the admin or a compliance officer
+    pub fn revoke_compliance(
+        ctx: Context<RevokeCompliance>,
+        params: RevokeComplianceParams,
+    ) -> Result<()> {
+        instructions::revoke_compliance::handler(ctx, params)
+    }
+
+    /// Set per-tier limits for deposits, withdrawals, swaps and bridges
+    /// Enforced against each user's attestation whenever compliance is required
+    pub fn set_compliance_policy(
+        ctx: Context<SetCompliancePolicy>,
+        params: SetCompliancePolicyParams,
+    ) -> Result<()> {
+        instructions::set_compliance_policy::handler(ctx, params)
+    }
+
+    /// Publish the Merkle root of the sorted sanctions denylist
+    /// Admin or an approved compliance provider; only the admin can change the staleness limit
+    pub fn update_screening_root(
+        ctx: Context<UpdateScreeningRoot>,
+        params: UpdateScreeningRootParams,
+    ) -> Result<()> {
+        instructions::update_screening_root::handler(ctx, params)
+    }
+
+    /// Register or rotate the vault auditor's ElGamal key
+    /// Once set, deposits and withdrawals must disclose their amount to this key
+    pub fn set_auditor_key(ctx: Context<SetAuditorKey>, params: SetAuditorKeyParams) -> Result<()> {
+        instructions::set_auditor_key::handler(ctx, params)
+    }
+
+    /// Prove principal plus yield equals or exceeds a threshold to a named verifier
+    /// Writes a time-bounded disclosure record the verifier can read
+    pub fn disclose_position(
+        ctx: Context<DisclosePosition>,
+        params: DisclosePositionParams,
+    ) -> Result<()> {
+        instructions::disclose_position::handler(ctx, params)
+    }
+
+    /// Add, replace or remove the rule for one jurisdiction
+    /// Rules restrict operations, tighten limits and block bridge destinations
+    pub fn set_jurisdiction_rule(
+        ctx: Context<SetJurisdictionRule>,
+        params: SetJurisdictionRuleParams,
+    ) -> Result<()> {
+        instructions::set_jurisdiction_rule::handler(ctx, params)
+    }
+
+    /// Propose a new admin or cancel a pending proposal
+    /// The current admin keeps full control until the successor accepts
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, params: ProposeAdminParams) -> Result<()> {
+        instructions::propose_admin::handler(ctx, params)
+    }
+
+    /// Accept a pending admin handover
+    /// Must be signed by the proposed admin
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::handler(ctx)
+    }
+
+    /// Grant or revoke an operational role
+    /// Role holders may perform the matching admin actions without the admin key
+    pub fn manage_roles(ctx: Context<ManageRoles>, params: ManageRolesParams) -> Result<()> {
+        instructions::manage_roles::handler(ctx, params)
+    }
+
+    /// Schedule a fee, yield, SDK or delay change behind the timelock
+    /// Signed by the admin or the holder of the change's role, co-signed by the council once configured
+    pub fn schedule_change(ctx: Context<ScheduleChange>, params: ScheduleChangeParams) -> Result<()> {
+        instructions::schedule_change::handler(ctx, params)
+    }
+
+    /// Cancel a scheduled change before it executes
+    /// Admin only, co-signed by the council once configured; rent returns to the proposer
+    pub fn cancel_change(ctx: Context<CancelChange>) -> Result<()> {
+        instructions::cancel_change::handler(ctx)
+    }
+
+    /// Apply a scheduled change once its delay has elapsed
+    /// Callable by anyone
+    pub fn execute_change(ctx: Context<ExecuteChange>) -> Result<()> {
+        instructions::execute_change::handler(ctx)
+    }
+
+    /// Create or replace the M-of-N admin council
+    /// Replacing an existing council needs a threshold of its members to co-sign
+    pub fn configure_council(ctx: Context<ConfigureCouncil>, params: ConfigureCouncilParams) -> Result<()> {
+        instructions::configure_council::handler(ctx, params)
+    }
+
+    /// Propose an admin action to the council
+    /// The proposer's approval counts toward the threshold
+    pub fn council_propose(ctx: Context<CouncilPropose>, params: CouncilProposeParams) -> Result<()> {
+        instructions::council_propose::handler(ctx, params)
+    }
+
+    /// Approve an open council proposal
+    /// Executes the action once the threshold is reached
+    pub fn council_approve(ctx: Context<CouncilApprove>) -> Result<()> {
+        instructions::council_approve::handler(ctx)
+    }
+
+    /// Grow a position created before per-request bridge nonces to the current layout
+    /// Legacy bridge requests held no escrow, so the pending-request counter starts at 0
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        instructions::migrate_position::handler(ctx)
+    }
+
+    /// Grow a dark pool order placed before time-in-force to the current layout
+    /// The order keeps its status and reads as good-til-cancelled
+    pub fn migrate_dark_pool_order(ctx: Context<MigrateDarkPoolOrder>) -> Result<()> {
+        instructions::migrate_dark_pool_order::handler(ctx)
+    }
+
+    /// Grow an attestation accepted before provider-signed attestations to the current layout
+    /// The attestation is invalidated until a signed one is submitted
+    pub fn migrate_compliance_attestation(ctx: Context<MigrateComplianceAttestation>) -> Result<()> {
+        instructions::migrate_compliance_attestation::handler(ctx)
+    }
 }
@@ -5,44 +5,55 @@ pub const VAULT_CONFIG_SEED: &[u8] = b"vault_config";
 pub const USER_POSITION_SEED: &[u8] = b"user_position";
 pub const SHIELDED_VAULT_SEED: &[u8] = b"shielded_vault";
 pub const COMPLIANCE_SEED: &[u8] = b"compliance";
+pub const DARK_POOL_ORDER_SEED: &[u8] = b"dark_pool_order";
+pub const RECURRING_SWAP_SEED: &[u8] = b"recurring_swap";
+pub const ADAPTER_REGISTRY_SEED: &[u8] = b"adapter_registry";
+pub const BRIDGE_ESCROW_SEED: &[u8] = b"bridge_escrow";
+pub const GUARDIAN_SET_SEED: &[u8] = b"guardian_set";
+pub const CONSUMED_MESSAGE_SEED: &[u8] = b"consumed_message";
+pub const CHAIN_CONFIG_SEED: &[u8] = b"chain_config";
+pub const BRIDGE_REQUEST_SEED: &[u8] = b"bridge_request";
+pub const BRIDGE_STATS_SEED: &[u8] = b"bridge_stats";
+pub const RELAYER_KEY_SEED: &[u8] = b"relayer_key";
+pub const COMPLIANCE_PROVIDERS_SEED: &[u8] = b"compliance_providers";
+pub const COMPLIANCE_POLICY_SEED: &[u8] = b"compliance_policy";
+pub const SCREENING_SEED: &[u8] = b"screening";
+pub const AUDITOR_KEY_SEED: &[u8] = b"auditor_key";
+pub const DISCLOSURE_SEED: &[u8] = b"disclosure";
+pub const JURISDICTION_REGISTRY_SEED: &[u8] = b"jurisdictions";
+pub const ROLE_REGISTRY_SEED: &[u8] = b"roles";
+pub const TIMELOCK_SEED: &[u8] = b"timelock";
+pub const QUEUED_CHANGE_SEED: &[u8] = b"queued_change";
+pub const COUNCIL_SEED: &[u8] = b"council";
+pub const COUNCIL_PROPOSAL_SEED: &[u8] = b"council_proposal";
 
 // Protocol Constants
 pub const MAX_BASIS_POINTS: u16 = 10_000;
 pub const MIN_DEPOSIT_LAMPORTS: u64 = 1_000_000;
 pub const PROOF_DATA_LEN: usize = 32;
-
-// External Program IDs (from sponsor documentation)
-// These are placeholder addresses for the hackathon demo since real SDK programs don't exist yet
-// Using byte arrays to avoid IDL conflicts with declare_id!
-
-pub const ARCIUM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    148, 83, 91, 105, 246, 117, 211, 118, 62, 186, 169, 61, 30, 78, 214, 63,
-    163, 100, 65, 151, 167, 46, 97, 138, 124, 31, 84, 214, 0, 0, 0, 0
-]);
-pub const SHADOWWIRE_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    5, 73, 14, 162, 232, 118, 145, 231, 181, 59, 107, 2, 34, 225, 107, 189,
-    127, 41, 107, 63, 167, 214, 175, 125, 143, 171, 46, 30, 0, 0, 0, 0
-]);
-pub const PRIVACY_CASH_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    6, 56, 154, 205, 65, 187, 56, 188, 181, 82, 113, 114, 195, 39, 217, 117,
-    216, 160, 81, 170, 110, 247, 149, 127, 57, 22, 168, 100, 0, 0, 0, 1
-]);
-pub const SILENTSWAP_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    5, 148, 24, 234, 220, 63, 33, 127, 48, 159, 134, 127, 48, 159, 134, 127,
-    48, 159, 134, 127, 48, 159, 134, 127, 48, 159, 134, 127, 0, 0, 0, 0
-]);
-pub const STARPAY_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    5, 203, 46, 194, 153, 48, 159, 134, 127, 48, 159, 134, 127, 48, 159, 134,
-    127, 48, 159, 134, 127, 48, 159, 134, 127, 48, 159, 134, 0, 0, 0, 0
-]);
-pub const ANONCOIN_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    142, 178, 229, 46, 218, 48, 159, 134, 127, 48, 159, 134, 127, 48, 159, 134,
-    127, 48, 159, 134, 127, 48, 159, 134, 127, 48, 159, 134, 0, 0, 0, 0
-]);
-pub const RANGE_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    15, 37, 156, 224, 228, 48, 159, 134, 127, 48, 159, 134, 127, 48, 159, 134,
-    127, 48, 159, 134, 127, 48, 159, 134, 127, 48, 159, 134, 0, 0, 0, 0
-]);
+pub const EXPIRE_ORDER_INCENTIVE_BPS: u16 = 1_000;
+pub const MAX_SLIPPAGE_BPS: u16 = 1_000;
+pub const MIN_RECURRING_INTERVAL_SECS: i64 = 60;
+pub const MAX_GUARDIANS: usize = 10;
+pub const MAX_PENDING_BRIDGE_REQUESTS: u32 = 8;
+pub const BRIDGE_OUTFLOW_WINDOW_SECS: i64 = 86_400;
+pub const MAX_COMPLIANCE_PROVIDERS: usize = 8;
+pub const MAX_ACCEPTED_RISK_SCORE: u8 = 75;
+pub const MAX_COMPLIANCE_VALIDITY_SECS: i64 = 365 * 86_400;
+pub const COMPLIANCE_TIER_COUNT: usize = 3;
+pub const MAX_SCREENING_TREE_DEPTH: usize = 24;
+/// Twisted ElGamal ciphertext: Pedersen commitment followed by the decryption handle
+pub const ELGAMAL_CIPHERTEXT_LEN: usize = 64;
+pub const MAX_DISCLOSURE_VALIDITY_SECS: i64 = 90 * 86_400;
+pub const MAX_JURISDICTION_RULES: usize = 16;
+pub const MAX_ROLE_MEMBERS: usize = 16;
+pub const DEFAULT_TIMELOCK_DELAY_SECS: i64 = 2 * 86_400;
+pub const MAX_TIMELOCK_DELAY_SECS: i64 = 30 * 86_400;
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+pub const MAX_PROPOSAL_TTL_SECS: i64 = 30 * 86_400;
+pub const MAX_BLOCKED_CHAINS: usize = 8;
+pub const COMPLIANCE_ATTESTATION_DOMAIN: &[u8] = b"shadowforge:compliance_attestation:v2";
+pub const BRIDGE_ATTESTATION_DOMAIN: &[u8] = b"shadowforge:bridge_attestation:v2";
 
 #[account]
 pub struct VaultConfig {
@@ -65,13 +76,15 @@ pub struct VaultConfig {
     pub current_yield_bps: u16,
     pub total_shielded_tvl: u64,
     pub total_positions: u64,
-    pub is_paused: bool,
+    /// One `PausableOperation::bit` per paused operation; bit 0 is the former `is_paused` flag
+    pub paused_operations: u8,
     pub emergency_mode: bool,
     pub compliance_required: bool,
     pub initialized_at: i64,
     pub last_yield_update: i64,
     pub bump: u8,
-    pub _reserved: [u8; 32],
+    /// Proposed successor to `admin`; default when no handover is pending
+    pub pending_admin: Pubkey,
 }
 
 impl Default for VaultConfig {
@@ -96,13 +109,13 @@ impl Default for VaultConfig {
             current_yield_bps: 0,
             total_shielded_tvl: 0,
             total_positions: 0,
-            is_paused: false,
+            paused_operations: 0,
             emergency_mode: false,
             compliance_required: false,
             initialized_at: 0,
             last_yield_update: 0,
             bump: 0,
-            _reserved: [0u8; 32],
+            pending_admin: Pubkey::default(),
         }
     }
 }
@@ -110,8 +123,313 @@ impl Default for VaultConfig {
 impl VaultConfig {
     pub const LEN: usize = 8 + 32 * 4 + 7 + 6 * 2 + 8 * 2 + 3 + 8 * 2 + 1 + 32;
 
-    pub fn is_operational(&self) -> bool {
-        !self.is_paused && !self.emergency_mode
+    /// Emergency mode halts everything; otherwise only the operation's own pause bit applies
+    pub fn is_operational(&self, operation: PausableOperation) -> bool {
+        self.paused_operations & (operation.bit() | PausableOperation::LEGACY_PAUSED) == 0 &&
+            !self.emergency_mode
+    }
+
+    pub fn has_pending_admin(&self) -> bool {
+        self.pending_admin != Pubkey::default()
+    }
+}
+
+/// Operation families that can be paused independently
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum PausableOperation {
+    Deposit,
+    Withdraw,
+    Lend,
+    Swap,
+    Bridge,
+    Compliance,
+    Wrap,
+}
+
+impl PausableOperation {
+    /// Byte value of the `is_paused` flag this field replaced; a vault paused under it stays
+    /// fully paused until its pause bits are next updated
+    pub const LEGACY_PAUSED: u8 = 1;
+    pub const ALL: u8 = ((1 << 7) - 1) << 1;
+
+    pub fn bit(self) -> u8 {
+        1 << (self as u8 + 1)
+    }
+}
+
+/// Operational roles the admin can delegate; the admin implicitly holds all of them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Role {
+    /// Pause the vault and toggle emergency mode
+    Pauser,
+    /// Change protocol fees
+    FeeManager,
+    /// Change the yield rate
+    YieldManager,
+    /// Toggle the vault-wide compliance requirement and revoke any user's attestation
+    ComplianceOfficer,
+    /// Enable or disable SDK integrations
+    SdkOperator,
+    /// Deposit reward tokens into the vault
+    Treasurer,
+}
+
+impl Role {
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Roles held by one key, one `Role::bit` each
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RoleAssignment {
+    pub member: Pubkey,
+    pub roles: u8,
+}
+
+impl RoleAssignment {
+    pub const LEN: usize = 32 + 1;
+}
+
+/// Admin-managed mapping of keys to operational roles
+#[account]
+#[derive(Default)]
+pub struct RoleRegistry {
+    pub vault: Pubkey,
+    pub members: Vec<RoleAssignment>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl RoleRegistry {
+    pub const LEN: usize = 8 + 32 + 4 + RoleAssignment::LEN * MAX_ROLE_MEMBERS + 8 + 1;
+
+    pub fn has_role(&self, member: &Pubkey, role: Role) -> bool {
+        self.members
+            .iter()
+            .any(|m| m.member == *member && m.roles & role.bit() != 0)
+    }
+}
+
+/// Vault parameter change that only takes effect after the timelock delay
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ParameterChange {
+    UpdateFees {
+        deposit_fee_bps: Option<u16>,
+        withdrawal_fee_bps: Option<u16>,
+        lending_fee_bps: Option<u16>,
+        swap_fee_bps: Option<u16>,
+        bridge_fee_bps: Option<u16>,
+    },
+    UpdateYieldRate { new_rate_bps: u16 },
+    ToggleSdk {
+        arcium: Option<bool>,
+        shadowwire: Option<bool>,
+        anoncoin: Option<bool>,
+        privacy_cash: Option<bool>,
+        silentswap: Option<bool>,
+        starpay: Option<bool>,
+        range: Option<bool>,
+    },
+    /// Change the timelock delay itself
+    SetDelay { delay_secs: i64 },
+}
+
+impl Default for ParameterChange {
+    fn default() -> Self {
+        ParameterChange::UpdateYieldRate { new_rate_bps: 0 }
+    }
+}
+
+impl ParameterChange {
+    /// Tag plus the largest variant (five optional fees)
+    pub const LEN: usize = 1 + 5 * 3;
+
+    /// Role a non-admin signer needs to schedule this change; `None` means admin only
+    pub fn required_role(&self) -> Option<Role> {
+        match self {
+            ParameterChange::UpdateFees { .. } => Some(Role::FeeManager),
+            ParameterChange::UpdateYieldRate { .. } => Some(Role::YieldManager),
+            ParameterChange::ToggleSdk { .. } => Some(Role::SdkOperator),
+            ParameterChange::SetDelay { .. } => None,
+        }
+    }
+}
+
+/// Delay applied to parameter changes and the id of the next one
+#[account]
+#[derive(Default)]
+pub struct Timelock {
+    pub vault: Pubkey,
+    pub delay_secs: i64,
+    pub next_change_id: u64,
+    pub bump: u8,
+}
+
+impl Timelock {
+    pub const LEN: usize = 8 + 32 + 8 + 8 + 1;
+}
+
+/// One scheduled parameter change, closed when executed or cancelled
+#[account]
+#[derive(Default)]
+pub struct QueuedChange {
+    pub vault: Pubkey,
+    pub id: u64,
+    pub change: ParameterChange,
+    pub proposer: Pubkey,
+    pub queued_at: i64,
+    pub execute_after: i64,
+    pub bump: u8,
+}
+
+impl QueuedChange {
+    pub const LEN: usize = 8 + 32 + 8 + ParameterChange::LEN + 32 + 8 + 8 + 1;
+}
+
+/// M-of-N signers that approve privileged admin actions once configured
+#[account]
+#[derive(Default)]
+pub struct AdminCouncil {
+    pub vault: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    /// How long a proposal stays open for approvals
+    pub proposal_ttl_secs: i64,
+    pub next_proposal_id: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl AdminCouncil {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_COUNCIL_MEMBERS + 1 + 8 + 8 + 8 + 1;
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
+
+/// Vault-level action taken by the admin, a role holder, or an approved council proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum AdminAction {
+    /// Deposit reward tokens into vault
+    DepositRewards { amount: u64 },
+    /// Pause/unpause every operation
+    SetPaused { paused: bool },
+    /// Pause/unpause a single operation
+    SetOperationPaused { operation: PausableOperation, paused: bool },
+    /// Toggle emergency mode
+    SetEmergencyMode { enabled: bool },
+    /// Toggle compliance requirement
+    SetComplianceRequired { required: bool },
+}
+
+impl AdminAction {
+    /// Tag plus the largest variant (reward amount)
+    pub const LEN: usize = 1 + 8;
+
+    /// Whether this action needs council approval once a council is configured
+    /// Pausing and entering emergency mode stay immediate; lifting either needs the council
+    pub fn requires_council(&self) -> bool {
+        !matches!(
+            self,
+            AdminAction::DepositRewards { .. } |
+            AdminAction::SetPaused { paused: true } |
+            AdminAction::SetOperationPaused { paused: true, .. } |
+            AdminAction::SetEmergencyMode { enabled: true }
+        )
+    }
+
+    /// Role a non-admin signer needs to perform this action
+    pub fn required_role(&self) -> Role {
+        match self {
+            AdminAction::DepositRewards { .. } => Role::Treasurer,
+            AdminAction::SetPaused { .. }
+            | AdminAction::SetOperationPaused { .. }
+            | AdminAction::SetEmergencyMode { .. } => Role::Pauser,
+            AdminAction::SetComplianceRequired { .. } => Role::ComplianceOfficer,
+        }
+    }
+}
+
+/// Admin action awaiting council approvals
+#[account]
+pub struct CouncilProposal {
+    pub vault: Pubkey,
+    pub id: u64,
+    pub action: AdminAction,
+    pub proposer: Pubkey,
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl CouncilProposal {
+    pub const LEN: usize = 8 + 32 + 8 + AdminAction::LEN + 32 + 4 + 32 * MAX_COUNCIL_MEMBERS + 8 + 8 + 1 + 1;
+}
+
+/// External integrations whose program IDs are pinned in the adapter registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum Integration {
+    Arcium,
+    ShadowWire,
+    Anoncoin,
+    PrivacyCash,
+    SilentSwap,
+    Starpay,
+    Range,
+}
+
+/// Admin-managed allowlist of the program ID accepted for each integration
+/// An unset (default) entry means the integration is not usable yet
+#[account]
+#[derive(Default)]
+pub struct AdapterRegistry {
+    pub vault: Pubkey,
+    pub arcium_program: Pubkey,
+    pub shadowwire_program: Pubkey,
+    pub anoncoin_program: Pubkey,
+    pub privacy_cash_program: Pubkey,
+    pub silentswap_program: Pubkey,
+    pub starpay_program: Pubkey,
+    pub range_program: Pubkey,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl AdapterRegistry {
+    pub const LEN: usize = 8 + 32 * 8 + 8 + 1;
+
+    pub fn program_id(&self, integration: Integration) -> Pubkey {
+        match integration {
+            Integration::Arcium => self.arcium_program,
+            Integration::ShadowWire => self.shadowwire_program,
+            Integration::Anoncoin => self.anoncoin_program,
+            Integration::PrivacyCash => self.privacy_cash_program,
+            Integration::SilentSwap => self.silentswap_program,
+            Integration::Starpay => self.starpay_program,
+            Integration::Range => self.range_program,
+        }
+    }
+
+    pub fn set_program_id(&mut self, integration: Integration, program_id: Pubkey) {
+        let slot = match integration {
+            Integration::Arcium => &mut self.arcium_program,
+            Integration::ShadowWire => &mut self.shadowwire_program,
+            Integration::Anoncoin => &mut self.anoncoin_program,
+            Integration::PrivacyCash => &mut self.privacy_cash_program,
+            Integration::SilentSwap => &mut self.silentswap_program,
+            Integration::Starpay => &mut self.starpay_program,
+            Integration::Range => &mut self.range_program,
+        };
+        *slot = program_id;
+    }
+
+    pub fn is_registered(&self, integration: Integration, program: &Pubkey) -> bool {
+        let registered = self.program_id(integration);
+        registered != Pubkey::default() && registered == *program
     }
 }
 
@@ -130,6 +448,26 @@ impl EncryptedAmount {
     }
 }
 
+/// Remote-chain recipient sealed to the relayer's X25519 key with XChaCha20-Poly1305
+/// Addresses are zero-padded to 32 bytes, so 20-byte EVM and 32-byte recipients look alike
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct EncryptedRecipient {
+    /// `RelayerKeyRegistry::key_version` the recipient was sealed to
+    pub key_version: u32,
+    pub ephemeral_pubkey: [u8; 32],
+    pub nonce: [u8; 24],
+    pub ciphertext: [u8; 32],
+    pub tag: [u8; 16],
+}
+
+impl EncryptedRecipient {
+    pub const LEN: usize = 4 + 32 + 24 + 32 + 16;
+
+    pub fn is_empty(&self) -> bool {
+        self.ephemeral_pubkey.iter().all(|&b| b == 0) || self.ciphertext.iter().all(|&b| b == 0)
+    }
+}
+
 #[account]
 pub struct UserEncryptedPosition {
     pub owner: Pubkey,
@@ -139,6 +477,7 @@ pub struct UserEncryptedPosition {
     pub balance_commitment: [u8; 32],
     pub nullifier: [u8; 32],
     pub has_active_loan: bool,
+    /// Mirrors `pending_bridge_count > 0`; kept in its original slot for existing accounts
     pub has_pending_bridge: bool,
     pub compliance_verified: bool,
     pub compliance_expiry: i64,
@@ -149,6 +488,9 @@ pub struct UserEncryptedPosition {
     pub withdrawal_count: u32,
     pub action_count: u32,
     pub bump: u8,
+    // Appended by `migrate_position` on accounts created before per-request nonces
+    pub pending_bridge_count: u32,
+    pub bridge_nonce: u64,
 }
 
 impl Default for UserEncryptedPosition {
@@ -171,16 +513,25 @@ impl Default for UserEncryptedPosition {
             withdrawal_count: 0,
             action_count: 0,
             bump: 0,
+            pending_bridge_count: 0,
+            bridge_nonce: 0,
         }
     }
 }
 
 impl UserEncryptedPosition {
-    pub const LEN: usize = 8 + 32 * 2 + EncryptedAmount::LEN * 2 + 32 * 2 + 3 + 8 * 4 + 4 * 3 + 1;
+    /// Size before `pending_bridge_count` and `bridge_nonce` were appended
+    pub const LEGACY_LEN: usize = 8 + 32 * 2 + EncryptedAmount::LEN * 2 + 32 * 2 + 3 + 8 * 4 + 4 * 3 + 1;
+    pub const LEN: usize = Self::LEGACY_LEN + 4 + 8;
 
     pub fn is_compliant(&self, current_time: i64) -> bool {
         self.compliance_verified && self.compliance_expiry > current_time
     }
+
+    pub fn set_pending_bridge_count(&mut self, count: u32) {
+        self.pending_bridge_count = count;
+        self.has_pending_bridge = count > 0;
+    }
 }
 
 #[account]
@@ -193,6 +544,14 @@ pub struct ComplianceAttestation {
     pub risk_score: u8,
     pub is_valid: bool,
     pub bump: u8,
+    pub tier: ComplianceTier,
+    /// ISO 3166-1 alpha-2 country code
+    pub jurisdiction: [u8; 2],
+    pub usage: ComplianceUsage,
+    /// Provider issue time of the accepted attestation
+    pub issued_at: i64,
+    /// Last revocation; attestations issued at or before it are refused
+    pub revoked_at: i64,
 }
 
 impl Default for ComplianceAttestation {
@@ -206,21 +565,331 @@ impl Default for ComplianceAttestation {
             risk_score: 0,
             is_valid: false,
             bump: 0,
+            tier: ComplianceTier::default(),
+            jurisdiction: [0u8; 2],
+            usage: ComplianceUsage::default(),
+            issued_at: 0,
+            revoked_at: 0,
         }
     }
 }
 
 impl ComplianceAttestation {
-    pub const LEN: usize = 8 + 32 * 3 + 8 * 2 + 3;
+    /// Size before provider signatures, tiers and jurisdictions were appended
+    pub const LEGACY_LEN: usize = 8 + 32 * 3 + 8 * 2 + 1 + 2;
+    pub const LEN: usize = Self::LEGACY_LEN + 1 + 2 + ComplianceUsage::LEN + 8 * 2;
+
+    /// Newer attestations must be issued strictly after this time
+    pub fn issued_after(&self) -> i64 {
+        self.issued_at.max(self.revoked_at)
+    }
 }
 
+/// Value-moving operations a jurisdiction rule can permit; all but `Lend` count against
+/// a tier's limits, since loan amounts are only ever committed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum ComplianceOperation {
+    Deposit,
+    Withdraw,
+    Swap,
+    Bridge,
+    Lend,
+}
+
+impl ComplianceOperation {
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Caps for one operation; zero means unlimited
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct OperationLimit {
+    pub per_transaction: u64,
+    pub per_period: u64,
+}
+
+impl OperationLimit {
+    pub const LEN: usize = 8 * 2;
+    pub const UNLIMITED: OperationLimit = OperationLimit { per_transaction: 0, per_period: 0 };
+
+    /// The stricter of two limits, field by field
+    pub fn tightened(&self, other: &OperationLimit) -> OperationLimit {
+        let stricter = |a: u64, b: u64| match (a, b) {
+            (0, b) => b,
+            (a, 0) => a,
+            (a, b) => a.min(b),
+        };
+        OperationLimit {
+            per_transaction: stricter(self.per_transaction, other.per_transaction),
+            per_period: stricter(self.per_period, other.per_period),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TierLimits {
+    pub deposit: OperationLimit,
+    pub withdraw: OperationLimit,
+    pub swap: OperationLimit,
+    pub bridge: OperationLimit,
+}
+
+impl TierLimits {
+    pub const LEN: usize = OperationLimit::LEN * 4;
+
+    pub fn for_operation(&self, operation: ComplianceOperation) -> &OperationLimit {
+        match operation {
+            ComplianceOperation::Deposit => &self.deposit,
+            ComplianceOperation::Withdraw => &self.withdraw,
+            ComplianceOperation::Swap => &self.swap,
+            ComplianceOperation::Bridge => &self.bridge,
+            ComplianceOperation::Lend => &OperationLimit::UNLIMITED,
+        }
+    }
+
+    /// The stricter of two limit sets, operation by operation
+    pub fn tightened(&self, other: &TierLimits) -> TierLimits {
+        TierLimits {
+            deposit: self.deposit.tightened(&other.deposit),
+            withdraw: self.withdraw.tightened(&other.withdraw),
+            swap: self.swap.tightened(&other.swap),
+            bridge: self.bridge.tightened(&other.bridge),
+        }
+    }
+}
+
+/// Vault-level limits per compliance tier, indexed by `ComplianceTier as usize`
+#[account]
+#[derive(Default)]
+pub struct CompliancePolicy {
+    pub vault: Pubkey,
+    /// Length of the rolling period `per_period` limits apply to
+    pub period_secs: i64,
+    pub tiers: [TierLimits; COMPLIANCE_TIER_COUNT],
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl CompliancePolicy {
+    pub const LEN: usize = 8 + 32 + 8 + TierLimits::LEN * COMPLIANCE_TIER_COUNT + 8 + 1;
+
+    pub fn limits(&self, tier: ComplianceTier) -> &TierLimits {
+        &self.tiers[tier as usize]
+    }
+}
+
+/// Restrictions on users attested to one jurisdiction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct JurisdictionRule {
+    /// ISO 3166-1 alpha-2 country code
+    pub jurisdiction: [u8; 2],
+    /// One `ComplianceOperation::bit` per permitted operation
+    pub allowed_operations: u8,
+    /// Caps applied on top of the tier limits
+    pub limits: TierLimits,
+    /// Destination chains these users may not bridge to
+    pub blocked_chains: Vec<u64>,
+}
+
+impl JurisdictionRule {
+    pub const LEN: usize = 2 + 1 + TierLimits::LEN + 4 + 8 * MAX_BLOCKED_CHAINS;
+
+    pub fn allows(&self, operation: ComplianceOperation) -> bool {
+        self.allowed_operations & operation.bit() != 0
+    }
+}
+
+/// Per-jurisdiction rules; jurisdictions without a rule are unrestricted beyond their tier
+#[account]
+#[derive(Default)]
+pub struct JurisdictionRegistry {
+    pub vault: Pubkey,
+    pub rules: Vec<JurisdictionRule>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl JurisdictionRegistry {
+    pub const LEN: usize = 8 + 32 + 4 + JurisdictionRule::LEN * MAX_JURISDICTION_RULES + 8 + 1;
+
+    pub fn rule(&self, jurisdiction: &[u8; 2]) -> Option<&JurisdictionRule> {
+        self.rules.iter().find(|r| r.jurisdiction == *jurisdiction)
+    }
+}
+
+/// Merkle root of the sorted sanctions denylist
+/// Leaves are the denylisted addresses in ascending byte order, bracketed by the all-zero
+/// and all-0xff keys so every other address falls between two adjacent leaves
+#[account]
+#[derive(Default)]
+pub struct ScreeningRoot {
+    pub vault: Pubkey,
+    pub root: [u8; 32],
+    pub version: u64,
+    pub updated_at: i64,
+    pub updated_by: Pubkey,
+    /// Screening fails closed once the root is older than this
+    pub max_staleness_secs: i64,
+    pub bump: u8,
+}
+
+impl ScreeningRoot {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 32 + 8 + 1;
+
+    pub fn is_stale(&self, current_time: i64) -> bool {
+        current_time.saturating_sub(self.updated_at) > self.max_staleness_secs
+    }
+}
+
+/// Vault auditor's ElGamal key; deposits and withdrawals re-encrypt their amount under it
+#[account]
+#[derive(Default)]
+pub struct AuditorKey {
+    pub vault: Pubkey,
+    /// Authority allowed to act as the designated auditor
+    pub auditor: Pubkey,
+    pub elgamal_pubkey: [u8; 32],
+    pub key_version: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl AuditorKey {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 4 + 8 + 1;
+}
+
+/// What a disclosure proves about principal plus yield
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub enum DisclosureKind {
+    #[default]
+    Exact,
+    AtLeast,
+}
+
+/// Proven statement about a user's shielded balance, readable by the named verifier
+#[account]
+#[derive(Default)]
+pub struct DisclosureRecord {
+    pub user: Pubkey,
+    pub verifier: Pubkey,
+    pub kind: DisclosureKind,
+    pub threshold: u64,
+    /// Position ciphertexts the proof was checked against
+    pub principal_commitment: [u8; 32],
+    pub yield_commitment: [u8; 32],
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl DisclosureRecord {
+    pub const LEN: usize = 8 + 32 * 2 + 1 + 8 + 32 * 2 + 8 * 2 + 1;
+
+    pub fn is_active(&self, current_time: i64) -> bool {
+        current_time < self.expires_at
+    }
+}
+
+/// Volume a user has moved over the rolling compliance period
+/// Each total drains linearly at its `per_period` limit per period rather than resetting at a
+/// fixed boundary, so a limit cannot be spent just before a reset and again right after it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct ComplianceUsage {
+    /// When the totals were last drained
+    pub updated_at: i64,
+    pub deposited: u64,
+    pub withdrawn: u64,
+    pub swapped: u64,
+    pub bridged: u64,
+}
+
+impl ComplianceUsage {
+    pub const LEN: usize = 8 * 5;
+
+    /// Add `amount` to the operation's drained total unless it breaks either of its caps in `limits`
+    pub fn try_record(
+        &mut self,
+        limits: &TierLimits,
+        operation: ComplianceOperation,
+        amount: u64,
+        current_time: i64,
+        period_secs: i64,
+    ) -> bool {
+        let limit = limits.for_operation(operation);
+        if limit.per_transaction != 0 && amount > limit.per_transaction {
+            return false;
+        }
+
+        let since = self.updated_at;
+        let drain = |used: &mut u64, limit: &OperationLimit| {
+            *used = used.saturating_sub(drained_since(limit.per_period, since, current_time, period_secs));
+        };
+        drain(&mut self.deposited, &limits.deposit);
+        drain(&mut self.withdrawn, &limits.withdraw);
+        drain(&mut self.swapped, &limits.swap);
+        drain(&mut self.bridged, &limits.bridge);
+        self.updated_at = current_time;
+
+        let used = match operation {
+            ComplianceOperation::Deposit => &mut self.deposited,
+            ComplianceOperation::Withdraw => &mut self.withdrawn,
+            ComplianceOperation::Swap => &mut self.swapped,
+            ComplianceOperation::Bridge => &mut self.bridged,
+            ComplianceOperation::Lend => return true,
+        };
+
+        match used.checked_add(amount) {
+            Some(total) if limit.per_period == 0 || total <= limit.per_period => {
+                *used = total;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// KYC level a provider vouches for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub enum ComplianceTier {
+    #[default]
+    Basic,
+    Verified,
+    Institutional,
+}
+
+/// Ed25519 keys of the compliance providers whose signed attestations are accepted
+#[account]
+#[derive(Default)]
+pub struct ComplianceProviderRegistry {
+    pub vault: Pubkey,
+    pub providers: Vec<Pubkey>,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ComplianceProviderRegistry {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_COMPLIANCE_PROVIDERS + 8 + 1;
+
+    pub fn is_approved(&self, provider: &Pubkey) -> bool {
+        self.providers.contains(provider)
+    }
+}
+
+/// Keyed by the position's `bridge_nonce`; requests opened under the earlier per-user seeds
+/// are never derived again, so their layout needs no migration
 #[account]
 pub struct BridgeRequest {
     pub user: Pubkey,
     pub dest_chain_id: u64,
+    pub nonce: u64,
     pub amount_commitment: [u8; 32],
+    pub encrypted_recipient: EncryptedRecipient,
+    pub escrowed_amount: u64,
+    pub fee_amount: u64,
     pub status: BridgeStatus,
     pub created_at: i64,
+    pub expires_at: i64,
     pub bump: u8,
 }
 
@@ -229,22 +898,191 @@ impl Default for BridgeRequest {
         Self {
             user: Pubkey::default(),
             dest_chain_id: 0,
+            nonce: 0,
             amount_commitment: [0u8; 32],
+            encrypted_recipient: EncryptedRecipient::default(),
+            escrowed_amount: 0,
+            fee_amount: 0,
             status: BridgeStatus::default(),
             created_at: 0,
+            expires_at: 0,
             bump: 0,
         }
     }
 }
 
 impl BridgeRequest {
-    pub const LEN: usize = 8 + 32 + 8 + 32 + 1 + 8 + 1;
+    pub const LEN: usize = 8 + 32 + 8 * 2 + 32 + EncryptedRecipient::LEN + 8 * 2 + 1 + 8 * 2 + 1;
+
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        self.status == BridgeStatus::Pending && current_time > self.expires_at
+    }
+
+    /// Escrow is still held and the request awaits a final outcome
+    pub fn is_in_flight(&self) -> bool {
+        matches!(self.status, BridgeStatus::Pending | BridgeStatus::Confirmed)
+    }
+}
+
+/// Admin-managed bridge settings for one remote chain, keyed by its chain id
+/// Chains are added or disabled by writing this account, without a program upgrade
+#[account]
+#[derive(Default)]
+pub struct ChainConfig {
+    pub vault: Pubkey,
+    pub chain_id: u64,
+    pub enabled: bool,
+    /// Replaces `VaultConfig::bridge_fee_bps` for this chain when set
+    pub fee_bps_override: Option<u16>,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub required_confirmations: u32,
+    pub address_format: AddressFormat,
+    /// Seconds a pending request may wait for completion before it can be refunded
+    pub timeout_secs: i64,
+    pub outflow: OutflowWindow,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl ChainConfig {
+    pub const LEN: usize = 8 + 32 + 8 + 1 + (1 + 2) + 8 * 2 + 4 + 1 + 8 + OutflowWindow::LEN + 8 + 1;
+
+    pub fn fee_bps(&self, default_fee_bps: u16) -> u16 {
+        self.fee_bps_override.unwrap_or(default_fee_bps)
+    }
+}
+
+/// Outbound volume over a rolling `BRIDGE_OUTFLOW_WINDOW_SECS`
+/// Recorded outflow drains linearly at `limit` per window instead of resetting at a fixed
+/// boundary, so the limit cannot be spent once before a reset and again right after it
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct OutflowWindow {
+    /// Maximum volume per window; zero means unlimited
+    pub limit: u64,
+    /// When `outflow` was last drained
+    pub updated_at: i64,
+    pub outflow: u64,
+}
+
+impl OutflowWindow {
+    pub const LEN: usize = 8 * 3;
+
+    /// Add `amount` to the drained outflow unless it would push it over the limit
+    pub fn try_record(&mut self, amount: u64, current_time: i64) -> bool {
+        let outflow = self.outflow.saturating_sub(drained_since(
+            self.limit,
+            self.updated_at,
+            current_time,
+            BRIDGE_OUTFLOW_WINDOW_SECS,
+        ));
+
+        match outflow.checked_add(amount) {
+            Some(outflow) if self.limit == 0 || outflow <= self.limit => {
+                self.outflow = outflow;
+                self.updated_at = current_time;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Volume a `limit`-per-`window_secs` allowance frees up between `since` and `current_time`
+pub fn drained_since(limit: u64, since: i64, current_time: i64, window_secs: i64) -> u64 {
+    let elapsed = current_time.saturating_sub(since).clamp(0, window_secs);
+    (limit as u128 * elapsed as u128 / window_secs.max(1) as u128) as u64
+}
+
+/// Vault-wide bridge outflow tracking and the bridge-only circuit breaker
+#[account]
+#[derive(Default)]
+pub struct BridgeStats {
+    pub vault: Pubkey,
+    pub outflow: OutflowWindow,
+    /// Halts new outbound requests and inbound claims without pausing the rest of the vault
+    pub bridge_paused: bool,
+    pub paused_by: Pubkey,
+    pub paused_at: i64,
+    pub bump: u8,
+}
+
+impl BridgeStats {
+    pub const LEN: usize = 8 + 32 + OutflowWindow::LEN + 1 + 32 + 8 + 1;
+}
+
+/// Encoding of recipient addresses on the remote chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq)]
+pub enum AddressFormat {
+    /// 20-byte hex address (Ethereum and other EVM chains)
+    #[default]
+    Evm,
+    /// 32-byte base58 public key
+    Base58,
+    /// Bech32 with a human-readable prefix (Cosmos-style chains)
+    Bech32,
+}
+
+/// X25519 key the bridge relayer publishes for sealing destination addresses
+/// Rotating the key bumps `key_version` so requests sealed to the old key are rejected
+#[account]
+#[derive(Default)]
+pub struct RelayerKeyRegistry {
+    pub vault: Pubkey,
+    /// Signer that acknowledges pickup of outbound requests
+    pub relayer: Pubkey,
+    pub encryption_pubkey: [u8; 32],
+    pub key_version: u32,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl RelayerKeyRegistry {
+    pub const LEN: usize = 8 + 32 * 3 + 4 + 8 + 1;
+}
+
+/// Ed25519 keys whose signatures attest to bridge events on other chains
+/// `threshold` distinct guardians must sign before an attestation is accepted
+#[account]
+#[derive(Default)]
+pub struct GuardianSet {
+    pub vault: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    pub const LEN: usize = 8 + 32 + 4 + 32 * MAX_GUARDIANS + 1 + 8 + 1;
+}
+
+/// Marker recording that an inbound bridge message has been claimed
+/// Seeded by (source chain id, nonce) so each message can be claimed exactly once
+#[account]
+#[derive(Default)]
+pub struct ConsumedMessage {
+    pub source_chain_id: u64,
+    pub nonce: u64,
+    pub message_hash: [u8; 32],
+    pub recipient: Pubkey,
+    pub consumed_at: i64,
+    pub bump: u8,
+}
+
+impl ConsumedMessage {
+    pub const LEN: usize = 8 + 8 * 2 + 32 * 2 + 8 + 1;
+
+    pub fn is_consumed(&self) -> bool {
+        self.consumed_at != 0
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
 pub enum BridgeStatus {
     #[default]
     Pending,
+    /// Picked up by the relayer; the owner can no longer cancel
     Confirmed,
     Completed,
     Failed,
@@ -277,6 +1115,8 @@ pub struct DarkPoolOrder {
     pub status: OrderStatus,
     pub created_at: i64,
     pub bump: u8,
+    pub time_in_force: TimeInForce,
+    pub expires_at: i64,
 }
 
 impl Default for DarkPoolOrder {
@@ -289,12 +1129,78 @@ impl Default for DarkPoolOrder {
             status: OrderStatus::default(),
             created_at: 0,
             bump: 0,
+            time_in_force: TimeInForce::default(),
+            expires_at: 0,
         }
     }
 }
 
 impl DarkPoolOrder {
-    pub const LEN: usize = 8 + 32 + 1 + EncryptedAmount::LEN * 2 + 1 + 8 + 1;
+    /// Size before `time_in_force` and `expires_at` were appended
+    pub const LEGACY_LEN: usize = 8 + 32 + 1 + EncryptedAmount::LEN * 2 + 1 + 8 + 1;
+    pub const LEN: usize = Self::LEGACY_LEN + TimeInForce::LEN + 8;
+
+    /// GTC orders never expire; GTT orders lapse after `expires_at`
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        match self.time_in_force {
+            TimeInForce::GoodTilCancelled => false,
+            _ => current_time > self.expires_at,
+        }
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct RecurringSwapOrder {
+    pub owner: Pubkey,
+    pub vault: Pubkey,
+    pub route: SwapRoute,
+    pub encrypted_total: EncryptedAmount,
+    pub encrypted_slice: EncryptedAmount,
+    /// Owner ElGamal key the total and slice are encrypted under
+    pub elgamal_pubkey: [u8; 32],
+    pub min_out_commitment: [u8; 32],
+    pub min_slice_amount_out: u64,
+    pub max_slippage_bps: u16,
+    pub interval_seconds: i64,
+    pub next_execution_at: i64,
+    pub total_slices: u32,
+    pub executed_slices: u32,
+    pub status: RecurringSwapStatus,
+    pub created_at: i64,
+    pub last_executed_at: i64,
+    pub bump: u8,
+}
+
+impl RecurringSwapOrder {
+    pub const LEN: usize = 8 + 32 * 2 + SwapRoute::LEN + EncryptedAmount::LEN * 2 + 32 * 2 + 8 + 2
+        + 8 * 2 + 4 * 2 + 1 + 8 * 2 + 1;
+
+    pub fn is_due(&self, current_time: i64) -> bool {
+        self.status == RecurringSwapStatus::Active && current_time >= self.next_execution_at
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
+pub enum RecurringSwapStatus {
+    #[default]
+    None,
+    Active,
+    Paused,
+    Completed,
+    Cancelled,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
+pub enum SwapRoute {
+    #[default]
+    Starpay,
+    AnocoinDarkPool,
+    Split { starpay_weight_bps: u16 },
+}
+
+impl SwapRoute {
+    pub const LEN: usize = 1 + 2;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
@@ -312,6 +1218,26 @@ pub enum OrderStatus {
     PartiallyFilled,
     Filled,
     Cancelled,
+    Expired,
+}
+
+/// Time-in-force for dark pool limit orders
+/// IOC and FOK orders never rest on the book: they are matched in the instruction that places them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq)]
+pub enum TimeInForce {
+    #[default]
+    GoodTilCancelled,
+    GoodTilTime { expires_at: i64 },
+    ImmediateOrCancel,
+    FillOrKill,
+}
+
+impl TimeInForce {
+    pub const LEN: usize = 1 + 8;
+
+    pub fn is_immediate(&self) -> bool {
+        matches!(self, TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill)
+    }
 }
 
 #[event]
@@ -335,6 +1261,26 @@ pub struct PrivateSwapEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct DarkPoolOrderExpiredEvent {
+    pub maker: Pubkey,
+    pub cranker: Pubkey,
+    pub incentive_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecurringSwapExecutedEvent {
+    pub owner: Pubkey,
+    pub order: Pubkey,
+    pub executed_slices: u32,
+    pub total_slices: u32,
+    /// Encrypted total left after this slice
+    pub encrypted_remaining: EncryptedAmount,
+    pub next_execution_at: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct BridgeRequestEvent {
     pub user: Pubkey,
@@ -343,10 +1289,241 @@ pub struct BridgeRequestEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BridgeRefundedEvent {
+    pub user: Pubkey,
+    pub nonce: u64,
+    pub refunded_amount: u64,
+    pub cranker: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BridgeCompletedEvent {
+    pub user: Pubkey,
+    pub nonce: u64,
+    pub burned_amount: u64,
+    pub settler: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BridgeAcknowledgedEvent {
+    pub user: Pubkey,
+    pub nonce: u64,
+    pub relayer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InboundBridgeClaimedEvent {
+    pub user: Pubkey,
+    pub source_chain_id: u64,
+    pub nonce: u64,
+    pub commitment: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChainConfigUpdatedEvent {
+    pub chain_id: u64,
+    pub enabled: bool,
+    pub fee_bps_override: Option<u16>,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BridgeCircuitBreakerEvent {
+    pub paused: bool,
+    pub global_outflow_limit: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RelayerKeyRotatedEvent {
+    pub relayer: Pubkey,
+    pub encryption_pubkey: [u8; 32],
+    pub key_version: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianSetUpdatedEvent {
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdapterRegistryUpdatedEvent {
+    pub integration: Integration,
+    pub previous_program_id: Pubkey,
+    pub new_program_id: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ComplianceEvent {
     pub user: Pubkey,
     pub provider: Pubkey,
     pub risk_score: u8,
+    pub tier: ComplianceTier,
     pub expires_at: i64,
 }
+
+/// Why a provider or compliance officer pulled an attestation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum RevocationReason {
+    SanctionsMatch,
+    FraudSuspected,
+    DocumentsInvalid,
+    ProviderRequest,
+    Other,
+}
+
+#[event]
+pub struct ComplianceRevokedEvent {
+    pub user: Pubkey,
+    pub provider: Pubkey,
+    pub revoked_by: Pubkey,
+    pub reason: RevocationReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompliancePolicyUpdatedEvent {
+    pub period_secs: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScreeningRootUpdatedEvent {
+    pub root: [u8; 32],
+    pub version: u64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuditorKeyUpdatedEvent {
+    pub auditor: Pubkey,
+    pub elgamal_pubkey: [u8; 32],
+    pub key_version: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Carries the auditor-readable copy of a flow amount; only the auditor key can decrypt it
+#[event]
+pub struct AuditorDisclosureEvent {
+    pub user: Pubkey,
+    pub operation: ComplianceOperation,
+    pub key_version: u32,
+    pub auditor_ciphertext: [u8; ELGAMAL_CIPHERTEXT_LEN],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PositionDisclosedEvent {
+    pub user: Pubkey,
+    pub verifier: Pubkey,
+    pub kind: DisclosureKind,
+    pub threshold: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct JurisdictionRuleUpdatedEvent {
+    pub jurisdiction: [u8; 2],
+    pub removed: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ComplianceProvidersUpdatedEvent {
+    pub providers: Vec<Pubkey>,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleUpdatedEvent {
+    pub member: Pubkey,
+    pub role: Role,
+    pub granted: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeScheduledEvent {
+    pub id: u64,
+    pub change: ParameterChange,
+    pub proposer: Pubkey,
+    pub execute_after: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeCancelledEvent {
+    pub id: u64,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ParameterChangeExecutedEvent {
+    pub id: u64,
+    pub change: ParameterChange,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilUpdatedEvent {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub proposal_ttl_secs: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalEvent {
+    pub id: u64,
+    pub action: AdminAction,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub executed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferProposedEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferCancelledEvent {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminTransferAcceptedEvent {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ShadowForgeError;
+use crate::state::*;
+
+/// Reject a change that could never be applied, so it fails when scheduled rather than when executed
+pub fn validate_change(change: &ParameterChange) -> Result<()> {
+    match change {
+        ParameterChange::UpdateFees {
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            lending_fee_bps,
+            swap_fee_bps,
+            bridge_fee_bps,
+        } => {
+            require!(
+                [deposit_fee_bps, withdrawal_fee_bps, lending_fee_bps, swap_fee_bps, bridge_fee_bps]
+                    .iter()
+                    .all(|fee| fee.unwrap_or(0) <= MAX_BASIS_POINTS),
+                ShadowForgeError::InvalidAmount
+            );
+        }
+
+        ParameterChange::UpdateYieldRate { new_rate_bps } => {
+            require!(
+                *new_rate_bps <= 5000, // Max 50% APY
+                ShadowForgeError::InvalidAmount
+            );
+        }
+
+        ParameterChange::ToggleSdk { .. } => {}
+
+        ParameterChange::SetDelay { delay_secs } => {
+            require!(
+                (0..=MAX_TIMELOCK_DELAY_SECS).contains(delay_secs),
+                ShadowForgeError::InvalidAdminOperation
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Apply a change whose delay has elapsed
+pub fn apply_change(
+    vault_config: &mut VaultConfig,
+    timelock: &mut Timelock,
+    change: &ParameterChange,
+    current_time: i64,
+) -> Result<()> {
+    validate_change(change)?;
+
+    match *change {
+        ParameterChange::UpdateFees {
+            deposit_fee_bps,
+            withdrawal_fee_bps,
+            lending_fee_bps,
+            swap_fee_bps,
+            bridge_fee_bps,
+        } => {
+            set_fee(&mut vault_config.deposit_fee_bps, deposit_fee_bps, "Deposit");
+            set_fee(&mut vault_config.withdrawal_fee_bps, withdrawal_fee_bps, "Withdrawal");
+            set_fee(&mut vault_config.lending_fee_bps, lending_fee_bps, "Lending");
+            set_fee(&mut vault_config.swap_fee_bps, swap_fee_bps, "Swap");
+            set_fee(&mut vault_config.bridge_fee_bps, bridge_fee_bps, "Bridge");
+        }
+
+        ParameterChange::UpdateYieldRate { new_rate_bps } => {
+            let old_rate = vault_config.current_yield_bps;
+            vault_config.current_yield_bps = new_rate_bps;
+            vault_config.last_yield_update = current_time;
+
+            msg!(
+                "Admin: Updated yield rate from {} bps to {} bps",
+                old_rate,
+                new_rate_bps
+            );
+        }
+
+        ParameterChange::ToggleSdk {
+            arcium,
+            shadowwire,
+            anoncoin,
+            privacy_cash,
+            silentswap,
+            starpay,
+            range,
+        } => {
+            set_flag(&mut vault_config.arcium_enabled, arcium, "Arcium MXE");
+            set_flag(&mut vault_config.shadowwire_enabled, shadowwire, "ShadowWire");
+            set_flag(&mut vault_config.anoncoin_enabled, anoncoin, "Anoncoin");
+            set_flag(&mut vault_config.privacy_cash_enabled, privacy_cash, "Privacy Cash");
+            set_flag(&mut vault_config.silentswap_enabled, silentswap, "SilentSwap");
+            set_flag(&mut vault_config.starpay_enabled, starpay, "Starpay");
+            set_flag(&mut vault_config.range_enabled, range, "Range Compliance");
+        }
+
+        ParameterChange::SetDelay { delay_secs } => {
+            timelock.delay_secs = delay_secs;
+            msg!("Admin: Timelock delay updated to {} seconds", delay_secs);
+        }
+    }
+
+    Ok(())
+}
+
+fn set_fee(fee_bps: &mut u16, update: Option<u16>, name: &str) {
+    if let Some(fee) = update {
+        *fee_bps = fee;
+        msg!("Admin: {} fee updated to {} bps", name, fee);
+    }
+}
+
+fn set_flag(enabled: &mut bool, update: Option<bool>, name: &str) {
+    if let Some(value) = update {
+        *enabled = value;
+        msg!("Admin: {} = {}", name, value);
+    }
+}
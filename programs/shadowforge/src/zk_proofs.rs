@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use solana_curve25519::ristretto::{multiply_ristretto, subtract_ristretto, PodRistrettoPoint};
+use solana_curve25519::scalar::PodScalar;
+
+use crate::state::ELGAMAL_CIPHERTEXT_LEN;
+
+pub const ZK_ELGAMAL_PROOF_PROGRAM_ID: Pubkey = pubkey!("ZkE1Gama1Proof11111111111111111111111111111");
+
+/// `ProofInstruction` discriminators of the ZK ElGamal proof program
+pub const VERIFY_ZERO_CIPHERTEXT: u8 = 1;
+pub const VERIFY_CIPHERTEXT_CIPHERTEXT_EQUALITY: u8 = 2;
+pub const VERIFY_BATCHED_RANGE_PROOF_U64: u8 = 6;
+
+/// Context and proof byte lengths for the proofs this program consumes
+pub const ZERO_CIPHERTEXT_CONTEXT_LEN: usize = 32 + 64;
+pub const ZERO_CIPHERTEXT_PROOF_LEN: usize = 32 * 3;
+pub const CIPHERTEXT_EQUALITY_CONTEXT_LEN: usize = 32 + 32 + 64 * 2;
+pub const CIPHERTEXT_EQUALITY_PROOF_LEN: usize = 32 * 7;
+pub const BATCHED_RANGE_CONTEXT_LEN: usize = 32 * 8 + 8;
+pub const RANGE_PROOF_U64_LEN: usize = 672;
+
+/// Pedersen value base G, the compressed Ristretto basepoint
+const PEDERSEN_VALUE_BASE: PodRistrettoPoint = PodRistrettoPoint([
+    0xe2, 0xf2, 0xae, 0x0a, 0x6a, 0xbc, 0x4e, 0x71, 0xa8, 0x84, 0xa9, 0x61, 0xc5, 0x00, 0x51, 0x5f,
+    0x58, 0xe3, 0x0b, 0x6a, 0xa5, 0x82, 0xdd, 0x8d, 0xb6, 0xa6, 0x59, 0x45, 0xe0, 0x8d, 0x2d, 0x76,
+]);
+
+/// Pedersen commitment minus `amount` * G; a ciphertext of `amount` becomes one of zero
+pub fn commitment_minus_amount(commitment: &PodRistrettoPoint, amount: u64) -> Option<PodRistrettoPoint> {
+    let mut scalar = [0u8; 32];
+    scalar[..8].copy_from_slice(&amount.to_le_bytes());
+    let amount_point = multiply_ristretto(&PodScalar(scalar), &PEDERSEN_VALUE_BASE)?;
+
+    subtract_ristretto(commitment, &amount_point)
+}
+
+/// Contexts of every `discriminator` proof placed before the current instruction
+/// Only proofs carried inline in the instruction data count, not context-state accounts;
+/// the proof program aborts the transaction on an invalid proof, so a present context is a verified one
+pub fn proof_contexts(
+    instructions_sysvar: &AccountInfo,
+    discriminator: u8,
+    context_len: usize,
+    proof_len: usize,
+) -> Result<Vec<Vec<u8>>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let mut contexts = Vec::new();
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if instruction.program_id != ZK_ELGAMAL_PROOF_PROGRAM_ID ||
+            instruction.data.len() != 1 + context_len + proof_len ||
+            instruction.data[0] != discriminator
+        {
+            continue;
+        }
+
+        contexts.push(instruction.data[1..1 + context_len].to_vec());
+    }
+
+    Ok(contexts)
+}
+
+/// Whether a zero-ciphertext proof earlier in this transaction shows that `ciphertext` encrypts
+/// exactly `amount` under `pubkey`, i.e. that the ciphertext minus `amount` encrypts 0
+pub fn proves_ciphertext_amount(
+    instructions_sysvar: &AccountInfo,
+    pubkey: &[u8; 32],
+    ciphertext: &[u8; ELGAMAL_CIPHERTEXT_LEN],
+    amount: u64,
+) -> Result<bool> {
+    let mut commitment = [0u8; 32];
+    commitment.copy_from_slice(&ciphertext[..32]);
+    let Some(opened) = commitment_minus_amount(&PodRistrettoPoint(commitment), amount) else {
+        return Ok(false);
+    };
+
+    // Context layout: pubkey, ciphertext
+    Ok(proof_contexts(
+        instructions_sysvar,
+        VERIFY_ZERO_CIPHERTEXT,
+        ZERO_CIPHERTEXT_CONTEXT_LEN,
+        ZERO_CIPHERTEXT_PROOF_LEN,
+    )?
+    .iter()
+    .any(|context| {
+        context[..32] == *pubkey && context[32..64] == opened.0 && context[64..96] == ciphertext[32..]
+    }))
+}
@@ -7,6 +7,11 @@ import {
   Keypair,
   SystemProgram,
   Transaction,
+  TransactionInstruction,
+  AccountMeta,
+  Ed25519Program,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  ComputeBudgetProgram,
 } from "@solana/web3.js";
 import {
   TOKEN_2022_PROGRAM_ID,
@@ -19,6 +24,8 @@ import {
 import { expect } from "chai";
 import { BanksClient, Clock, ProgramTestContext } from "solana-bankrun";
 import path from "path";
+import { createHash, randomBytes } from "crypto";
+import { RistrettoPoint } from "@noble/curves/ed25519";
 
 const VAULT_CONFIG_SEED = Buffer.from("vault_config");
 const USER_POSITION_SEED = Buffer.from("user_position");
@@ -27,13 +34,45 @@ const COMPLIANCE_SEED = Buffer.from("compliance");
 const LENDING_POSITION_SEED = Buffer.from("lending_position");
 const BRIDGE_REQUEST_SEED = Buffer.from("bridge_request");
 const DARK_POOL_ORDER_SEED = Buffer.from("dark_pool_order");
+const RECURRING_SWAP_SEED = Buffer.from("recurring_swap");
+const ADAPTER_REGISTRY_SEED = Buffer.from("adapter_registry");
+const BRIDGE_ESCROW_SEED = Buffer.from("bridge_escrow");
+const GUARDIAN_SET_SEED = Buffer.from("guardian_set");
+const CONSUMED_MESSAGE_SEED = Buffer.from("consumed_message");
+const CHAIN_CONFIG_SEED = Buffer.from("chain_config");
+const BRIDGE_STATS_SEED = Buffer.from("bridge_stats");
+const RELAYER_KEY_SEED = Buffer.from("relayer_key");
+const COMPLIANCE_PROVIDERS_SEED = Buffer.from("compliance_providers");
+const COMPLIANCE_POLICY_SEED = Buffer.from("compliance_policy");
+const JURISDICTION_REGISTRY_SEED = Buffer.from("jurisdictions");
+const SCREENING_SEED = Buffer.from("screening");
+const AUDITOR_KEY_SEED = Buffer.from("auditor_key");
+const DISCLOSURE_SEED = Buffer.from("disclosure");
+const ROLE_REGISTRY_SEED = Buffer.from("roles");
+const TIMELOCK_SEED = Buffer.from("timelock");
+const QUEUED_CHANGE_SEED = Buffer.from("queued_change");
+const DEFAULT_TIMELOCK_DELAY_SECS = 2 * 86400;
+const COUNCIL_SEED = Buffer.from("council");
+const COUNCIL_PROPOSAL_SEED = Buffer.from("council_proposal");
+const ALL_OPERATIONS_PAUSED = 0b11111110;
+const BRIDGE_PAUSE_BIT = 1 << 5;
+const BRIDGE_OUTFLOW_WINDOW_SECS = 86400;
+const SHADOWFORGE_PROGRAM_ID = new PublicKey("Brejex6T6bCkvVko2qCSW7LGK93anqEWoiuYs5pfu9oA");
+const BRIDGE_ATTESTATION_DOMAIN = Buffer.from("shadowforge:bridge_attestation:v2");
+const COMPLIANCE_ATTESTATION_DOMAIN = Buffer.from("shadowforge:compliance_attestation:v2");
 
-// External program IDs (must match what's in the Rust code)
+// External program IDs, registered in the adapter registry during setup
 const PRIVACY_CASH_PROGRAM_ID = new PublicKey("PRVCxGv9EzBxvT4jDL1bKurkXMcJ6TPGHHCFnFfpump");
 const SILENTSWAP_PROGRAM_ID = new PublicKey("Si1entSwap111111111111111111111111111111111");
 const STARPAY_PROGRAM_ID = new PublicKey("STARpay111111111111111111111111111111111111");
 const ANONCOIN_PROGRAM_ID = new PublicKey("ANoNco1n11111111111111111111111111111111111");
 const RANGE_PROGRAM_ID = new PublicKey("RANGE11111111111111111111111111111111111111");
+const ZK_ELGAMAL_PROOF_PROGRAM_ID = new PublicKey("ZkE1Gama1Proof11111111111111111111111111111");
+
+// ZK ElGamal proof instruction discriminators and proof byte lengths the program looks for
+const ZERO_CIPHERTEXT_PROOF = { discriminator: 1, proofLen: 32 * 3 };
+const CIPHERTEXT_EQUALITY_PROOF = { discriminator: 2, proofLen: 32 * 7 };
+const BATCHED_RANGE_PROOF_U64 = { discriminator: 6, proofLen: 672 };
 
 function generateProof(length: number = 32): number[] {
   const proof = new Array(length).fill(0);
@@ -51,6 +90,218 @@ function generateNullifier(): number[] {
   return generateProof(32);
 }
 
+// Stand-in for an address sealed to the relayer's X25519 key; the program only checks shape and key version
+function generateEncryptedRecipient(keyVersion: number = 1) {
+  return {
+    keyVersion,
+    ephemeralPubkey: generateProof(32),
+    nonce: generateProof(24),
+    ciphertext: generateProof(32),
+    tag: generateProof(16),
+  };
+}
+
+// Mirrors guardians::recipient_commitment: sha256(owner || amount commitment)
+function recipientCommitment(owner: PublicKey, amountCommitment: number[]): number[] {
+  return Array.from(
+    createHash("sha256").update(owner.toBuffer()).update(Buffer.from(amountCommitment)).digest()
+  );
+}
+
+type BridgeAttestation = {
+  kind: { inboundTransfer?: {}; outboundCompletion?: {}; outboundFailure?: {} };
+  chainId: BN;
+  nonce: BN;
+  recipientCommitment: number[];
+  amount: BN;
+};
+
+// Mirrors BridgeAttestation::message, the bytes each guardian signs
+function bridgeAttestationMessage(
+  attestation: BridgeAttestation,
+  vault: PublicKey = PublicKey.findProgramAddressSync([VAULT_CONFIG_SEED], SHADOWFORGE_PROGRAM_ID)[0]
+): Buffer {
+  return Buffer.concat([
+    BRIDGE_ATTESTATION_DOMAIN,
+    SHADOWFORGE_PROGRAM_ID.toBuffer(),
+    vault.toBuffer(),
+    Buffer.from([attestation.kind.inboundTransfer ? 0 : attestation.kind.outboundCompletion ? 1 : 2]),
+    attestation.chainId.toArrayLike(Buffer, "le", 8),
+    attestation.nonce.toArrayLike(Buffer, "le", 8),
+    Buffer.from(attestation.recipientCommitment),
+    attestation.amount.toArrayLike(Buffer, "le", 8),
+  ]);
+}
+
+// One ed25519 program instruction per guardian, placed ahead of the bridge instruction
+function guardianSignatures(
+  signers: Keypair[],
+  attestation: BridgeAttestation,
+  vault?: PublicKey
+): TransactionInstruction[] {
+  const message = bridgeAttestationMessage(attestation, vault);
+  return signers.map((guardian) =>
+    Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: guardian.secretKey,
+      message,
+    })
+  );
+}
+
+type ProviderAttestation = {
+  provider: PublicKey;
+  issuedAt: BN;
+  riskScore: number;
+  tier: { basic?: {}; verified?: {}; institutional?: {} };
+  expiresAt: BN;
+  jurisdiction: number[];
+};
+
+// A valid compressed Ristretto point, for commitments and keys the program does arithmetic on
+function randomRistrettoPoint(): number[] {
+  const scalar = BigInt("0x" + randomBytes(16).toString("hex")) + BigInt(1);
+  return Array.from(RistrettoPoint.BASE.multiply(scalar).toRawBytes());
+}
+
+// Mirrors zk_proofs::commitment_minus_amount: commitment - amount * G
+function commitmentMinusAmount(commitment: number[], amount: BN): Buffer {
+  const point = RistrettoPoint.fromHex(Uint8Array.from(commitment));
+  return Buffer.from(point.subtract(RistrettoPoint.BASE.multiply(BigInt(amount.toString()))).toRawBytes());
+}
+
+type EncryptedAmount = { handle: number[]; commitment: number[] };
+
+// Mirrors disclose_position::balance_minus_threshold: principal + yield - threshold as a ciphertext
+function balanceMinusThreshold(principal: EncryptedAmount, accrued: EncryptedAmount, threshold: BN) {
+  const point = (bytes: number[]) => RistrettoPoint.fromHex(Uint8Array.from(bytes));
+  const balance = point(principal.commitment).add(point(accrued.commitment));
+  return {
+    commitment: commitmentMinusAmount(Array.from(balance.toRawBytes()), threshold),
+    handle: Buffer.from(point(principal.handle).add(point(accrued.handle)).toRawBytes()),
+  };
+}
+
+// Proof instruction carrying `proofContext`; the stand-in proof program accepts any proof bytes
+function zkProofInstruction(
+  proof: { discriminator: number; proofLen: number },
+  proofContext: Buffer
+): TransactionInstruction {
+  return new TransactionInstruction({
+    programId: ZK_ELGAMAL_PROOF_PROGRAM_ID,
+    keys: [],
+    data: Buffer.concat([Buffer.from([proof.discriminator]), proofContext, Buffer.alloc(proof.proofLen)]),
+  });
+}
+
+// Batched range proof context with `commitment` first, proven to 64 bits
+function rangeProofContext(commitment: number[]): Buffer {
+  const bitLengths = Buffer.alloc(8);
+  bitLengths[0] = 64;
+  return Buffer.concat([Buffer.from(commitment), Buffer.alloc(32 * 7), bitLengths]);
+}
+
+type AuditorDisclosure = {
+  elgamalPubkey: number[];
+  amountCiphertext: number[];
+  auditorCiphertext: number[];
+};
+
+// The three proofs auditor::verify_auditor_disclosure looks for: the user ciphertext opens to
+// `amount`, its commitment is in range, and the auditor ciphertext encrypts the same value
+// Zero-ciphertext proof that `ciphertext` encrypts exactly `amount` under `elgamalPubkey`
+function ciphertextAmountProof(elgamalPubkey: number[], ciphertext: number[], amount: BN): TransactionInstruction {
+  return zkProofInstruction(
+    ZERO_CIPHERTEXT_PROOF,
+    Buffer.concat([
+      Buffer.from(elgamalPubkey),
+      commitmentMinusAmount(ciphertext.slice(0, 32), amount),
+      Buffer.from(ciphertext.slice(32)),
+    ])
+  );
+}
+
+function auditorDisclosureProofs(
+  disclosure: AuditorDisclosure,
+  auditorPubkey: number[],
+  amount: BN
+): TransactionInstruction[] {
+  return [
+    ciphertextAmountProof(disclosure.elgamalPubkey, disclosure.amountCiphertext, amount),
+    zkProofInstruction(BATCHED_RANGE_PROOF_U64, rangeProofContext(disclosure.amountCiphertext.slice(0, 32))),
+    zkProofInstruction(
+      CIPHERTEXT_EQUALITY_PROOF,
+      Buffer.concat([
+        Buffer.from(disclosure.elgamalPubkey),
+        Buffer.from(auditorPubkey),
+        Buffer.from(disclosure.amountCiphertext),
+        Buffer.from(disclosure.auditorCiphertext),
+      ])
+    ),
+  ];
+}
+
+// Mirrors ProviderAttestation::message, the bytes a compliance provider signs for a user
+function providerAttestationMessage(user: PublicKey, attestation: ProviderAttestation): Buffer {
+  const tier = attestation.tier.basic ? 0 : attestation.tier.verified ? 1 : 2;
+  return Buffer.concat([
+    COMPLIANCE_ATTESTATION_DOMAIN,
+    user.toBuffer(),
+    attestation.issuedAt.toArrayLike(Buffer, "le", 8),
+    Buffer.from([attestation.riskScore, tier]),
+    attestation.expiresAt.toArrayLike(Buffer, "le", 8),
+    Buffer.from(attestation.jurisdiction),
+  ]);
+}
+
+function providerSignature(
+  signer: Keypair,
+  user: PublicKey,
+  attestation: ProviderAttestation
+): TransactionInstruction {
+  return Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: signer.secretKey,
+    message: providerAttestationMessage(user, attestation),
+  });
+}
+
+// Sorted denylist tree mirroring compliance::screen_address: leaves are sha256(0x00 || key),
+// nodes sha256(0x01 || left || right), bracketed by the all-zero and all-0xff keys
+function buildScreeningTree(denylist: PublicKey[]) {
+  const keys = [
+    Buffer.alloc(32, 0),
+    ...denylist.map((k) => k.toBuffer()).sort(Buffer.compare),
+    Buffer.alloc(32, 0xff),
+  ];
+  while (keys.length & (keys.length - 1)) keys.push(Buffer.alloc(32, 0xff));
+
+  const sha = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+  const levels: Buffer[][] = [keys.map((k) => sha(Buffer.from([0]), k))];
+  while (levels[levels.length - 1].length > 1) {
+    const prev = levels[levels.length - 1];
+    const next: Buffer[] = [];
+    for (let i = 0; i < prev.length; i += 2) next.push(sha(Buffer.from([1]), prev[i], prev[i + 1]));
+    levels.push(next);
+  }
+
+  const siblings = (index: number) =>
+    levels.slice(0, -1).map((level, depth) => Array.from(level[(index >> depth) ^ 1]));
+
+  return {
+    root: Array.from(levels[levels.length - 1][0]),
+    nonMembershipProof(address: PublicKey) {
+      const target = address.toBuffer();
+      const low = keys.findIndex((k, i) => Buffer.compare(k, target) < 0 && Buffer.compare(target, keys[i + 1]) < 0);
+      return {
+        low: new PublicKey(keys[low]),
+        high: new PublicKey(keys[low + 1]),
+        lowIndex: low,
+        lowSiblings: siblings(low),
+        highSiblings: siblings(low + 1),
+      };
+    },
+  };
+}
+
 // Bankrun doesn't provide connection.getMinimumBalanceForRentExemption, so we hardcode values.
 // Token-2022 requires slightly more lamports than regular SPL Token for rent exemption.
 const MINT_RENT_EXEMPT_LAMPORTS = 1_500_000;
@@ -173,17 +424,99 @@ describe("shadowforge", () => {
   let treasury: Keypair;
   let shieldedMint: PublicKey;
   let secondaryMint: PublicKey;
+  let destVaultPda: PublicKey;
+  let venueAccounts: AccountMeta[];
   let vaultConfigPda: PublicKey;
   let shieldedVaultAta: PublicKey;
+  let adapterRegistryPda: PublicKey;
+  let guardianSetPda: PublicKey;
+  let bridgeStatsPda: PublicKey;
+  let relayerKeysPda: PublicKey;
+  let relayer: Keypair;
+  let complianceProvidersPda: PublicKey;
+  let guardians: Keypair[];
+  let complianceProvider: Keypair;
+  let complianceOfficer: Keypair;
+  let roleRegistryPda: PublicKey;
+  let screeningPda: PublicKey;
+  let jurisdictionsPda: PublicKey;
+  let auditorKeyPda: PublicKey;
+  let auditor: Keypair;
+  let auditorElgamalPubkey: number[];
+  let screeningTree: ReturnType<typeof buildScreeningTree>;
+
+  const chainConfigPda = (chainId: number) =>
+    PublicKey.findProgramAddressSync(
+      [CHAIN_CONFIG_SEED, vaultConfigPda.toBuffer(), new BN(chainId).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  const timelockPda = () =>
+    PublicKey.findProgramAddressSync([TIMELOCK_SEED, vaultConfigPda.toBuffer()], program.programId)[0];
+  const councilPda = () =>
+    PublicKey.findProgramAddressSync([COUNCIL_SEED, vaultConfigPda.toBuffer()], program.programId)[0];
+  const queuedChangePda = (changeId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [QUEUED_CHANGE_SEED, vaultConfigPda.toBuffer(), changeId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+  const advanceClock = async (secs: number) => {
+    const clock = await banksClient.getClock();
+    context.setClock(
+      new Clock(
+        clock.slot + BigInt(100),
+        clock.epochStartTimestamp,
+        clock.epoch,
+        clock.leaderScheduleEpoch,
+        clock.unixTimestamp + BigInt(secs)
+      )
+    );
+  };
+  const scheduleChange = async (change: object) => {
+    const timelock = await program.account.timelock.fetchNullable(timelockPda());
+    const changeId = timelock ? timelock.nextChangeId : new BN(0);
+    await program.methods
+      .scheduleChange({ changeId, change })
+      .accountsStrict({
+        authority: admin.publicKey,
+        vaultConfig: vaultConfigPda,
+        roleRegistry: null,
+        council: councilPda(),
+        timelock: timelockPda(),
+        queuedChange: queuedChangePda(changeId),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+    return changeId;
+  };
+  const executeChange = (changeId: BN) =>
+    program.methods
+      .executeChange()
+      .accountsStrict({
+        executor: user.publicKey,
+        vaultConfig: vaultConfigPda,
+        timelock: timelockPda(),
+        queuedChange: queuedChangePda(changeId),
+        proposer: admin.publicKey,
+      })
+      .signers([user])
+      .rpc();
   let userPositionPda: PublicKey;
   let userTokenAccount: PublicKey;
   let adminTokenAccount: PublicKey;
 
   before(async () => {
-    // startAnchor loads programs from target/deploy based on Anchor.toml
+    // startAnchor loads programs from target/deploy based on Anchor.toml.
+    // The mock venue binary stands in for both Starpay and Anoncoin so swap CPIs have a target,
+    // and for the ZK ElGamal proof program so proof contexts can be placed in a transaction.
+    process.env.SBF_OUT_DIR ??= path.resolve(__dirname, "../target/deploy");
     context = await startAnchor(
       path.resolve(__dirname, ".."),
-      [],
+      [
+        { name: "mock_venue", programId: STARPAY_PROGRAM_ID },
+        { name: "mock_venue", programId: ANONCOIN_PROGRAM_ID },
+        { name: "mock_venue", programId: ZK_ELGAMAL_PROOF_PROGRAM_ID },
+      ],
       []
     );
     provider = new BankrunProvider(context);
@@ -225,6 +558,55 @@ describe("shadowforge", () => {
       program.programId
     );
 
+    [adapterRegistryPda] = PublicKey.findProgramAddressSync(
+      [ADAPTER_REGISTRY_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+
+    [guardianSetPda] = PublicKey.findProgramAddressSync(
+      [GUARDIAN_SET_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+    guardians = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+    [bridgeStatsPda] = PublicKey.findProgramAddressSync(
+      [BRIDGE_STATS_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+
+    [relayerKeysPda] = PublicKey.findProgramAddressSync(
+      [RELAYER_KEY_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+    relayer = Keypair.generate();
+
+    [complianceProvidersPda] = PublicKey.findProgramAddressSync(
+      [COMPLIANCE_PROVIDERS_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+    complianceProvider = Keypair.generate();
+    complianceOfficer = Keypair.generate();
+    [roleRegistryPda] = PublicKey.findProgramAddressSync(
+      [ROLE_REGISTRY_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+
+    [screeningPda] = PublicKey.findProgramAddressSync(
+      [SCREENING_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+    [jurisdictionsPda] = PublicKey.findProgramAddressSync(
+      [JURISDICTION_REGISTRY_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+    [auditorKeyPda] = PublicKey.findProgramAddressSync(
+      [AUDITOR_KEY_SEED, vaultConfigPda.toBuffer()],
+      program.programId
+    );
+    auditor = Keypair.generate();
+    auditorElgamalPubkey = randomRistrettoPoint();
+    screeningTree = buildScreeningTree([Keypair.generate().publicKey, Keypair.generate().publicKey]);
+
     [userPositionPda] = PublicKey.findProgramAddressSync(
       [USER_POSITION_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
       program.programId
@@ -307,9 +689,224 @@ describe("shadowforge", () => {
       expect(vaultConfig.silentswapEnabled).to.be.true;
       expect(vaultConfig.starpayEnabled).to.be.true;
       expect(vaultConfig.rangeEnabled).to.be.true;
-      expect(vaultConfig.isPaused).to.be.false;
+      expect(vaultConfig.pausedOperations).to.equal(0);
       expect(vaultConfig.totalPositions.toNumber()).to.equal(0);
     });
+
+    it("registers integration program IDs in the adapter registry", async () => {
+      await program.methods
+        .updateAdapterRegistry({
+          updates: [
+            { integration: { privacyCash: {} }, programId: PRIVACY_CASH_PROGRAM_ID },
+            { integration: { silentSwap: {} }, programId: SILENTSWAP_PROGRAM_ID },
+            { integration: { starpay: {} }, programId: STARPAY_PROGRAM_ID },
+            { integration: { anoncoin: {} }, programId: ANONCOIN_PROGRAM_ID },
+            { integration: { range: {} }, programId: RANGE_PROGRAM_ID },
+          ],
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          adapterRegistry: adapterRegistryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const registry = await program.account.adapterRegistry.fetch(adapterRegistryPda);
+      expect(registry.starpayProgram.toBase58()).to.equal(STARPAY_PROGRAM_ID.toBase58());
+      expect(registry.rangeProgram.toBase58()).to.equal(RANGE_PROGRAM_ID.toBase58());
+      expect(registry.arciumProgram.toBase58()).to.equal(PublicKey.default.toBase58());
+    });
+
+    it("configures bridge destination chains", async () => {
+      for (const chainId of [1, 137]) {
+        await program.methods
+          .configureChain({
+            chainId: new BN(chainId),
+            enabled: true,
+            feeBpsOverride: null,
+            minAmount: new BN(1_000_000),
+            maxAmount: new BN(10_000_000_000),
+            requiredConfirmations: chainId === 1 ? 12 : 128,
+            addressFormat: { evm: {} },
+          timeoutSecs: new BN(3600),
+          outflowLimit: new BN(0),
+            timeoutSecs: new BN(3600),
+          outflowLimit: new BN(0),
+            outflowLimit: new BN(0),
+          })
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            chainConfig: chainConfigPda(chainId),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      }
+
+      const ethereum = await program.account.chainConfig.fetch(chainConfigPda(1));
+      expect(ethereum.enabled).to.be.true;
+      expect(ethereum.requiredConfirmations).to.equal(12);
+      expect(ethereum.feeBpsOverride).to.be.null;
+    });
+
+    it("configures a 2-of-3 bridge guardian set", async () => {
+      await program.methods
+        .updateGuardianSet({
+          guardians: guardians.map((g) => g.publicKey),
+          threshold: 2,
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          guardianSet: guardianSetPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const guardianSet = await program.account.guardianSet.fetch(guardianSetPda);
+      expect(guardianSet.guardians.length).to.equal(3);
+      expect(guardianSet.threshold).to.equal(2);
+    });
+
+    it("publishes the relayer encryption key", async () => {
+      await program.methods
+        .setRelayerKey({
+          relayer: relayer.publicKey,
+          encryptionPubkey: generateProof(32),
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          relayerKeys: relayerKeysPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const relayerKeys = await program.account.relayerKeyRegistry.fetch(relayerKeysPda);
+      expect(relayerKeys.keyVersion).to.equal(1);
+      expect(relayerKeys.relayer.toBase58()).to.equal(relayer.publicKey.toBase58());
+    });
+
+    it("approves a compliance provider and grants the compliance officer role", async () => {
+      await program.methods
+        .updateComplianceProviders({
+          providers: [complianceProvider.publicKey],
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          complianceProviders: complianceProvidersPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const registry = await program.account.complianceProviderRegistry.fetch(complianceProvidersPda);
+      expect(registry.providers.map((p) => p.toBase58())).to.deep.equal([
+        complianceProvider.publicKey.toBase58(),
+      ]);
+
+      await program.methods
+        .manageRoles({
+          action: { grant: { member: complianceOfficer.publicKey, role: { complianceOfficer: {} } } },
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          roleRegistry: roleRegistryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const roles = await program.account.roleRegistry.fetch(roleRegistryPda);
+      expect(roles.members.map((m) => m.member.toBase58())).to.include(complianceOfficer.publicKey.toBase58());
+    });
+
+    it("publishes the sanctions screening root", async () => {
+      await program.methods
+        .updateScreeningRoot({
+          root: screeningTree.root,
+          maxStalenessSecs: new BN(30 * 86400),
+        })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          complianceProviders: complianceProvidersPda,
+          screening: screeningPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const screening = await program.account.screeningRoot.fetch(screeningPda);
+      expect(screening.version.toNumber()).to.equal(1);
+      expect(screening.root).to.deep.equal(screeningTree.root);
+    });
+
+    it("restricts a sanctioned jurisdiction to no operations", async () => {
+      const unlimited = { perTransaction: new BN(0), perPeriod: new BN(0) };
+      await program.methods
+        .setJurisdictionRule({
+          action: {
+            upsert: {
+              rule: {
+                jurisdiction: Array.from(Buffer.from("KP")),
+                allowedOperations: 0,
+                limits: { deposit: unlimited, withdraw: unlimited, swap: unlimited, bridge: unlimited },
+                blockedChains: [],
+              },
+            },
+          },
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          jurisdictions: jurisdictionsPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const registry = await program.account.jurisdictionRegistry.fetch(jurisdictionsPda);
+      expect(registry.rules.length).to.equal(1);
+      expect(Buffer.from(registry.rules[0].jurisdiction).toString()).to.equal("KP");
+      expect(registry.rules[0].allowedOperations).to.equal(0);
+    });
+
+    it("sets the vault-wide bridge outflow limit", async () => {
+      await program.methods
+        .bridgeCircuitBreaker({
+          action: { setGlobalLimit: { limit: new BN(50_000_000_000) } },
+        })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          guardianSet: null,
+          bridgeStats: bridgeStatsPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const bridgeStats = await program.account.bridgeStats.fetch(bridgeStatsPda);
+      expect(bridgeStats.outflow.limit.toNumber()).to.equal(50_000_000_000);
+      expect(bridgeStats.bridgePaused).to.be.false;
+    });
   });
 
   describe("2. Private Deposit", () => {
@@ -325,6 +922,8 @@ describe("shadowforge", () => {
           amount: depositAmount,
           amountCommitment: amountCommitment,
           blindingFactor: blindingFactor,
+          screeningProof: null,
+          auditorDisclosure: null,
         })
         .accountsStrict({
           user: user.publicKey,
@@ -334,6 +933,11 @@ describe("shadowforge", () => {
           shieldedVaultAta: shieldedVaultAta,
           shieldedMint: shieldedMint,
           complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          screening: null,
+          auditorKey: auditorKeyPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -344,7 +948,7 @@ describe("shadowforge", () => {
       expect(userPosition.owner.toBase58()).to.equal(user.publicKey.toBase58());
       expect(userPosition.depositCount).to.equal(1);
       expect(userPosition.hasActiveLoan).to.be.false;
-      expect(userPosition.hasPendingBridge).to.be.false;
+      expect(userPosition.pendingBridgeCount).to.equal(0);
 
       const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
       expect(Number(userBalanceBefore) - Number(userBalanceAfter)).to.equal(
@@ -383,11 +987,15 @@ describe("shadowforge", () => {
         .accountsStrict({
           user: user.publicKey,
           vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
           lendingPosition: lendingPositionPda,
           shieldedVaultAta: shieldedVaultAta,
           shieldedMint: shieldedMint,
           privacyCashProgram: PRIVACY_CASH_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -418,11 +1026,15 @@ describe("shadowforge", () => {
         .accountsStrict({
           user: user.publicKey,
           vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
           lendingPosition: lendingPositionPda,
           shieldedVaultAta: shieldedVaultAta,
           shieldedMint: shieldedMint,
           privacyCashProgram: PRIVACY_CASH_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -440,17 +1052,31 @@ describe("shadowforge", () => {
   describe("4. Private Swap (Starpay/Anoncoin)", () => {
     let darkPoolOrderPda: PublicKey;
 
-    before(() => {
+    before(async () => {
       [darkPoolOrderPda] = PublicKey.findProgramAddressSync(
         [DARK_POOL_ORDER_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
         program.programId
       );
+      [destVaultPda] = PublicKey.findProgramAddressSync(
+        [SHIELDED_VAULT_SEED, secondaryMint.toBuffer()],
+        program.programId
+      );
+
+      // The mock venue collects swap input in one reserve and pays the output from another
+      const sourceReserve = await createTokenAccountWithBankrun(context, admin, shieldedMint, treasury.publicKey);
+      const destReserve = await createTokenAccountWithBankrun(context, admin, secondaryMint, vaultConfigPda);
+      await mintToWithBankrun(context, admin, secondaryMint, destReserve, admin, BigInt(10_000_000_000));
+      venueAccounts = [sourceReserve, destReserve].map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+
+      // Swap input leaves the shielded vault; top it up so later withdrawals stay covered
+      await mintToWithBankrun(context, admin, shieldedMint, shieldedVaultAta, admin, BigInt(5_000_000_000));
     });
 
     it("executes a private swap via Starpay route", async () => {
       const amountInCommitment = generateCommitment();
       const minOutCommitment = generateCommitment();
       const swapProof = generateProof(32);
+      const sourceBalanceBefore = await getTokenBalanceWithBankrun(context, shieldedVaultAta);
 
       await program.methods
         .privateSwap({
@@ -458,31 +1084,135 @@ describe("shadowforge", () => {
           route: { starpay: {} },
           amountInCommitment: amountInCommitment,
           minOutCommitment: minOutCommitment,
+          amountIn: new BN(1_000_000_000),
+          minAmountOut: new BN(990_000_000),
           limitPriceCommitment: null,
           side: { buy: {} },
           swapProof: swapProof,
-          maxSlippageBps: 100,
         })
         .accountsStrict({
           user: user.publicKey,
           vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
           darkPoolOrder: darkPoolOrderPda,
           sourceMint: shieldedMint,
           destMint: secondaryMint,
           sourceVault: shieldedVaultAta,
+          destVault: destVaultPda,
           starpayProgram: STARPAY_PROGRAM_ID,
           anoncoinProgram: ANONCOIN_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(venueAccounts)
         .signers([user])
         .rpc();
 
+      // The fill is what the destination vault received: 1 token less the venue's 30 bps
+      const sourceBalanceAfter = await getTokenBalanceWithBankrun(context, shieldedVaultAta);
+      const destBalance = await getTokenBalanceWithBankrun(context, destVaultPda);
+      expect(Number(sourceBalanceBefore - sourceBalanceAfter)).to.equal(1_000_000_000);
+      expect(Number(destBalance)).to.equal(997_000_000);
+
       const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
       expect(userPosition.actionCount).to.be.greaterThan(0);
     });
 
+    it("splits a swap across Starpay and Anoncoin and enforces the combined minimum", async () => {
+      const buildSplitSwap = (minAmountOut: BN) =>
+        program.methods
+          .privateSwap({
+            action: { execute: {} },
+            route: { split: { starpayWeightBps: 7000 } },
+            amountInCommitment: generateCommitment(),
+            minOutCommitment: generateCommitment(),
+            amountIn: new BN(1_000_000_000),
+            minAmountOut,
+            limitPriceCommitment: null,
+            side: { buy: {} },
+            swapProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            darkPoolOrder: darkPoolOrderPda,
+            sourceMint: shieldedMint,
+            destMint: secondaryMint,
+            sourceVault: shieldedVaultAta,
+            destVault: destVaultPda,
+            starpayProgram: STARPAY_PROGRAM_ID,
+            anoncoinProgram: ANONCOIN_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(venueAccounts)
+          .signers([user]);
+
+      // Each mock venue keeps 30 bps, so a full 1:1 fill is unreachable
+      try {
+        await buildSplitSwap(new BN(1_000_000_000)).rpc();
+        expect.fail("split swap filled below the combined minimum");
+      } catch (err) {
+        expect(String(err)).to.include("SlippageExceeded");
+      }
+
+      const actionCountBefore = (await program.account.userEncryptedPosition.fetch(userPositionPda)).actionCount;
+      await buildSplitSwap(new BN(990_000_000)).rpc();
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.actionCount).to.equal(actionCountBefore + 1);
+    });
+
+    it("rejects a venue program that does not match the adapter registry", async () => {
+      try {
+        await program.methods
+          .privateSwap({
+            action: { execute: {} },
+            route: { starpay: {} },
+            amountInCommitment: generateCommitment(),
+            minOutCommitment: generateCommitment(),
+            amountIn: new BN(1_000_000_000),
+            minAmountOut: new BN(0),
+            limitPriceCommitment: null,
+            side: { buy: {} },
+            swapProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            darkPoolOrder: darkPoolOrderPda,
+            sourceMint: shieldedMint,
+            destMint: secondaryMint,
+            sourceVault: shieldedVaultAta,
+            destVault: destVaultPda,
+            starpayProgram: ANONCOIN_PROGRAM_ID,
+            anoncoinProgram: ANONCOIN_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(venueAccounts)
+          .signers([user])
+          .rpc();
+        expect.fail("swap accepted an unregistered Starpay program");
+      } catch (err) {
+        expect(String(err)).to.include("InvalidAdapterProgram");
+      }
+    });
+
     it("places a dark pool limit order", async () => {
       const amountInCommitment = generateCommitment();
       const minOutCommitment = generateCommitment();
@@ -491,25 +1221,31 @@ describe("shadowforge", () => {
 
       await program.methods
         .privateSwap({
-          action: { placeLimitOrder: {} },
+          action: { placeLimitOrder: { timeInForce: { goodTilCancelled: {} } } },
           route: { anocoinDarkPool: {} },
           amountInCommitment: amountInCommitment,
           minOutCommitment: minOutCommitment,
+          amountIn: new BN(0),
+          minAmountOut: new BN(0),
           limitPriceCommitment: limitPriceCommitment,
           side: { sell: {} },
           swapProof: swapProof,
-          maxSlippageBps: 50,
         })
         .accountsStrict({
           user: user.publicKey,
           vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
           darkPoolOrder: darkPoolOrderPda,
           sourceMint: shieldedMint,
           destMint: secondaryMint,
           sourceVault: shieldedVaultAta,
+          destVault: destVaultPda,
           starpayProgram: STARPAY_PROGRAM_ID,
           anoncoinProgram: ANONCOIN_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -530,21 +1266,27 @@ describe("shadowforge", () => {
           route: { anocoinDarkPool: {} },
           amountInCommitment: generateCommitment(),
           minOutCommitment: generateCommitment(),
+          amountIn: new BN(0),
+          minAmountOut: new BN(0),
           limitPriceCommitment: null,
           side: { sell: {} },
           swapProof: swapProof,
-          maxSlippageBps: 0,
         })
         .accountsStrict({
           user: user.publicKey,
           vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
           darkPoolOrder: darkPoolOrderPda,
           sourceMint: shieldedMint,
           destMint: secondaryMint,
           sourceVault: shieldedVaultAta,
+          destVault: destVaultPda,
           starpayProgram: STARPAY_PROGRAM_ID,
           anoncoinProgram: ANONCOIN_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -554,336 +1296,2115 @@ describe("shadowforge", () => {
       const darkPoolOrder = await program.account.darkPoolOrder.fetch(darkPoolOrderPda);
       expect(darkPoolOrder.status).to.deep.equal({ cancelled: {} });
     });
-  });
 
-  describe("5. Private Bridge (SilentSwap)", () => {
-    let bridgeRequestPda: PublicKey;
+    it("fills IOC and FOK orders in the placing instruction without resting them", async () => {
+      const placeImmediate = (timeInForce: object, minAmountOut: BN) =>
+        program.methods
+          .privateSwap({
+            action: { placeLimitOrder: { timeInForce } },
+            route: { anocoinDarkPool: {} },
+            amountInCommitment: generateCommitment(),
+            minOutCommitment: generateCommitment(),
+            amountIn: new BN(1_000_000_000),
+            minAmountOut,
+            limitPriceCommitment: generateCommitment(),
+            side: { sell: {} },
+            swapProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            darkPoolOrder: darkPoolOrderPda,
+            sourceMint: shieldedMint,
+            destMint: secondaryMint,
+            sourceVault: shieldedVaultAta,
+            destVault: destVaultPda,
+            starpayProgram: STARPAY_PROGRAM_ID,
+            anoncoinProgram: ANONCOIN_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(venueAccounts)
+          .signers([user])
+          .rpc();
 
-    before(async () => {
-      [bridgeRequestPda] = PublicKey.findProgramAddressSync(
-        [BRIDGE_REQUEST_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
-        program.programId
-      );
-      const clock = await banksClient.getClock();
-      context.setClock(new Clock(clock.slot + BigInt(100), clock.epochStartTimestamp, clock.epoch, clock.leaderScheduleEpoch, clock.unixTimestamp));
+      // The venue keeps 30 bps, so a 1:1 limit cannot be met and FOK reverts
+      const destBefore = await getTokenBalanceWithBankrun(context, destVaultPda);
+      try {
+        await placeImmediate({ fillOrKill: {} }, new BN(1_000_000_000));
+        expect.fail("Should have killed a fill-or-kill order below its limit");
+      } catch (err) {
+        expect(String(err)).to.include("SlippageExceeded");
+      }
+      expect(await getTokenBalanceWithBankrun(context, destVaultPda)).to.equal(destBefore);
+
+      await placeImmediate({ fillOrKill: {} }, new BN(990_000_000));
+      await placeImmediate({ immediateOrCancel: {} }, new BN(990_000_000));
+
+      const destAfter = await getTokenBalanceWithBankrun(context, destVaultPda);
+      expect(Number(destAfter - destBefore)).to.equal(2 * 997_000_000);
+
+      // Neither order touched the book
+      const darkPoolOrder = await program.account.darkPoolOrder.fetch(darkPoolOrderPda);
+      expect(darkPoolOrder.status).to.deep.equal({ cancelled: {} });
     });
 
-    it("initiates outbound bridge to Ethereum", async () => {
-      const amountCommitment = generateCommitment();
-      const bridgeProof = generateProof(32);
+    it("migrates a dark pool order placed before time-in-force", async () => {
+      const current = await banksClient.getAccount(darkPoolOrderPda);
+      const before = await program.account.darkPoolOrder.fetch(darkPoolOrderPda);
 
-      await program.methods
-        .privateBridge({
-          action: { initiateOutbound: {} },
-          destChain: { ethereum: {} },
-          amountCommitment: amountCommitment,
-          bridgeProof: bridgeProof,
-          inboundProof: null,
-        })
-        .accountsStrict({
-          user: user.publicKey,
-          vaultConfig: vaultConfigPda,
-          userPosition: userPositionPda,
-          bridgeRequest: bridgeRequestPda,
-          shieldedMint: shieldedMint,
-          shieldedVaultAta: shieldedVaultAta,
-          silentswapProgram: SILENTSWAP_PROGRAM_ID,
-          token2022Program: TOKEN_2022_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([user])
-        .rpc();
+      const migrate = () =>
+        program.methods
+          .migrateDarkPoolOrder()
+          .accountsStrict({
+            maker: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            darkPoolOrder: darkPoolOrderPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user]);
 
-      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda);
-      expect(bridgeRequest.user.toBase58()).to.equal(user.publicKey.toBase58());
-      expect(bridgeRequest.destChainId.toNumber()).to.equal(1);
-      expect(bridgeRequest.status).to.deep.equal({ pending: {} });
+      // The legacy layout ends at `bump`, before the time-in-force and expiry
+      const legacyLen = current.data.length - 9 - 8;
+      context.setAccount(darkPoolOrderPda, { ...current, data: current.data.slice(0, legacyLen) });
 
-      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
-      expect(userPosition.hasPendingBridge).to.be.true;
+      await migrate().rpc();
+
+      const migrated = await program.account.darkPoolOrder.fetch(darkPoolOrderPda);
+      expect((await banksClient.getAccount(darkPoolOrderPda)).data.length).to.equal(current.data.length);
+      expect(migrated.maker.toBase58()).to.equal(before.maker.toBase58());
+      expect(migrated.status).to.deep.equal(before.status);
+      expect(migrated.createdAt.toString()).to.equal(before.createdAt.toString());
+      expect(migrated.timeInForce).to.deep.equal({ goodTilCancelled: {} });
+      expect(migrated.expiresAt.toNumber()).to.equal(0);
+
+      try {
+        await migrate()
+          .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+          .rpc();
+        expect.fail("order migrated twice");
+      } catch (err) {
+        expect(String(err)).to.include("AccountAlreadyMigrated");
+      }
+
+      context.setAccount(darkPoolOrderPda, current);
     });
 
-    it("verifies bridge completion", async () => {
-      const bridgeProof = generateProof(32);
+    it("expires a lapsed good-til-time order via the permissionless crank", async () => {
+      const clock = await banksClient.getClock();
+      const expiresAt = new BN((clock.unixTimestamp + BigInt(60)).toString());
 
       await program.methods
-        .privateBridge({
-          action: { verifyCompletion: {} },
-          destChain: { ethereum: {} },
-          amountCommitment: generateCommitment(),
-          bridgeProof: bridgeProof,
-          inboundProof: null,
+        .privateSwap({
+          action: { placeLimitOrder: { timeInForce: { goodTilTime: { expiresAt } } } },
+          route: { anocoinDarkPool: {} },
+          amountInCommitment: generateCommitment(),
+          minOutCommitment: generateCommitment(),
+          amountIn: new BN(0),
+          minAmountOut: new BN(0),
+          limitPriceCommitment: generateCommitment(),
+          side: { buy: {} },
+          swapProof: generateProof(32),
         })
         .accountsStrict({
           user: user.publicKey,
           vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
-          bridgeRequest: bridgeRequestPda,
-          shieldedMint: shieldedMint,
-          shieldedVaultAta: shieldedVaultAta,
-          silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          darkPoolOrder: darkPoolOrderPda,
+          sourceMint: shieldedMint,
+          destMint: secondaryMint,
+          sourceVault: shieldedVaultAta,
+          destVault: destVaultPda,
+          starpayProgram: STARPAY_PROGRAM_ID,
+          anoncoinProgram: ANONCOIN_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([user])
         .rpc();
 
-      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda);
-      expect(bridgeRequest.status).to.deep.equal({ completed: {} });
+      const order = await program.account.darkPoolOrder.fetch(darkPoolOrderPda);
+      expect(order.expiresAt.toString()).to.equal(expiresAt.toString());
 
-      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
-      expect(userPosition.hasPendingBridge).to.be.false;
+      context.setClock(
+        new Clock(
+          clock.slot + BigInt(100),
+          clock.epochStartTimestamp,
+          clock.epoch,
+          clock.leaderScheduleEpoch,
+          clock.unixTimestamp + BigInt(120)
+        )
+      );
+
+      const cranker = Keypair.generate();
+      const fundTx = new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: admin.publicKey,
+          toPubkey: cranker.publicKey,
+          lamports: 1_000_000_000,
+        })
+      );
+      fundTx.recentBlockhash = context.lastBlockhash;
+      fundTx.feePayer = admin.publicKey;
+      fundTx.sign(admin);
+      await banksClient.processTransaction(fundTx);
+
+      const crankerBalanceBefore = await banksClient.getBalance(cranker.publicKey);
+
+      await program.methods
+        .expireOrder()
+        .accountsStrict({
+          cranker: cranker.publicKey,
+          vaultConfig: vaultConfigPda,
+          maker: user.publicKey,
+          makerPosition: userPositionPda,
+          darkPoolOrder: darkPoolOrderPda,
+        })
+        .signers([cranker])
+        .rpc();
+
+      const closedOrder = await banksClient.getAccount(darkPoolOrderPda);
+      expect(closedOrder).to.be.null;
+
+      const crankerBalanceAfter = await banksClient.getBalance(cranker.publicKey);
+      expect(Number(crankerBalanceAfter)).to.be.greaterThan(Number(crankerBalanceBefore));
     });
   });
 
-  describe("6. Apply Compliance (Range Protocol)", () => {
-    let complianceAttestationPda: PublicKey;
+  describe("5. Private Bridge (SilentSwap)", () => {
+    const bridgeRequestPda = (nonce: number) =>
+      PublicKey.findProgramAddressSync(
+        [
+          BRIDGE_REQUEST_SEED,
+          vaultConfigPda.toBuffer(),
+          user.publicKey.toBuffer(),
+          new BN(nonce).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      )[0];
+    const acknowledgeBridge = (nonce: number, signer: Keypair = relayer) =>
+      program.methods
+        .acknowledgeBridgeRequest()
+        .accountsStrict({
+          relayer: signer.publicKey,
+          vaultConfig: vaultConfigPda,
+          relayerKeys: relayerKeysPda,
+          owner: user.publicKey,
+          bridgeRequest: bridgeRequestPda(nonce),
+        })
+        .signers([signer]);
+    const settleBridge = (nonce: number, attestation: BridgeAttestation, signatures: TransactionInstruction[]) =>
+      program.methods
+        .settleBridgeRequest({ attestation })
+        .accountsStrict({
+          settler: treasury.publicKey,
+          vaultConfig: vaultConfigPda,
+          owner: user.publicKey,
+          userPosition: userPositionPda,
+          bridgeRequest: bridgeRequestPda(nonce),
+          shieldedMint: shieldedMint,
+          ownerTokenAccount: userTokenAccount,
+          bridgeEscrow: bridgeEscrowPda,
+          guardianSet: guardianSetPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .preInstructions(signatures)
+        .signers([treasury]);
+    let bridgeEscrowPda: PublicKey;
+    const bridgeAmount = new BN(1_000_000_000);
+    const bridgeFee = bridgeAmount.toNumber() * 25 / 10000;
 
     before(async () => {
-      [complianceAttestationPda] = PublicKey.findProgramAddressSync(
-        [COMPLIANCE_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
+      [bridgeEscrowPda] = PublicKey.findProgramAddressSync(
+        [BRIDGE_ESCROW_SEED, shieldedMint.toBuffer()],
         program.programId
       );
       const clock = await banksClient.getClock();
       context.setClock(new Clock(clock.slot + BigInt(100), clock.epochStartTimestamp, clock.epoch, clock.leaderScheduleEpoch, clock.unixTimestamp));
     });
 
-    it("submits compliance attestation", async () => {
-      const attestationHash = new Array(32).fill(1);
-      const disclosureProof = generateProof(32);
-
-      await program.methods
-        .applyCompliance({
-          action: { submit: {} },
-          attestationHash: attestationHash,
-          disclosureProof: disclosureProof,
-          validityDays: 30,
+    const buildBaseBridge = (action: any, nonce: number, amount: BN = bridgeAmount) =>
+      program.methods
+        .privateBridge({
+          action,
+          destChainId: new BN(8453),
+          requestNonce: new BN(nonce),
+          amount,
+          amountCommitment: generateCommitment(),
+          encryptedRecipient: generateEncryptedRecipient(),
+          bridgeProof: generateProof(32),
         })
         .accountsStrict({
           user: user.publicKey,
+          chainConfig: chainConfigPda(8453),
+          bridgeStats: bridgeStatsPda,
           vaultConfig: vaultConfigPda,
+          relayerKeys: relayerKeysPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
-          complianceAttestation: complianceAttestationPda,
-          rangeProgram: RANGE_PROGRAM_ID,
+          bridgeRequest: bridgeRequestPda(nonce),
+          shieldedMint: shieldedMint,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          bridgeEscrow: bridgeEscrowPda,
+          silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
-        .signers([user])
-        .rpc();
-
-      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
-      expect(compliance.user.toBase58()).to.equal(user.publicKey.toBase58());
-      expect(compliance.isValid).to.be.true;
-      expect(compliance.riskScore).to.be.lessThanOrEqual(75);
+        .signers([user]);
 
-      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
-      expect(userPosition.complianceVerified).to.be.true;
-    });
+    it("initiates outbound bridge to Ethereum", async () => {
+      const amountCommitment = generateCommitment();
+      const encryptedRecipient = generateEncryptedRecipient();
+      const bridgeProof = generateProof(32);
 
-    it("verifies existing compliance", async () => {
-      const disclosureProof = generateProof(32);
+      const userBalanceBefore = await getTokenBalanceWithBankrun(context, userTokenAccount);
+      const vaultBalanceBefore = await getTokenBalanceWithBankrun(context, shieldedVaultAta);
 
       await program.methods
-        .applyCompliance({
-          action: { verify: {} },
-          attestationHash: generateCommitment(),
-          disclosureProof: disclosureProof,
-          validityDays: 30,
+        .privateBridge({
+          action: { initiateOutbound: {} },
+          destChainId: new BN(1),
+          requestNonce: new BN(0),
+          amount: bridgeAmount,
+          amountCommitment: amountCommitment,
+          encryptedRecipient: encryptedRecipient,
+          bridgeProof: bridgeProof,
         })
         .accountsStrict({
           user: user.publicKey,
+          chainConfig: chainConfigPda(1),
           vaultConfig: vaultConfigPda,
+          relayerKeys: relayerKeysPda,
+          bridgeStats: bridgeStatsPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
-          complianceAttestation: complianceAttestationPda,
-          rangeProgram: RANGE_PROGRAM_ID,
+          bridgeRequest: bridgeRequestPda(0),
+          shieldedMint: shieldedMint,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          bridgeEscrow: bridgeEscrowPda,
+          silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([user])
         .rpc();
 
-      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
-      expect(compliance.isValid).to.be.true;
+      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(0));
+      expect(bridgeRequest.user.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(bridgeRequest.destChainId.toNumber()).to.equal(1);
+      expect(bridgeRequest.nonce.toNumber()).to.equal(0);
+      expect(bridgeRequest.encryptedRecipient.keyVersion).to.equal(1);
+      expect(bridgeRequest.encryptedRecipient.ciphertext).to.deep.equal(encryptedRecipient.ciphertext);
+      expect(bridgeRequest.status).to.deep.equal({ pending: {} });
+      expect(bridgeRequest.feeAmount.toNumber()).to.equal(bridgeFee);
+      expect(bridgeRequest.escrowedAmount.toNumber()).to.equal(bridgeAmount.toNumber() - bridgeFee);
+
+      const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
+      const vaultBalanceAfter = await getTokenBalanceWithBankrun(context, shieldedVaultAta);
+      const escrowBalance = await getTokenBalanceWithBankrun(context, bridgeEscrowPda);
+      expect(Number(userBalanceBefore) - Number(userBalanceAfter)).to.equal(bridgeAmount.toNumber());
+      expect(Number(vaultBalanceAfter) - Number(vaultBalanceBefore)).to.equal(bridgeFee);
+      expect(Number(escrowBalance)).to.equal(bridgeAmount.toNumber() - bridgeFee);
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.pendingBridgeCount).to.equal(1);
+      expect(userPosition.hasPendingBridge).to.be.true;
+      expect(userPosition.bridgeNonce.toNumber()).to.equal(1);
     });
-  });
 
-  describe("7. Time Advance + Accrue View", () => {
-    it("admin deposits rewards to simulate yield", async () => {
-      const rewardAmount = new BN(10_000_000_000);
+    it("stops the owner cancelling once the relayer picks a request up", async () => {
+      try {
+        await acknowledgeBridge(0, user).rpc();
+        expect.fail("only the registered relayer may acknowledge");
+      } catch (err) {
+        expect(String(err)).to.include("Unauthorized");
+      }
+
+      await acknowledgeBridge(0).rpc();
+
+      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(0));
+      expect(bridgeRequest.status).to.deep.equal({ confirmed: {} });
+
+      try {
+        await program.methods
+          .privateBridge({
+            action: { cancelRequest: {} },
+            destChainId: new BN(1),
+            requestNonce: new BN(0),
+            amount: new BN(0),
+            amountCommitment: generateCommitment(),
+            encryptedRecipient: null,
+            bridgeProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            chainConfig: chainConfigPda(1),
+            vaultConfig: vaultConfigPda,
+            relayerKeys: relayerKeysPda,
+            bridgeStats: bridgeStatsPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            bridgeRequest: bridgeRequestPda(0),
+            shieldedMint: shieldedMint,
+            userTokenAccount: userTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            bridgeEscrow: bridgeEscrowPda,
+            silentswapProgram: SILENTSWAP_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("owner cancelled a request the relayer already picked up");
+      } catch (err) {
+        expect(String(err)).to.include("BridgeRequestInFlight");
+      }
+    });
+
+    it("rejects completion signed by fewer guardians or for another deployment", async () => {
+      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(0));
+      const attestation = {
+        kind: { outboundCompletion: {} },
+        chainId: bridgeRequest.destChainId,
+        nonce: bridgeRequest.nonce,
+        recipientCommitment: recipientCommitment(user.publicKey, bridgeRequest.amountCommitment),
+        amount: bridgeRequest.escrowedAmount,
+      };
+
+      try {
+        await settleBridge(0, attestation, guardianSignatures(guardians.slice(0, 1), attestation)).rpc();
+        expect.fail("completion should require the guardian threshold");
+      } catch (err) {
+        expect(String(err)).to.include("InsufficientGuardianSignatures");
+      }
+
+      const otherVault = Keypair.generate().publicKey;
+      try {
+        await settleBridge(0, attestation, guardianSignatures(guardians.slice(0, 2), attestation, otherVault)).rpc();
+        expect.fail("accepted guardian signatures made for another vault");
+      } catch (err) {
+        expect(String(err)).to.include("InsufficientGuardianSignatures");
+      }
+    });
+
+    it("lets anyone finalize a completion with a guardian attestation", async () => {
+      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(0));
+      const attestation = {
+        kind: { outboundCompletion: {} },
+        chainId: bridgeRequest.destChainId,
+        nonce: bridgeRequest.nonce,
+        recipientCommitment: recipientCommitment(user.publicKey, bridgeRequest.amountCommitment),
+        amount: bridgeRequest.escrowedAmount,
+      };
+
+      await settleBridge(0, attestation, guardianSignatures(guardians.slice(0, 2), attestation)).rpc();
+
+      const completed = await program.account.bridgeRequest.fetch(bridgeRequestPda(0));
+      expect(completed.status).to.deep.equal({ completed: {} });
+      expect(completed.escrowedAmount.toNumber()).to.equal(0);
+
+      const escrowBalance = await getTokenBalanceWithBankrun(context, bridgeEscrowPda);
+      expect(Number(escrowBalance)).to.equal(0);
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.pendingBridgeCount).to.equal(0);
+      expect(userPosition.hasPendingBridge).to.be.false;
+    });
+
+    it("refunds the escrow minus the fee when a request is cancelled", async () => {
+      const userBalanceBefore = await getTokenBalanceWithBankrun(context, userTokenAccount);
 
       await program.methods
-        .adminMockYield({
-          action: { depositRewards: { amount: rewardAmount } },
+        .privateBridge({
+          action: { initiateOutbound: {} },
+          destChainId: new BN(137),
+          requestNonce: new BN(1),
+          amount: bridgeAmount,
+          amountCommitment: generateCommitment(),
+          encryptedRecipient: generateEncryptedRecipient(),
+          bridgeProof: generateProof(32),
         })
         .accountsStrict({
-          admin: admin.publicKey,
+          user: user.publicKey,
+          chainConfig: chainConfigPda(137),
           vaultConfig: vaultConfigPda,
-          adminTokenAccount: adminTokenAccount,
-          shieldedVaultAta: shieldedVaultAta,
+          relayerKeys: relayerKeysPda,
+          bridgeStats: bridgeStatsPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          bridgeRequest: bridgeRequestPda(1),
           shieldedMint: shieldedMint,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          bridgeEscrow: bridgeEscrowPda,
+          silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
-        .signers([admin])
+        .signers([user])
         .rpc();
 
-      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
-      expect(vaultConfig.totalShieldedTvl.toNumber()).to.be.greaterThan(0);
-    });
-
-    it("admin updates yield rate to 10%", async () => {
       await program.methods
-        .adminMockYield({
-          action: { updateYieldRate: { newRateBps: 1000 } },
+        .privateBridge({
+          action: { cancelRequest: {} },
+          destChainId: new BN(137),
+          requestNonce: new BN(1),
+          amount: new BN(0),
+          amountCommitment: generateCommitment(),
+          encryptedRecipient: null,
+          bridgeProof: generateProof(32),
         })
         .accountsStrict({
-          admin: admin.publicKey,
+          user: user.publicKey,
+          chainConfig: chainConfigPda(137),
           vaultConfig: vaultConfigPda,
-          adminTokenAccount: adminTokenAccount,
-          shieldedVaultAta: shieldedVaultAta,
+          relayerKeys: relayerKeysPda,
+          bridgeStats: bridgeStatsPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          bridgeRequest: bridgeRequestPda(1),
           shieldedMint: shieldedMint,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          bridgeEscrow: bridgeEscrowPda,
+          silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
-        .signers([admin])
+        .signers([user])
         .rpc();
 
-      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
-      expect(vaultConfig.currentYieldBps).to.equal(1000);
+      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(1));
+      expect(bridgeRequest.status).to.deep.equal({ failed: {} });
+      expect(bridgeRequest.nonce.toNumber()).to.equal(1);
+      expect(bridgeRequest.escrowedAmount.toNumber()).to.equal(0);
+
+      const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
+      expect(Number(userBalanceBefore) - Number(userBalanceAfter)).to.equal(bridgeFee);
+
+      const escrowBalance = await getTokenBalanceWithBankrun(context, bridgeEscrowPda);
+      expect(Number(escrowBalance)).to.equal(0);
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.pendingBridgeCount).to.equal(0);
     });
 
-    it("advances time and shows yield growth via accrue_view", async () => {
-      const currentClock = await banksClient.getClock();
-      const newTimestamp = currentClock.unixTimestamp + BigInt(30 * 24 * 60 * 60);
+    it("tracks concurrent requests to different chains and closes finalized ones", async () => {
+      const buildBridge = (action: any, chainId: number, nonce: number) =>
+        program.methods
+          .privateBridge({
+            action,
+            destChainId: new BN(chainId),
+            requestNonce: new BN(nonce),
+            amount: bridgeAmount,
+            amountCommitment: generateCommitment(),
+            encryptedRecipient: generateEncryptedRecipient(),
+            bridgeProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            chainConfig: chainConfigPda(chainId),
+            vaultConfig: vaultConfigPda,
+            relayerKeys: relayerKeysPda,
+            bridgeStats: bridgeStatsPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            bridgeRequest: bridgeRequestPda(nonce),
+            shieldedMint: shieldedMint,
+            userTokenAccount: userTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            bridgeEscrow: bridgeEscrowPda,
+            silentswapProgram: SILENTSWAP_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user]);
 
-      context.setClock(
-        new Clock(
-          currentClock.slot + BigInt(100),
-          currentClock.epochStartTimestamp,
-          currentClock.epoch,
-          currentClock.leaderScheduleEpoch,
-          newTimestamp
-        )
-      );
+      await buildBridge({ initiateOutbound: {} }, 1, 2).rpc();
+      await buildBridge({ initiateOutbound: {} }, 137, 3).rpc();
 
-      const result = await program.methods
-        .accrueView()
+      let userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.pendingBridgeCount).to.equal(2);
+      expect(userPosition.bridgeNonce.toNumber()).to.equal(4);
+
+      const ethereumRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(2));
+      const polygonRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(3));
+      expect(ethereumRequest.destChainId.toNumber()).to.equal(1);
+      expect(polygonRequest.destChainId.toNumber()).to.equal(137);
+
+      await buildBridge({ cancelRequest: {} }, 1, 2).rpc();
+      await buildBridge({ cancelRequest: {} }, 137, 3).rpc();
+
+      userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.pendingBridgeCount).to.equal(0);
+
+      for (const nonce of [0, 1, 2, 3]) {
+        await program.methods
+          .closeBridgeRequest()
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            bridgeRequest: bridgeRequestPda(nonce),
+          })
+          .signers([user])
+          .rpc();
+
+        const closed = await banksClient.getAccount(bridgeRequestPda(nonce));
+        expect(closed).to.be.null;
+      }
+    });
+
+    it("rejects outbound requests to a disabled chain", async () => {
+      await program.methods
+        .configureChain({
+          chainId: new BN(10),
+          enabled: false,
+          feeBpsOverride: 50,
+          minAmount: new BN(1_000_000),
+          maxAmount: new BN(10_000_000_000),
+          requiredConfirmations: 64,
+          addressFormat: { evm: {} },
+          timeoutSecs: new BN(3600),
+          outflowLimit: new BN(0),
+        })
         .accountsStrict({
-          user: user.publicKey,
+          admin: admin.publicKey,
           vaultConfig: vaultConfigPda,
-          userPosition: userPositionPda,
-          lendingPosition: null,
+          council: councilPda(),
+          chainConfig: chainConfigPda(10),
+          systemProgram: SystemProgram.programId,
         })
-        .signers([user])
+        .signers([admin])
         .rpc();
 
-      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
-      expect(vaultConfig.currentYieldBps).to.equal(1000);
-
-      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
-      expect(userPosition.hasActiveLoan).to.be.false;
+      try {
+        await program.methods
+          .privateBridge({
+            action: { initiateOutbound: {} },
+            destChainId: new BN(10),
+            requestNonce: new BN(4),
+            amount: bridgeAmount,
+            amountCommitment: generateCommitment(),
+            encryptedRecipient: generateEncryptedRecipient(),
+            bridgeProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            chainConfig: chainConfigPda(10),
+            vaultConfig: vaultConfigPda,
+            relayerKeys: relayerKeysPda,
+            bridgeStats: bridgeStatsPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            bridgeRequest: bridgeRequestPda(4),
+            shieldedMint: shieldedMint,
+            userTokenAccount: userTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            bridgeEscrow: bridgeEscrowPda,
+            silentswapProgram: SILENTSWAP_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("bridge accepted a disabled destination chain");
+      } catch (err) {
+        expect(String(err)).to.include("ChainDisabled");
+      }
     });
-  });
-
-  describe("8. Private Withdraw", () => {
-    it("withdraws more than deposited (principal + yield)", async () => {
-      const withdrawalProof = generateProof(32);
-      const ownershipProof = generateProof(32);
-      const nullifier = generateNullifier();
-      const withdrawAmount = new BN(55_000_000_000);
 
+    it("refunds a timed-out request through the permissionless crank", async () => {
       const userBalanceBefore = await getTokenBalanceWithBankrun(context, userTokenAccount);
 
       await program.methods
-        .privateWithdraw({
-          withdrawType: { full: {} },
-          withdrawalProof: withdrawalProof,
-          ownershipProof: ownershipProof,
-          nullifier: nullifier,
-          expectedAmount: withdrawAmount,
+        .privateBridge({
+          action: { initiateOutbound: {} },
+          destChainId: new BN(1),
+          requestNonce: new BN(4),
+          amount: bridgeAmount,
+          amountCommitment: generateCommitment(),
+          encryptedRecipient: generateEncryptedRecipient(),
+          bridgeProof: generateProof(32),
         })
         .accountsStrict({
           user: user.publicKey,
+          chainConfig: chainConfigPda(1),
           vaultConfig: vaultConfigPda,
+          relayerKeys: relayerKeysPda,
+          bridgeStats: bridgeStatsPda,
+          adapterRegistry: adapterRegistryPda,
           userPosition: userPositionPda,
+          bridgeRequest: bridgeRequestPda(4),
+          shieldedMint: shieldedMint,
           userTokenAccount: userTokenAccount,
           shieldedVaultAta: shieldedVaultAta,
-          shieldedMint: shieldedMint,
+          bridgeEscrow: bridgeEscrowPda,
+          silentswapProgram: SILENTSWAP_PROGRAM_ID,
           complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
         .signers([user])
         .rpc();
 
-      const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
+      const buildRefund = () =>
+        program.methods
+          .refundExpiredBridge()
+          .accountsStrict({
+            cranker: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            owner: user.publicKey,
+            userPosition: userPositionPda,
+            bridgeRequest: bridgeRequestPda(4),
+            shieldedMint: shieldedMint,
+            ownerTokenAccount: userTokenAccount,
+            bridgeEscrow: bridgeEscrowPda,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([admin]);
 
-      const received = Number(userBalanceAfter) - Number(userBalanceBefore);
-      const fee = withdrawAmount.toNumber() * 10 / 10000;
-      const expectedNet = withdrawAmount.toNumber() - fee;
+      try {
+        await buildRefund().rpc();
+        expect.fail("refunded a request before its timeout");
+      } catch (err) {
+        expect(String(err)).to.include("BridgeRequestNotExpired");
+      }
 
-      expect(received).to.equal(expectedNet);
+      const clock = await banksClient.getClock();
+      context.setClock(
+        new Clock(
+          clock.slot + BigInt(100),
+          clock.epochStartTimestamp,
+          clock.epoch,
+          clock.leaderScheduleEpoch,
+          clock.unixTimestamp + BigInt(3601)
+        )
+      );
+
+      try {
+        await acknowledgeBridge(4).rpc();
+        expect.fail("relayer picked up a request after its timeout");
+      } catch (err) {
+        expect(String(err)).to.include("BridgeRequestExpired");
+      }
+
+      await buildRefund().rpc();
+
+      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(4));
+      expect(bridgeRequest.status).to.deep.equal({ failed: {} });
+      expect(bridgeRequest.escrowedAmount.toNumber()).to.equal(0);
+
+      const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
+      expect(Number(userBalanceBefore) - Number(userBalanceAfter)).to.equal(bridgeFee);
 
       const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
-      expect(userPosition.withdrawalCount).to.equal(1);
-      expect(userPosition.nullifier).to.deep.equal(nullifier);
+      expect(userPosition.pendingBridgeCount).to.equal(0);
     });
-  });
 
-  describe("9. Admin Controls", () => {
-    it("toggles SDK features", async () => {
+    it("rejects outbound volume above the chain's window limit", async () => {
       await program.methods
-        .adminMockYield({
-          action: {
-            toggleSdk: {
-              arcium: false,
-              shadowwire: null,
-              anoncoin: null,
-              privacyCash: null,
-              silentswap: null,
-              starpay: null,
-              range: null,
-            },
-          },
+        .configureChain({
+          chainId: new BN(8453),
+          enabled: true,
+          feeBpsOverride: null,
+          minAmount: new BN(1_000_000),
+          maxAmount: new BN(10_000_000_000),
+          requiredConfirmations: 64,
+          addressFormat: { evm: {} },
+          timeoutSecs: new BN(3600),
+          outflowLimit: new BN(1_500_000_000),
         })
         .accountsStrict({
           admin: admin.publicKey,
           vaultConfig: vaultConfigPda,
-          adminTokenAccount: adminTokenAccount,
-          shieldedVaultAta: shieldedVaultAta,
-          shieldedMint: shieldedMint,
-          token2022Program: TOKEN_2022_PROGRAM_ID,
+          council: councilPda(),
+          chainConfig: chainConfigPda(8453),
           systemProgram: SystemProgram.programId,
         })
         .signers([admin])
         .rpc();
 
-      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
-      expect(vaultConfig.arciumEnabled).to.be.false;
+      await buildBaseBridge({ initiateOutbound: {} }, 5).rpc();
+
+      try {
+        await buildBaseBridge({ initiateOutbound: {} }, 6).rpc();
+        expect.fail("second request exceeded the Base outflow limit");
+      } catch (err) {
+        expect(String(err)).to.include("BridgeRateLimitExceeded");
+      }
+
+      const chainConfig = await program.account.chainConfig.fetch(chainConfigPda(8453));
+      expect(chainConfig.outflow.outflow.toNumber()).to.equal(bridgeAmount.toNumber());
+
+      await buildBaseBridge({ cancelRequest: {} }, 5).rpc();
+    });
+
+    it("refunds a picked-up request only against a guardian failure attestation, not on timeout", async () => {
+      const userBalanceBefore = await getTokenBalanceWithBankrun(context, userTokenAccount);
 
       await program.methods
-        .adminMockYield({
-          action: {
-            toggleSdk: {
-              arcium: true,
-              shadowwire: null,
-              anoncoin: null,
-              privacyCash: null,
-              silentswap: null,
-              starpay: null,
-              range: null,
-            },
-          },
+        .privateBridge({
+          action: { initiateOutbound: {} },
+          destChainId: new BN(1),
+          requestNonce: new BN(6),
+          amount: bridgeAmount,
+          amountCommitment: generateCommitment(),
+          encryptedRecipient: generateEncryptedRecipient(),
+          bridgeProof: generateProof(32),
         })
         .accountsStrict({
-          admin: admin.publicKey,
+          user: user.publicKey,
+          chainConfig: chainConfigPda(1),
           vaultConfig: vaultConfigPda,
-          adminTokenAccount: adminTokenAccount,
-          shieldedVaultAta: shieldedVaultAta,
+          relayerKeys: relayerKeysPda,
+          bridgeStats: bridgeStatsPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          bridgeRequest: bridgeRequestPda(6),
           shieldedMint: shieldedMint,
-          token2022Program: TOKEN_2022_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          bridgeEscrow: bridgeEscrowPda,
+          silentswapProgram: SILENTSWAP_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      await acknowledgeBridge(6).rpc();
+      await advanceClock(3601);
+
+      try {
+        await program.methods
+          .refundExpiredBridge()
+          .accountsStrict({
+            cranker: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            owner: user.publicKey,
+            userPosition: userPositionPda,
+            bridgeRequest: bridgeRequestPda(6),
+            shieldedMint: shieldedMint,
+            ownerTokenAccount: userTokenAccount,
+            bridgeEscrow: bridgeEscrowPda,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("timeout refunded a request the relayer may have delivered");
+      } catch (err) {
+        expect(String(err)).to.include("BridgeRequestInFlight");
+      }
+
+      const bridgeRequest = await program.account.bridgeRequest.fetch(bridgeRequestPda(6));
+      const failure = {
+        kind: { outboundFailure: {} },
+        chainId: bridgeRequest.destChainId,
+        nonce: bridgeRequest.nonce,
+        recipientCommitment: recipientCommitment(user.publicKey, bridgeRequest.amountCommitment),
+        amount: bridgeRequest.escrowedAmount,
+      };
+
+      try {
+        await settleBridge(6, failure, guardianSignatures(guardians.slice(0, 1), failure)).rpc();
+        expect.fail("refund should require the guardian threshold");
+      } catch (err) {
+        expect(String(err)).to.include("InsufficientGuardianSignatures");
+      }
+
+      await settleBridge(6, failure, guardianSignatures(guardians.slice(0, 2), failure)).rpc();
+
+      const settled = await program.account.bridgeRequest.fetch(bridgeRequestPda(6));
+      expect(settled.status).to.deep.equal({ failed: {} });
+      expect(settled.escrowedAmount.toNumber()).to.equal(0);
+
+      const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
+      expect(Number(userBalanceBefore) - Number(userBalanceAfter)).to.equal(bridgeFee);
+    });
+
+    it("keeps the chain outflow limit rolling across what used to be a window boundary", async () => {
+      // Let earlier Base volume drain, then open the window with a minimal request
+      await advanceClock(BRIDGE_OUTFLOW_WINDOW_SECS);
+      await buildBaseBridge({ initiateOutbound: {} }, 7, new BN(1_000_000)).rpc();
+      await buildBaseBridge({ cancelRequest: {} }, 7).rpc();
+
+      // Spend most of the limit just before a fixed window would have reset...
+      const nearLimit = new BN(1_400_000_000);
+      await advanceClock(BRIDGE_OUTFLOW_WINDOW_SECS - 60);
+      await buildBaseBridge({ initiateOutbound: {} }, 8, nearLimit).rpc();
+
+      // ...and again just after it: only two minutes of volume have drained since
+      await advanceClock(120);
+      try {
+        await buildBaseBridge({ initiateOutbound: {} }, 9, nearLimit).rpc();
+        expect.fail("straddling the window boundary doubled the Base outflow limit");
+      } catch (err) {
+        expect(String(err)).to.include("BridgeRateLimitExceeded");
+      }
+
+      await buildBaseBridge({ cancelRequest: {} }, 8).rpc();
+    });
+
+    it("lets a guardian trip the bridge circuit breaker", async () => {
+      const buildBreaker = (action: any, authority: Keypair) =>
+        program.methods
+          .bridgeCircuitBreaker({ action })
+          .accountsStrict({
+            authority: authority.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            guardianSet: guardianSetPda,
+            bridgeStats: bridgeStatsPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority]);
+
+      await buildBreaker({ pause: {} }, guardians[0]).rpc();
+
+      let bridgeStats = await program.account.bridgeStats.fetch(bridgeStatsPda);
+      expect(bridgeStats.bridgePaused).to.be.true;
+      expect(bridgeStats.pausedBy.toBase58()).to.equal(guardians[0].publicKey.toBase58());
+
+      try {
+        await buildBreaker({ resume: {} }, guardians[0]).rpc();
+        expect.fail("guardian reset the circuit breaker");
+      } catch (err) {
+        expect(String(err)).to.include("Unauthorized");
+      }
+
+      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(0);
+
+      await buildBreaker({ resume: {} }, admin).rpc();
+
+      bridgeStats = await program.account.bridgeStats.fetch(bridgeStatsPda);
+      expect(bridgeStats.bridgePaused).to.be.false;
+    });
+
+    it("claims an inbound transfer attested by the guardians exactly once", async () => {
+      const amountCommitment = randomRistrettoPoint();
+      const amountHandle = randomRistrettoPoint();
+      const elgamalPubkey = randomRistrettoPoint();
+      const attestation = {
+        kind: { inboundTransfer: {} },
+        chainId: new BN(42161),
+        nonce: new BN(7),
+        recipientCommitment: recipientCommitment(user.publicKey, amountCommitment),
+        amount: new BN(500_000_000),
+      };
+      const [consumedMessagePda] = PublicKey.findProgramAddressSync(
+        [
+          CONSUMED_MESSAGE_SEED,
+          vaultConfigPda.toBuffer(),
+          attestation.chainId.toArrayLike(Buffer, "le", 8),
+          attestation.nonce.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const consumedBefore = await program.methods
+        .bridgeMessageStatus(attestation.chainId, attestation.nonce)
+        .accountsStrict({
+          vaultConfig: vaultConfigPda,
+          consumedMessage: consumedMessagePda,
+        })
+        .view();
+      expect(consumedBefore).to.be.false;
+
+      const buildClaim = () =>
+        program.methods
+          .claimInbound({
+            amountCommitment: amountCommitment,
+            amountHandle: amountHandle,
+            elgamalPubkey: elgamalPubkey,
+            attestation: attestation,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            bridgeStats: bridgeStatsPda,
+            userPosition: userPositionPda,
+            consumedMessage: consumedMessagePda,
+            guardianSet: guardianSetPda,
+            adapterRegistry: adapterRegistryPda,
+            silentswapProgram: SILENTSWAP_PROGRAM_ID,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user]);
+      const amountProof = (amount: BN) =>
+        ciphertextAmountProof(elgamalPubkey, [...amountCommitment, ...amountHandle], amount);
+
+      // The credited ciphertext must encrypt the amount the guardians signed for
+      try {
+        await buildClaim()
+          .preInstructions([
+            ...guardianSignatures(guardians.slice(1, 3), attestation),
+            amountProof(attestation.amount.addn(1)),
+          ])
+          .rpc();
+        expect.fail("claim credited an amount other than the attested one");
+      } catch (err) {
+        expect(String(err)).to.include("InvalidAttestation");
+      }
+
+      await buildClaim()
+        .preInstructions([
+          ...guardianSignatures(guardians.slice(1, 3), attestation),
+          amountProof(attestation.amount),
+        ])
+        .rpc();
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.encryptedPrincipal.commitment).to.deep.equal(amountCommitment);
+      expect(userPosition.encryptedPrincipal.handle).to.deep.equal(amountHandle);
+
+      const consumedMessage = await program.account.consumedMessage.fetch(consumedMessagePda);
+      expect(consumedMessage.recipient.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(consumedMessage.nonce.toNumber()).to.equal(7);
+
+      const consumedAfter = await program.methods
+        .bridgeMessageStatus(attestation.chainId, attestation.nonce)
+        .accountsStrict({
+          vaultConfig: vaultConfigPda,
+          consumedMessage: consumedMessagePda,
+        })
+        .view();
+      expect(consumedAfter).to.be.true;
+
+      // A different guardian pair keeps the transaction distinct from the first claim
+      try {
+        await buildClaim()
+          .preInstructions([
+            ...guardianSignatures([guardians[0], guardians[2]], attestation),
+            amountProof(attestation.amount),
+          ])
+          .rpc();
+        expect.fail("inbound message claimed twice");
+      } catch (err) {
+        expect(String(err)).to.include("BridgeMessageAlreadyConsumed");
+      }
+    });
+
+    it("migrates a position created before bridge requests were keyed by nonce", async () => {
+      const current = await banksClient.getAccount(userPositionPda);
+      const before = await program.account.userEncryptedPosition.fetch(userPositionPda);
+
+      const migrate = () =>
+        program.methods
+          .migratePosition()
+          .accountsStrict({
+            owner: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            userPosition: userPositionPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user]);
+
+      // The legacy layout ends at `bump`, before the pending count and nonce
+      const legacyLen = current.data.length - 4 - 8;
+      context.setAccount(userPositionPda, { ...current, data: current.data.slice(0, legacyLen) });
+
+      await migrate().rpc();
+
+      const migrated = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect((await banksClient.getAccount(userPositionPda)).data.length).to.equal(current.data.length);
+      expect(migrated.depositCount).to.equal(before.depositCount);
+      expect(migrated.actionCount).to.equal(before.actionCount);
+      expect(migrated.hasPendingBridge).to.be.false;
+      expect(migrated.pendingBridgeCount).to.equal(0);
+      expect(migrated.bridgeNonce.toNumber()).to.equal(0);
+
+      // The compute budget instruction keeps the transaction distinct from the migration
+      try {
+        await migrate()
+          .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+          .rpc();
+        expect.fail("position migrated twice");
+      } catch (err) {
+        expect(String(err)).to.include("PositionAlreadyMigrated");
+      }
+
+      // Restore the nonce later bridge requests derive their addresses from
+      context.setAccount(userPositionPda, current);
+    });
+  });
+
+  describe("6. Apply Compliance (Range Protocol)", () => {
+    let complianceAttestationPda: PublicKey;
+
+    before(async () => {
+      [complianceAttestationPda] = PublicKey.findProgramAddressSync(
+        [COMPLIANCE_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+      const clock = await banksClient.getClock();
+      context.setClock(new Clock(clock.slot + BigInt(100), clock.epochStartTimestamp, clock.epoch, clock.leaderScheduleEpoch, clock.unixTimestamp));
+    });
+
+    const signedAttestation = async (riskScore: number): Promise<ProviderAttestation> => {
+      const clock = await banksClient.getClock();
+      return {
+        provider: complianceProvider.publicKey,
+        issuedAt: new BN(Number(clock.unixTimestamp)),
+        riskScore,
+        tier: { verified: {} },
+        expiresAt: new BN(Number(clock.unixTimestamp) + 30 * 86400),
+        jurisdiction: Array.from(Buffer.from("DE")),
+      };
+    };
+
+    it("rejects an attestation signed by an unapproved key", async () => {
+      const attestation = await signedAttestation(10);
+      try {
+        await program.methods
+          .applyCompliance({
+            action: { submit: {} },
+            disclosureProof: generateProof(32),
+            providerAttestation: attestation,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            complianceAttestation: complianceAttestationPda,
+            complianceProviders: complianceProvidersPda,
+            rangeProgram: RANGE_PROGRAM_ID,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([providerSignature(Keypair.generate(), user.publicKey, attestation)])
+          .signers([user])
+          .rpc();
+        expect.fail("Should have rejected a forged provider signature");
+      } catch (err) {
+        expect(String(err)).to.include("InvalidComplianceAttestation");
+      }
+    });
+
+    let submittedAttestation: ProviderAttestation;
+
+    it("submits compliance attestation", async () => {
+      const attestation = await signedAttestation(10);
+      submittedAttestation = attestation;
+      const disclosureProof = generateProof(32);
+
+      await program.methods
+        .applyCompliance({
+          action: { submit: {} },
+          disclosureProof: disclosureProof,
+          providerAttestation: attestation,
+        })
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          complianceAttestation: complianceAttestationPda,
+          complianceProviders: complianceProvidersPda,
+          rangeProgram: RANGE_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([providerSignature(complianceProvider, user.publicKey, attestation)])
+        .signers([user])
+        .rpc();
+
+      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.user.toBase58()).to.equal(user.publicKey.toBase58());
+      expect(compliance.provider.toBase58()).to.equal(complianceProvider.publicKey.toBase58());
+      expect(compliance.isValid).to.be.true;
+      expect(compliance.riskScore).to.equal(10);
+      expect(compliance.tier).to.deep.equal({ verified: {} });
+      expect(Buffer.from(compliance.jurisdiction).toString()).to.equal("DE");
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.complianceVerified).to.be.true;
+    });
+
+    it("verifies existing compliance", async () => {
+      const disclosureProof = generateProof(32);
+
+      await program.methods
+        .applyCompliance({
+          action: { verify: {} },
+          disclosureProof: disclosureProof,
+          providerAttestation: null,
+        })
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          complianceAttestation: complianceAttestationPda,
+          complianceProviders: complianceProvidersPda,
+          rangeProgram: RANGE_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.isValid).to.be.true;
+    });
+
+    it("migrates an attestation accepted before provider signatures and invalidates it", async () => {
+      const current = await banksClient.getAccount(complianceAttestationPda);
+      const before = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+
+      const migrate = () =>
+        program.methods
+          .migrateComplianceAttestation()
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            complianceAttestation: complianceAttestationPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user]);
+
+      // The legacy layout ends at `bump`, before the tier, jurisdiction, usage and issue times
+      const legacyLen = current.data.length - 1 - 2 - 8 * 5 - 8 * 2;
+      context.setAccount(complianceAttestationPda, { ...current, data: current.data.slice(0, legacyLen) });
+
+      await migrate().rpc();
+
+      const migrated = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect((await banksClient.getAccount(complianceAttestationPda)).data.length).to.equal(current.data.length);
+      expect(migrated.user.toBase58()).to.equal(before.user.toBase58());
+      expect(migrated.riskScore).to.equal(before.riskScore);
+      expect(migrated.isValid).to.be.false;
+      expect(migrated.tier).to.deep.equal({ basic: {} });
+      expect(migrated.issuedAt.toNumber()).to.equal(0);
+
+      try {
+        await migrate()
+          .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 400_000 })])
+          .rpc();
+        expect.fail("attestation migrated twice");
+      } catch (err) {
+        expect(String(err)).to.include("AccountAlreadyMigrated");
+      }
+
+      context.setAccount(complianceAttestationPda, current);
+    });
+
+    it("rejects revocation signed by the attested user", async () => {
+      try {
+        await program.methods
+          .revokeCompliance({ reason: { other: {} } })
+          .accountsStrict({
+            authority: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            roleRegistry: roleRegistryPda,
+            user: user.publicKey,
+            userPosition: userPositionPda,
+            complianceAttestation: complianceAttestationPda,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have rejected a self-revocation");
+      } catch (err) {
+        expect(String(err)).to.include("UnauthorizedRevocation");
+      }
+    });
+
+    it("lets a compliance officer role holder revoke an attestation", async () => {
+      await program.methods
+        .revokeCompliance({ reason: { sanctionsMatch: {} } })
+        .accountsStrict({
+          authority: complianceOfficer.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: roleRegistryPda,
+          user: user.publicKey,
+          userPosition: userPositionPda,
+          complianceAttestation: complianceAttestationPda,
+        })
+        .signers([complianceOfficer])
+        .rpc();
+
+      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.isValid).to.be.false;
+      expect(compliance.revokedAt.toNumber()).to.be.greaterThan(0);
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.complianceVerified).to.be.false;
+    });
+
+    it("refuses to replay an attestation issued before the revocation", async () => {
+      try {
+        await program.methods
+          .applyCompliance({
+            action: { submit: {} },
+            disclosureProof: generateProof(32),
+            providerAttestation: submittedAttestation,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            complianceAttestation: complianceAttestationPda,
+            complianceProviders: complianceProvidersPda,
+            rangeProgram: RANGE_PROGRAM_ID,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([providerSignature(complianceProvider, user.publicKey, submittedAttestation)])
+          .signers([user])
+          .rpc();
+        expect.fail("replayed a pre-revocation attestation");
+      } catch (err) {
+        expect(String(err)).to.include("ComplianceAttestationStale");
+      }
+
+      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.isValid).to.be.false;
+    });
+
+    it("re-submits after revocation with a freshly issued attestation", async () => {
+      await advanceClock(1);
+      const attestation = await signedAttestation(20);
+      await program.methods
+        .applyCompliance({
+          action: { submit: {} },
+          disclosureProof: generateProof(32),
+          providerAttestation: attestation,
+        })
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          complianceAttestation: complianceAttestationPda,
+          complianceProviders: complianceProvidersPda,
+          rangeProgram: RANGE_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([providerSignature(complianceProvider, user.publicKey, attestation)])
+        .signers([user])
+        .rpc();
+
+      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.isValid).to.be.true;
+    });
+
+    it("lets the admin revoke without the user's position", async () => {
+      await program.methods
+        .revokeCompliance({ reason: { other: {} } })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          user: user.publicKey,
+          userPosition: null,
+          complianceAttestation: complianceAttestationPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      let compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.isValid).to.be.false;
+
+      await advanceClock(1);
+      const attestation = await signedAttestation(20);
+      await program.methods
+        .applyCompliance({
+          action: { submit: {} },
+          disclosureProof: generateProof(32),
+          providerAttestation: attestation,
+        })
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          complianceAttestation: complianceAttestationPda,
+          complianceProviders: complianceProvidersPda,
+          rangeProgram: RANGE_PROGRAM_ID,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions([providerSignature(complianceProvider, user.publicKey, attestation)])
+        .signers([user])
+        .rpc();
+
+      compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.isValid).to.be.true;
+    });
+
+    it("enforces per-tier deposit limits", async () => {
+      const [compliancePolicyPda] = PublicKey.findProgramAddressSync(
+        [COMPLIANCE_POLICY_SEED, vaultConfigPda.toBuffer()],
+        program.programId
+      );
+      const unlimited = { perTransaction: new BN(0), perPeriod: new BN(0) };
+      const tierLimits = (deposit: { perTransaction: BN; perPeriod: BN }) => ({
+        deposit,
+        withdraw: unlimited,
+        swap: unlimited,
+        bridge: unlimited,
+      });
+
+      await program.methods
+        .setCompliancePolicy({
+          periodSecs: new BN(86400),
+          tiers: [
+            tierLimits({ perTransaction: new BN(1_000_000_000), perPeriod: new BN(1_000_000_000) }),
+            tierLimits({ perTransaction: new BN(2_000_000_000), perPeriod: new BN(3_000_000_000) }),
+            tierLimits(unlimited),
+          ],
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          compliancePolicy: compliancePolicyPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      await program.methods
+        .adminMockYield({ action: { setComplianceRequired: { required: true } } })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
+          adminTokenAccount: adminTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      type ScreeningProof = ReturnType<typeof screeningTree.nonMembershipProof>;
+      const deposit = (
+        amount: number,
+        screeningProof: ScreeningProof | null = screeningTree.nonMembershipProof(user.publicKey)
+      ) =>
+        program.methods
+          .privateDeposit({
+            amount: new BN(amount),
+            amountCommitment: generateCommitment(),
+            blindingFactor: generateCommitment(),
+            screeningProof,
+            auditorDisclosure: null,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            userPosition: userPositionPda,
+            userTokenAccount: userTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            complianceAttestation: complianceAttestationPda,
+            compliancePolicy: compliancePolicyPda,
+            jurisdictions: jurisdictionsPda,
+            screening: screeningPda,
+            auditorKey: auditorKeyPda,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+
+      try {
+        await deposit(1_000_000_000, null);
+        expect.fail("Should have required a screening proof");
+      } catch (err) {
+        expect(String(err)).to.include("AddressScreeningFailed");
+      }
+
+      try {
+        await deposit(2_500_000_000);
+        expect.fail("Should have rejected a deposit over the per-transaction limit");
+      } catch (err) {
+        expect(String(err)).to.include("ComplianceThresholdExceeded");
+      }
+
+      await deposit(2_000_000_000);
+
+      try {
+        await deposit(1_500_000_000);
+        expect.fail("Should have rejected a deposit over the period limit");
+      } catch (err) {
+        expect(String(err)).to.include("ComplianceThresholdExceeded");
+      }
+
+      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.usage.deposited.toNumber()).to.equal(2_000_000_000);
+
+      // The period rolls: volume spent just before a fixed period would have reset still
+      // counts right after it
+      await advanceClock(86400 - 60);
+      await deposit(2_000_000_000);
+      await advanceClock(120);
+      try {
+        await deposit(2_000_000_000);
+        expect.fail("straddling the period boundary doubled the period limit");
+      } catch (err) {
+        expect(String(err)).to.include("ComplianceThresholdExceeded");
+      }
+
+      await program.methods
+        .adminMockYield({ action: { setComplianceRequired: { required: false } } })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
+          adminTokenAccount: adminTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("blocks bridging to chains barred for the attested jurisdiction", async () => {
+      const unlimited = { perTransaction: new BN(0), perPeriod: new BN(0) };
+      const setDeRule = (blockedChains: BN[]) =>
+        program.methods
+          .setJurisdictionRule({
+            action: {
+              upsert: {
+                rule: {
+                  jurisdiction: Array.from(Buffer.from("DE")),
+                  allowedOperations: 0b11111,
+                  limits: { deposit: unlimited, withdraw: unlimited, swap: unlimited, bridge: unlimited },
+                  blockedChains,
+                },
+              },
+            },
+          })
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            jurisdictions: jurisdictionsPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      const setComplianceRequired = (required: boolean) =>
+        program.methods
+          .adminMockYield({ action: { setComplianceRequired: { required } } })
+          .accountsStrict({
+            authority: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            roleRegistry: null,
+            council: councilPda(),
+            adminTokenAccount: adminTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+      await setDeRule([new BN(1)]);
+      await setComplianceRequired(true);
+
+      const [compliancePolicyPda] = PublicKey.findProgramAddressSync(
+        [COMPLIANCE_POLICY_SEED, vaultConfigPda.toBuffer()],
+        program.programId
+      );
+      const [bridgeEscrowPda] = PublicKey.findProgramAddressSync(
+        [BRIDGE_ESCROW_SEED, shieldedMint.toBuffer()],
+        program.programId
+      );
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      const [bridgeRequestPda] = PublicKey.findProgramAddressSync(
+        [
+          BRIDGE_REQUEST_SEED,
+          vaultConfigPda.toBuffer(),
+          user.publicKey.toBuffer(),
+          userPosition.bridgeNonce.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .privateBridge({
+            action: { initiateOutbound: {} },
+            destChainId: new BN(1),
+            requestNonce: userPosition.bridgeNonce,
+            amount: new BN(1_000_000_000),
+            amountCommitment: generateCommitment(),
+            encryptedRecipient: generateEncryptedRecipient(),
+            bridgeProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            chainConfig: chainConfigPda(1),
+            vaultConfig: vaultConfigPda,
+            relayerKeys: relayerKeysPda,
+            bridgeStats: bridgeStatsPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            bridgeRequest: bridgeRequestPda,
+            shieldedMint: shieldedMint,
+            userTokenAccount: userTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            bridgeEscrow: bridgeEscrowPda,
+            silentswapProgram: SILENTSWAP_PROGRAM_ID,
+            complianceAttestation: complianceAttestationPda,
+            compliancePolicy: compliancePolicyPda,
+            jurisdictions: jurisdictionsPda,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have blocked bridging to chain 1 from DE");
+      } catch (err) {
+        expect(String(err)).to.include("JurisdictionBlocked");
+      }
+
+      await setComplianceRequired(false);
+      await setDeRule([]);
+    });
+
+    it("blocks lending and limit orders for a jurisdiction that permits neither", async () => {
+      const unlimited = { perTransaction: new BN(0), perPeriod: new BN(0) };
+      const setDeAllowedOperations = (allowedOperations: number) =>
+        program.methods
+          .setJurisdictionRule({
+            action: {
+              upsert: {
+                rule: {
+                  jurisdiction: Array.from(Buffer.from("DE")),
+                  allowedOperations,
+                  limits: { deposit: unlimited, withdraw: unlimited, swap: unlimited, bridge: unlimited },
+                  blockedChains: [],
+                },
+              },
+            },
+          })
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            jurisdictions: jurisdictionsPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      const setComplianceRequired = (required: boolean) =>
+        program.methods
+          .adminMockYield({ action: { setComplianceRequired: { required } } })
+          .accountsStrict({
+            authority: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            roleRegistry: null,
+            council: councilPda(),
+            adminTokenAccount: adminTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+      // Deposit, withdraw and bridge only: no swap or lend bit
+      await setDeAllowedOperations(0b01011);
+      await setComplianceRequired(true);
+
+      const [compliancePolicyPda] = PublicKey.findProgramAddressSync(
+        [COMPLIANCE_POLICY_SEED, vaultConfigPda.toBuffer()],
+        program.programId
+      );
+      const [lendingPositionPda] = PublicKey.findProgramAddressSync(
+        [LENDING_POSITION_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+      const [darkPoolOrderPda] = PublicKey.findProgramAddressSync(
+        [DARK_POOL_ORDER_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .privateLend({
+            action: {
+              borrow: {
+                collateralCommitment: generateCommitment(),
+                borrowCommitment: generateCommitment(),
+              },
+            },
+            interestRateBps: 800,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            lendingPosition: lendingPositionPda,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            privacyCashProgram: PRIVACY_CASH_PROGRAM_ID,
+            complianceAttestation: complianceAttestationPda,
+            compliancePolicy: compliancePolicyPda,
+            jurisdictions: jurisdictionsPda,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have blocked lending from DE");
+      } catch (err) {
+        expect(String(err)).to.include("JurisdictionBlocked");
+      }
+
+      try {
+        await program.methods
+          .privateSwap({
+            action: { placeLimitOrder: { timeInForce: { goodTilCancelled: {} } } },
+            route: { anocoinDarkPool: {} },
+            amountInCommitment: generateCommitment(),
+            minOutCommitment: generateCommitment(),
+            amountIn: new BN(0),
+            minAmountOut: new BN(0),
+            limitPriceCommitment: generateCommitment(),
+            side: { sell: {} },
+            swapProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            darkPoolOrder: darkPoolOrderPda,
+            sourceMint: shieldedMint,
+            destMint: secondaryMint,
+            sourceVault: shieldedVaultAta,
+            destVault: destVaultPda,
+            starpayProgram: STARPAY_PROGRAM_ID,
+            anoncoinProgram: ANONCOIN_PROGRAM_ID,
+            complianceAttestation: complianceAttestationPda,
+            compliancePolicy: compliancePolicyPda,
+            jurisdictions: jurisdictionsPda,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have blocked a limit order from DE");
+      } catch (err) {
+        expect(String(err)).to.include("JurisdictionBlocked");
+      }
+
+      await setComplianceRequired(false);
+      await setDeAllowedOperations(0b11111);
+    });
+
+    it("lets the attesting provider revoke", async () => {
+      await program.methods
+        .revokeCompliance({ reason: { providerRequest: {} } })
+        .accountsStrict({
+          authority: complianceProvider.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          user: user.publicKey,
+          userPosition: userPositionPda,
+          complianceAttestation: complianceAttestationPda,
+        })
+        .signers([complianceProvider])
+        .rpc();
+
+      const compliance = await program.account.complianceAttestation.fetch(complianceAttestationPda);
+      expect(compliance.isValid).to.be.false;
+    });
+
+    it("gates lending on compliance when the vault requires it", async () => {
+      const [lendingPositionPda] = PublicKey.findProgramAddressSync(
+        [LENDING_POSITION_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .adminMockYield({ action: { setComplianceRequired: { required: true } } })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
+          adminTokenAccount: adminTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await program.methods
+          .privateLend({
+            action: {
+              borrow: {
+                collateralCommitment: generateCommitment(),
+                borrowCommitment: generateCommitment(),
+              },
+            },
+            interestRateBps: 800,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            lendingPosition: lendingPositionPda,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            privacyCashProgram: PRIVACY_CASH_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have required an attestation");
+      } catch (err) {
+        expect(String(err)).to.include("KycRequired");
+      }
+
+      try {
+        await program.methods
+          .privateLend({
+            action: {
+              borrow: {
+                collateralCommitment: generateCommitment(),
+                borrowCommitment: generateCommitment(),
+              },
+            },
+            interestRateBps: 800,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            lendingPosition: lendingPositionPda,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            privacyCashProgram: PRIVACY_CASH_PROGRAM_ID,
+            complianceAttestation: complianceAttestationPda,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have rejected a revoked attestation");
+      } catch (err) {
+        expect(String(err)).to.include("ComplianceExpired");
+      }
+
+      await program.methods
+        .adminMockYield({ action: { setComplianceRequired: { required: false } } })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
+          adminTokenAccount: adminTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+  });
+
+  describe("7. Time Advance + Accrue View", () => {
+    it("admin deposits rewards to simulate yield", async () => {
+      const rewardAmount = new BN(10_000_000_000);
+
+      await program.methods
+        .adminMockYield({
+          action: { depositRewards: { amount: rewardAmount } },
+        })
+        .accountsStrict({
+          authority: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
+          adminTokenAccount: adminTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.totalShieldedTvl.toNumber()).to.be.greaterThan(0);
+    });
+
+    it("admin updates yield rate to 10% through the timelock", async () => {
+      const changeId = await scheduleChange({ updateYieldRate: { newRateBps: 1000 } });
+
+      const queued = await program.account.queuedChange.fetch(queuedChangePda(changeId));
+      expect(queued.executeAfter.toNumber() - queued.queuedAt.toNumber()).to.equal(DEFAULT_TIMELOCK_DELAY_SECS);
+
+      try {
+        await executeChange(changeId);
+        expect.fail("Should have rejected execution before the delay");
+      } catch (err) {
+        expect(String(err)).to.include("TimelockNotElapsed");
+      }
+
+      await advanceClock(DEFAULT_TIMELOCK_DELAY_SECS);
+      await executeChange(changeId);
+
+      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.currentYieldBps).to.equal(1000);
+      expect(await banksClient.getAccount(queuedChangePda(changeId))).to.be.null;
+    });
+
+    it("advances time and shows yield growth via accrue_view", async () => {
+      const currentClock = await banksClient.getClock();
+      const newTimestamp = currentClock.unixTimestamp + BigInt(30 * 24 * 60 * 60);
+
+      context.setClock(
+        new Clock(
+          currentClock.slot + BigInt(100),
+          currentClock.epochStartTimestamp,
+          currentClock.epoch,
+          currentClock.leaderScheduleEpoch,
+          newTimestamp
+        )
+      );
+
+      const result = await program.methods
+        .accrueView()
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          userPosition: userPositionPda,
+          lendingPosition: null,
+        })
+        .signers([user])
+        .rpc();
+
+      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.currentYieldBps).to.equal(1000);
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.hasActiveLoan).to.be.false;
+    });
+  });
+
+  describe("8. Private Withdraw", () => {
+    it("withdraws more than deposited (principal + yield)", async () => {
+      const withdrawalProof = generateProof(32);
+      const ownershipProof = generateProof(32);
+      const nullifier = generateNullifier();
+      const withdrawAmount = new BN(55_000_000_000);
+
+      const userBalanceBefore = await getTokenBalanceWithBankrun(context, userTokenAccount);
+
+      await program.methods
+        .privateWithdraw({
+          withdrawType: { full: {} },
+          withdrawalProof: withdrawalProof,
+          ownershipProof: ownershipProof,
+          nullifier: nullifier,
+          expectedAmount: withdrawAmount,
+          screeningProof: null,
+          auditorDisclosure: null,
+        })
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          userPosition: userPositionPda,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          screening: null,
+          auditorKey: auditorKeyPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
+
+      const received = Number(userBalanceAfter) - Number(userBalanceBefore);
+      const fee = withdrawAmount.toNumber() * 10 / 10000;
+      const expectedNet = withdrawAmount.toNumber() - fee;
+
+      expect(received).to.equal(expectedNet);
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.withdrawalCount).to.equal(1);
+      expect(userPosition.nullifier).to.deep.equal(nullifier);
+    });
+
+    it("withdraws after migrating a position with a pending legacy bridge", async () => {
+      const current = await banksClient.getAccount(userPositionPda);
+
+      // The legacy layout ends at `bump`; `has_pending_bridge` follows the discriminator,
+      // owner, vault, both encrypted amounts, balance commitment, nullifier and loan flag
+      const hasPendingBridgeOffset = 8 + 32 + 32 + 64 + 64 + 32 + 32 + 1;
+      const legacy = Buffer.from(current.data.slice(0, current.data.length - 4 - 8));
+      legacy[hasPendingBridgeOffset] = 1;
+      context.setAccount(userPositionPda, { ...current, data: legacy });
+
+      await program.methods
+        .migratePosition()
+        .accountsStrict({
+          owner: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          userPosition: userPositionPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const migrated = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(migrated.hasPendingBridge).to.be.false;
+      expect(migrated.pendingBridgeCount).to.equal(0);
+
+      const withdrawAmount = new BN(1_000_000);
+      const userBalanceBefore = await getTokenBalanceWithBankrun(context, userTokenAccount);
+
+      await program.methods
+        .privateWithdraw({
+          withdrawType: { yieldOnly: {} },
+          withdrawalProof: generateProof(32),
+          ownershipProof: generateProof(32),
+          nullifier: generateNullifier(),
+          expectedAmount: withdrawAmount,
+          screeningProof: null,
+          auditorDisclosure: null,
+        })
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          userPosition: userPositionPda,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          screening: null,
+          auditorKey: auditorKeyPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const userBalanceAfter = await getTokenBalanceWithBankrun(context, userTokenAccount);
+      expect(Number(userBalanceAfter) - Number(userBalanceBefore)).to.equal(withdrawAmount.toNumber() * (10000 - 10) / 10000);
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.withdrawalCount).to.equal(2);
+    });
+  });
+
+  describe("9. Admin Controls", () => {
+    it("toggles SDK features through the timelock", async () => {
+      const toggleArcium = (arcium: boolean) => ({
+        toggleSdk: {
+          arcium,
+          shadowwire: null,
+          anoncoin: null,
+          privacyCash: null,
+          silentswap: null,
+          starpay: null,
+          range: null,
+        },
+      });
+
+      const cancelledId = await scheduleChange(toggleArcium(false));
+      await program.methods
+        .cancelChange()
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          queuedChange: queuedChangePda(cancelledId),
+          proposer: admin.publicKey,
         })
         .signers([admin])
         .rpc();
+      expect(await banksClient.getAccount(queuedChangePda(cancelledId))).to.be.null;
+
+      const disableId = await scheduleChange(toggleArcium(false));
+      await advanceClock(DEFAULT_TIMELOCK_DELAY_SECS);
+      await executeChange(disableId);
+
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.arciumEnabled).to.be.false;
+
+      const enableId = await scheduleChange(toggleArcium(true));
+      await advanceClock(DEFAULT_TIMELOCK_DELAY_SECS);
+      await executeChange(enableId);
 
       vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
       expect(vaultConfig.arciumEnabled).to.be.true;
@@ -895,8 +3416,10 @@ describe("shadowforge", () => {
           action: { setEmergencyMode: { enabled: true } },
         })
         .accountsStrict({
-          admin: admin.publicKey,
+          authority: admin.publicKey,
           vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
           adminTokenAccount: adminTokenAccount,
           shieldedVaultAta: shieldedVaultAta,
           shieldedMint: shieldedMint,
@@ -908,15 +3431,17 @@ describe("shadowforge", () => {
 
       let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
       expect(vaultConfig.emergencyMode).to.be.true;
-      expect(vaultConfig.isPaused).to.be.true;
+      expect(vaultConfig.pausedOperations).to.equal(ALL_OPERATIONS_PAUSED);
 
       await program.methods
         .adminMockYield({
           action: { setEmergencyMode: { enabled: false } },
         })
         .accountsStrict({
-          admin: admin.publicKey,
+          authority: admin.publicKey,
           vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
           adminTokenAccount: adminTokenAccount,
           shieldedVaultAta: shieldedVaultAta,
           shieldedMint: shieldedMint,
@@ -931,8 +3456,10 @@ describe("shadowforge", () => {
           action: { setPaused: { paused: false } },
         })
         .accountsStrict({
-          admin: admin.publicKey,
+          authority: admin.publicKey,
           vaultConfig: vaultConfigPda,
+          roleRegistry: null,
+          council: councilPda(),
           adminTokenAccount: adminTokenAccount,
           shieldedVaultAta: shieldedVaultAta,
           shieldedMint: shieldedMint,
@@ -944,37 +3471,954 @@ describe("shadowforge", () => {
 
       vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
       expect(vaultConfig.emergencyMode).to.be.false;
-      expect(vaultConfig.isPaused).to.be.false;
+      expect(vaultConfig.pausedOperations).to.equal(0);
+    });
+
+    it("updates fee configuration through the timelock", async () => {
+      const changeId = await scheduleChange({
+        updateFees: {
+          depositFeeBps: 15,
+          withdrawalFeeBps: 15,
+          lendingFeeBps: null,
+          swapFeeBps: null,
+          bridgeFeeBps: null,
+        },
+      });
+
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.depositFeeBps).to.not.equal(15);
+
+      await advanceClock(DEFAULT_TIMELOCK_DELAY_SECS);
+      await executeChange(changeId);
+
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.depositFeeBps).to.equal(15);
+      expect(vaultConfig.withdrawalFeeBps).to.equal(15);
+    });
+
+    it("lets a pauser pause without the admin key", async () => {
+      const pauser = Keypair.generate();
+      const manageRoles = (action: object) =>
+        program.methods
+          .manageRoles({ action })
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            roleRegistry: roleRegistryPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      const asPauser = (action: object) =>
+        program.methods
+          .adminMockYield({ action })
+          .accountsStrict({
+            authority: pauser.publicKey,
+            vaultConfig: vaultConfigPda,
+            roleRegistry: roleRegistryPda,
+            council: councilPda(),
+            adminTokenAccount: null,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([pauser])
+          .rpc();
+
+      await manageRoles({ grant: { member: pauser.publicKey, role: { pauser: {} } } });
+      const registry = await program.account.roleRegistry.fetch(roleRegistryPda);
+      expect(registry.members.map((m) => m.member.toBase58())).to.include(pauser.publicKey.toBase58());
+
+      await asPauser({ setPaused: { paused: true } });
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(ALL_OPERATIONS_PAUSED);
+
+      try {
+        await asPauser({ setComplianceRequired: { required: true } });
+        expect.fail("Should have required the compliance officer role");
+      } catch (err) {
+        expect(String(err)).to.include("Unauthorized");
+      }
+
+      await asPauser({ setPaused: { paused: false } });
+      await manageRoles({ revoke: { member: pauser.publicKey, role: { pauser: {} } } });
+
+      try {
+        await asPauser({ setPaused: { paused: true } });
+        expect.fail("Should have rejected a revoked pauser");
+      } catch (err) {
+        expect(String(err)).to.include("Unauthorized");
+      }
+
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(0);
+    });
+
+    it("pauses bridging alone", async () => {
+      const setBridgePaused = (paused: boolean) =>
+        program.methods
+          .adminMockYield({ action: { setOperationPaused: { operation: { bridge: {} }, paused } } })
+          .accountsStrict({
+            authority: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            roleRegistry: null,
+            council: councilPda(),
+            adminTokenAccount: null,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+      await setBridgePaused(true);
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(BRIDGE_PAUSE_BIT);
+
+      const [bridgeEscrowPda] = PublicKey.findProgramAddressSync(
+        [BRIDGE_ESCROW_SEED, shieldedMint.toBuffer()],
+        program.programId
+      );
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      const [bridgeRequestPda] = PublicKey.findProgramAddressSync(
+        [
+          BRIDGE_REQUEST_SEED,
+          vaultConfigPda.toBuffer(),
+          user.publicKey.toBuffer(),
+          userPosition.bridgeNonce.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .privateBridge({
+            action: { initiateOutbound: {} },
+            destChainId: new BN(1),
+            requestNonce: userPosition.bridgeNonce,
+            amount: new BN(1_000_000_000),
+            amountCommitment: generateCommitment(),
+            encryptedRecipient: generateEncryptedRecipient(),
+            bridgeProof: generateProof(32),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            chainConfig: chainConfigPda(1),
+            vaultConfig: vaultConfigPda,
+            relayerKeys: relayerKeysPda,
+            bridgeStats: bridgeStatsPda,
+            adapterRegistry: adapterRegistryPda,
+            userPosition: userPositionPda,
+            bridgeRequest: bridgeRequestPda,
+            shieldedMint: shieldedMint,
+            userTokenAccount: userTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            bridgeEscrow: bridgeEscrowPda,
+            silentswapProgram: SILENTSWAP_PROGRAM_ID,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have rejected bridging while the bridge bit is set");
+      } catch (err) {
+        expect(String(err)).to.include("VaultPaused");
+      }
+
+      await setBridgePaused(false);
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(0);
+    });
+
+    it("keeps a vault paused under the legacy flag fully paused", async () => {
+      const setPaused = (action: object) =>
+        program.methods
+          .adminMockYield({ action })
+          .accountsStrict({
+            authority: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            roleRegistry: null,
+            council: councilPda(),
+            adminTokenAccount: null,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+      // `is_paused = true` sat in this byte before per-operation bits, after the discriminator,
+      // four keys, seven SDK flags, six fee and yield fields and the two totals
+      const pausedOperationsOffset = 8 + 32 * 4 + 7 + 2 * 6 + 8 * 2;
+      const current = await banksClient.getAccount(vaultConfigPda);
+      const legacy = Buffer.from(current.data);
+      legacy[pausedOperationsOffset] = 1;
+      context.setAccount(vaultConfigPda, { ...current, data: legacy });
+
+      // Lifting one operation leaves the rest of the legacy pause in place
+      await setPaused({ setOperationPaused: { operation: { bridge: {} }, paused: false } });
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(ALL_OPERATIONS_PAUSED & ~BRIDGE_PAUSE_BIT);
+
+      await setPaused({ setPaused: { paused: false } });
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(0);
     });
 
-    it("updates fee configuration", async () => {
+    it("requires auditor disclosure on deposits once an auditor is registered", async () => {
       await program.methods
-        .adminMockYield({
-          action: {
-            updateFees: {
-              depositFeeBps: 15,
-              withdrawalFeeBps: 15,
-              lendingFeeBps: null,
-              swapFeeBps: null,
-              bridgeFeeBps: null,
-            },
-          },
+        .setAuditorKey({
+          auditor: auditor.publicKey,
+          elgamalPubkey: auditorElgamalPubkey,
         })
         .accountsStrict({
           admin: admin.publicKey,
           vaultConfig: vaultConfigPda,
-          adminTokenAccount: adminTokenAccount,
+          council: councilPda(),
+          auditorKey: auditorKeyPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const auditorKey = await program.account.auditorKey.fetch(auditorKeyPda);
+      expect(auditorKey.auditor.toBase58()).to.equal(auditor.publicKey.toBase58());
+      expect(auditorKey.keyVersion).to.equal(1);
+
+      const amount = new BN(1_000_000_000);
+      const amountCommitment = randomRistrettoPoint();
+      const blindingFactor = randomRistrettoPoint();
+      const disclosure: AuditorDisclosure = {
+        elgamalPubkey: randomRistrettoPoint(),
+        amountCiphertext: [...amountCommitment, ...blindingFactor],
+        auditorCiphertext: generateProof(64),
+      };
+      const deposit = (auditorDisclosure: AuditorDisclosure | null, proofs: TransactionInstruction[] = []) =>
+        program.methods
+          .privateDeposit({
+            amount,
+            amountCommitment,
+            blindingFactor,
+            screeningProof: null,
+            auditorDisclosure,
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            userPosition: userPositionPda,
+            userTokenAccount: userTokenAccount,
+            shieldedVaultAta: shieldedVaultAta,
+            shieldedMint: shieldedMint,
+            complianceAttestation: null,
+            compliancePolicy: null,
+            jurisdictions: null,
+            screening: null,
+            auditorKey: auditorKeyPda,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            token2022Program: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions(proofs)
+          .signers([user])
+          .rpc();
+
+      try {
+        await deposit(null);
+        expect.fail("Should have required an auditor disclosure");
+      } catch (err) {
+        expect(String(err)).to.include("SelectiveDisclosureFailed");
+      }
+
+      // Correct ciphertext but no ZK ElGamal proofs in the transaction
+      try {
+        await deposit(disclosure);
+        expect.fail("Should have required the disclosure proofs");
+      } catch (err) {
+        expect(String(err)).to.include("SelectiveDisclosureFailed");
+      }
+
+      // Proofs that the ciphertext opens to a different amount than the one deposited
+      try {
+        await deposit(disclosure, auditorDisclosureProofs(disclosure, auditorElgamalPubkey, amount.muln(2)));
+        expect.fail("Should have rejected a ciphertext of another amount");
+      } catch (err) {
+        expect(String(err)).to.include("SelectiveDisclosureFailed");
+      }
+
+      // Equality proven against a key other than the registered auditor's
+      try {
+        await deposit(disclosure, auditorDisclosureProofs(disclosure, randomRistrettoPoint(), amount));
+        expect.fail("Should have rejected an equality proof for another auditor key");
+      } catch (err) {
+        expect(String(err)).to.include("SelectiveDisclosureFailed");
+      }
+    });
+
+    it("accepts a deposit whose auditor ciphertext is proven to encrypt the deposited amount", async () => {
+      const amount = new BN(1_000_000_000);
+      const amountCommitment = randomRistrettoPoint();
+      const blindingFactor = randomRistrettoPoint();
+      const disclosure: AuditorDisclosure = {
+        elgamalPubkey: randomRistrettoPoint(),
+        amountCiphertext: [...amountCommitment, ...blindingFactor],
+        auditorCiphertext: generateProof(64),
+      };
+      const vaultBalanceBefore = await getTokenBalanceWithBankrun(context, shieldedVaultAta);
+
+      await program.methods
+        .privateDeposit({
+          amount,
+          amountCommitment,
+          blindingFactor,
+          screeningProof: null,
+          auditorDisclosure: disclosure,
+        })
+        .accountsStrict({
+          user: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          userPosition: userPositionPda,
+          userTokenAccount: userTokenAccount,
+          shieldedVaultAta: shieldedVaultAta,
+          shieldedMint: shieldedMint,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          screening: null,
+          auditorKey: auditorKeyPda,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .preInstructions(auditorDisclosureProofs(disclosure, auditorElgamalPubkey, amount))
+        .signers([user])
+        .rpc();
+
+      const vaultBalanceAfter = await getTokenBalanceWithBankrun(context, shieldedVaultAta);
+      expect(Number(vaultBalanceAfter - vaultBalanceBefore)).to.equal(1_000_000_000);
+
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      expect(userPosition.encryptedPrincipal.commitment).to.deep.equal(amountCommitment);
+      expect(userPosition.encryptedPrincipal.handle).to.deep.equal(blindingFactor);
+    });
+
+    it("rejects a position disclosure without a ZK ElGamal proof", async () => {
+      const verifier = Keypair.generate().publicKey;
+      const [disclosureRecordPda] = PublicKey.findProgramAddressSync(
+        [DISCLOSURE_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer(), verifier.toBuffer()],
+        program.programId
+      );
+      const disclose = (validForSecs: number) =>
+        program.methods
+          .disclosePosition({
+            kind: { atLeast: {} },
+            threshold: new BN(1_000_000_000),
+            validForSecs: new BN(validForSecs),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            userPosition: userPositionPda,
+            verifier,
+            disclosureRecord: disclosureRecordPda,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+
+      try {
+        await disclose(365 * 86400);
+        expect.fail("Should have rejected a validity window over the maximum");
+      } catch (err) {
+        expect(String(err)).to.include("InvalidTimestamp");
+      }
+
+      try {
+        await disclose(7 * 86400);
+        expect.fail("Should have required a range proof");
+      } catch (err) {
+        expect(String(err)).to.include("SelectiveDisclosureFailed");
+      }
+    });
+
+    it("records disclosures backed by zero-ciphertext and range proofs", async () => {
+      const verifier = Keypair.generate().publicKey;
+      const [disclosureRecordPda] = PublicKey.findProgramAddressSync(
+        [DISCLOSURE_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer(), verifier.toBuffer()],
+        program.programId
+      );
+      const userPosition = await program.account.userEncryptedPosition.fetch(userPositionPda);
+      const disclose = (kind: object, threshold: BN, proof: TransactionInstruction) =>
+        program.methods
+          .disclosePosition({
+            kind,
+            threshold,
+            validForSecs: new BN(7 * 86400),
+          })
+          .accountsStrict({
+            user: user.publicKey,
+            vaultConfig: vaultConfigPda,
+            userPosition: userPositionPda,
+            verifier,
+            disclosureRecord: disclosureRecordPda,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          })
+          .preInstructions([proof])
+          .signers([user]);
+
+      // At least 0.5 tokens: the balance minus the threshold is proven not to wrap below zero
+      const atLeastThreshold = new BN(500_000_000);
+      const remainder = balanceMinusThreshold(
+        userPosition.encryptedPrincipal,
+        userPosition.encryptedYield,
+        atLeastThreshold
+      );
+      const atLeast = disclose(
+        { atLeast: {} },
+        atLeastThreshold,
+        zkProofInstruction(BATCHED_RANGE_PROOF_U64, rangeProofContext(Array.from(remainder.commitment)))
+      );
+      const { events } = await atLeast.simulate();
+      const disclosed = events.find((event) => event.name === "positionDisclosedEvent");
+      expect(disclosed).to.not.be.undefined;
+      expect(disclosed.data.verifier.toBase58()).to.equal(verifier.toBase58());
+      expect(disclosed.data.threshold.toNumber()).to.equal(500_000_000);
+      expect(disclosed.data.kind).to.have.property("atLeast");
+      await atLeast.rpc();
+
+      let record = await program.account.disclosureRecord.fetch(disclosureRecordPda);
+      expect(record.verifier.toBase58()).to.equal(verifier.toBase58());
+      expect(record.kind).to.have.property("atLeast");
+      expect(record.principalCommitment).to.deep.equal(userPosition.encryptedPrincipal.commitment);
+
+      // Exactly the threshold: the balance minus the threshold is proven to encrypt zero
+      const exactThreshold = new BN(1_000_000_000);
+      const zero = balanceMinusThreshold(
+        userPosition.encryptedPrincipal,
+        userPosition.encryptedYield,
+        exactThreshold
+      );
+      const exact = disclose(
+        { exact: {} },
+        exactThreshold,
+        zkProofInstruction(
+          ZERO_CIPHERTEXT_PROOF,
+          Buffer.concat([Buffer.from(randomRistrettoPoint()), zero.commitment, zero.handle])
+        )
+      );
+      const exactEvent = (await exact.simulate()).events.find((event) => event.name === "positionDisclosedEvent");
+      expect(exactEvent.data.kind).to.have.property("exact");
+      await exact.rpc();
+
+      record = await program.account.disclosureRecord.fetch(disclosureRecordPda);
+      expect(record.kind).to.have.property("exact");
+      expect(record.threshold.toNumber()).to.equal(1_000_000_000);
+
+      // A range proof over a different threshold does not back this one
+      try {
+        await disclose(
+          { atLeast: {} },
+          new BN(2_000_000_000),
+          zkProofInstruction(BATCHED_RANGE_PROOF_U64, rangeProofContext(Array.from(remainder.commitment)))
+        ).rpc();
+        expect.fail("Should have rejected a range proof for another threshold");
+      } catch (err) {
+        expect(String(err)).to.include("SelectiveDisclosureFailed");
+      }
+    });
+
+    it("hands the admin role over in two steps", async () => {
+      const successor = Keypair.generate();
+      const propose = (from: Keypair, action: object) =>
+        program.methods
+          .proposeAdmin({ action })
+          .accountsStrict({
+            admin: from.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+          })
+          .signers([from])
+          .rpc();
+      const accept = (signer: Keypair) =>
+        program.methods
+          .acceptAdmin()
+          .accountsStrict({
+            pendingAdmin: signer.publicKey,
+            vaultConfig: vaultConfigPda,
+          })
+          .signers([signer])
+          .rpc();
+
+      await propose(admin, { propose: { newAdmin: successor.publicKey } });
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pendingAdmin.toBase58()).to.equal(successor.publicKey.toBase58());
+
+      try {
+        await accept(user);
+        expect.fail("Should have rejected a signer other than the pending admin");
+      } catch (err) {
+        expect(String(err)).to.include("Unauthorized");
+      }
+
+      await propose(admin, { cancel: {} });
+      try {
+        await accept(successor);
+        expect.fail("Should have rejected accepting a cancelled handover");
+      } catch (err) {
+        expect(String(err)).to.include("InvalidAdminOperation");
+      }
+
+      await propose(admin, { propose: { newAdmin: successor.publicKey } });
+      await accept(successor);
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.admin.toBase58()).to.equal(successor.publicKey.toBase58());
+      expect(vaultConfig.pendingAdmin.toBase58()).to.equal(PublicKey.default.toBase58());
+
+      try {
+        await propose(admin, { propose: { newAdmin: user.publicKey } });
+        expect.fail("Should have rejected the previous admin");
+      } catch (err) {
+        expect(String(err)).to.include("Unauthorized");
+      }
+
+      await propose(successor, { propose: { newAdmin: admin.publicKey } });
+      await accept(admin);
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.admin.toBase58()).to.equal(admin.publicKey.toBase58());
+    });
+
+    const councilMembers: Keypair[] = [];
+
+    const proposalPda = (proposalId: number) =>
+      PublicKey.findProgramAddressSync(
+        [COUNCIL_PROPOSAL_SEED, vaultConfigPda.toBuffer(), new BN(proposalId).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      )[0];
+    const propose = (proposalId: number, paused: boolean) =>
+      program.methods
+        .councilPropose({ proposalId: new BN(proposalId), action: { setPaused: { paused } } })
+        .accountsStrict({
+          member: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          proposal: proposalPda(proposalId),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+    const approve = (proposalId: number, member: Keypair) =>
+      program.methods
+        .councilApprove()
+        .accountsStrict({
+          member: member.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          proposal: proposalPda(proposalId),
+        })
+        .signers([member])
+        .rpc();
+    const buildSetPaused = (authority: Keypair, paused: boolean, roleRegistry: PublicKey | null = null) =>
+      program.methods
+        .adminMockYield({ action: { setPaused: { paused } } })
+        .accountsStrict({
+          authority: authority.publicKey,
+          vaultConfig: vaultConfigPda,
+          roleRegistry,
+          council: councilPda(),
+          adminTokenAccount: null,
           shieldedVaultAta: shieldedVaultAta,
           shieldedMint: shieldedMint,
           token2022Program: TOKEN_2022_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
+        .signers([authority]);
+
+    it("routes privileged actions through the admin council once configured", async () => {
+      const secondMember = Keypair.generate();
+      councilMembers.push(user, secondMember);
+
+      await program.methods
+        .configureCouncil({
+          members: [user.publicKey, secondMember.publicKey],
+          threshold: 2,
+          proposalTtlSecs: new BN(86400),
+        })
+        .accountsStrict({
+          admin: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          council: councilPda(),
+          systemProgram: SystemProgram.programId,
+        })
         .signers([admin])
         .rpc();
 
-      const vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
-      expect(vaultConfig.depositFeeBps).to.equal(15);
-      expect(vaultConfig.withdrawalFeeBps).to.equal(15);
+      // Pausing stays immediate with a council configured; only lifting it needs approvals
+      await buildSetPaused(admin, true).rpc();
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(ALL_OPERATIONS_PAUSED);
+
+      try {
+        await buildSetPaused(admin, false).rpc();
+        expect.fail("Should have required council approval");
+      } catch (err) {
+        expect(String(err)).to.include("CouncilApprovalRequired");
+      }
+
+      await propose(0, false);
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(ALL_OPERATIONS_PAUSED);
+
+      try {
+        await approve(0, user);
+        expect.fail("Should have rejected a repeated approval");
+      } catch (err) {
+        expect(String(err)).to.include("InvalidAdminOperation");
+      }
+
+      await approve(0, secondMember);
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(0);
+      const proposal = await program.account.councilProposal.fetch(proposalPda(0));
+      expect(proposal.executed).to.be.true;
+
+      await buildSetPaused(admin, true).rpc();
+      await propose(1, false);
+      await advanceClock(86400);
+      try {
+        await approve(1, secondMember);
+        expect.fail("Should have rejected an expired proposal");
+      } catch (err) {
+        expect(String(err)).to.include("ProposalExpired");
+      }
+
+      await propose(2, false);
+      await approve(2, secondMember);
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(0);
+    });
+
+    it("requires council co-signers for configuration and role grants while a council exists", async () => {
+      const coSigners = councilMembers.map((member) => ({
+        pubkey: member.publicKey,
+        isSigner: true,
+        isWritable: false,
+      }));
+      const pauser = Keypair.generate();
+      const buildRelayerKey = () =>
+        program.methods
+          .setRelayerKey({
+            relayer: relayer.publicKey,
+            encryptionPubkey: generateProof(32),
+          })
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            relayerKeys: relayerKeysPda,
+            systemProgram: SystemProgram.programId,
+          });
+      const buildGrant = () =>
+        program.methods
+          .manageRoles({ action: { grant: { member: pauser.publicKey, role: { pauser: {} } } } })
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            roleRegistry: roleRegistryPda,
+            systemProgram: SystemProgram.programId,
+          });
+
+      try {
+        await buildRelayerKey().signers([admin]).rpc();
+        expect.fail("admin rotated the relayer key without the council");
+      } catch (err) {
+        expect(String(err)).to.include("CouncilApprovalRequired");
+      }
+
+      try {
+        await buildGrant().signers([admin]).rpc();
+        expect.fail("admin granted a role without the council");
+      } catch (err) {
+        expect(String(err)).to.include("CouncilApprovalRequired");
+      }
+
+      const { keyVersion } = await program.account.relayerKeyRegistry.fetch(relayerKeysPda);
+      await buildRelayerKey()
+        .remainingAccounts(coSigners)
+        .signers([admin, ...councilMembers])
+        .rpc();
+      const relayerKeys = await program.account.relayerKeyRegistry.fetch(relayerKeysPda);
+      expect(relayerKeys.keyVersion).to.equal(keyVersion + 1);
+
+      await buildGrant()
+        .remainingAccounts(coSigners)
+        .signers([admin, ...councilMembers])
+        .rpc();
+
+      // An on-call pauser can still pause at once, but not lift the pause
+      await buildSetPaused(pauser, true, roleRegistryPda).rpc();
+      let vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(ALL_OPERATIONS_PAUSED);
+
+      try {
+        await buildSetPaused(pauser, false, roleRegistryPda).rpc();
+        expect.fail("a role holder bypassed the council");
+      } catch (err) {
+        expect(String(err)).to.include("CouncilApprovalRequired");
+      }
+
+      await propose(3, false);
+      await approve(3, councilMembers[1]);
+      vaultConfig = await program.account.vaultConfig.fetch(vaultConfigPda);
+      expect(vaultConfig.pausedOperations).to.equal(0);
+    });
+
+    it("requires council co-signers to schedule or cancel timelocked changes", async () => {
+      const coSigners = councilMembers.map((member) => ({
+        pubkey: member.publicKey,
+        isSigner: true,
+        isWritable: false,
+      }));
+      const { nextChangeId: changeId } = await program.account.timelock.fetch(timelockPda());
+      const buildSchedule = () =>
+        program.methods
+          .scheduleChange({ changeId, change: { setDelay: { delaySecs: new BN(0) } } })
+          .accountsStrict({
+            authority: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            roleRegistry: null,
+            council: councilPda(),
+            timelock: timelockPda(),
+            queuedChange: queuedChangePda(changeId),
+            systemProgram: SystemProgram.programId,
+          });
+      const buildCancel = () =>
+        program.methods
+          .cancelChange()
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            queuedChange: queuedChangePda(changeId),
+            proposer: admin.publicKey,
+          });
+
+      try {
+        await buildSchedule().signers([admin]).rpc();
+        expect.fail("admin queued a change without the council");
+      } catch (err) {
+        expect(String(err)).to.include("CouncilApprovalRequired");
+      }
+
+      await buildSchedule()
+        .remainingAccounts(coSigners)
+        .signers([admin, ...councilMembers])
+        .rpc();
+
+      try {
+        await buildCancel().signers([admin]).rpc();
+        expect.fail("admin cancelled a change without the council");
+      } catch (err) {
+        expect(String(err)).to.include("CouncilApprovalRequired");
+      }
+
+      await buildCancel()
+        .remainingAccounts(coSigners)
+        .signers([admin, ...councilMembers])
+        .rpc();
+      expect(await banksClient.getAccount(queuedChangePda(changeId))).to.be.null;
+    });
+
+    it("keeps screening staleness changes on the council path for an admin that is also a provider", async () => {
+      const coSigners = councilMembers.map((member) => ({
+        pubkey: member.publicKey,
+        isSigner: true,
+        isWritable: false,
+      }));
+      const setProviders = (providers: PublicKey[]) =>
+        program.methods
+          .updateComplianceProviders({ providers })
+          .accountsStrict({
+            admin: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            complianceProviders: complianceProvidersPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts(coSigners)
+          .signers([admin, ...councilMembers])
+          .rpc();
+      const buildRootUpdate = (maxStalenessSecs: BN | null, complianceProviders: PublicKey | null) =>
+        program.methods
+          .updateScreeningRoot({ root: screeningTree.root, maxStalenessSecs })
+          .accountsStrict({
+            authority: admin.publicKey,
+            vaultConfig: vaultConfigPda,
+            council: councilPda(),
+            complianceProviders,
+            screening: screeningPda,
+            systemProgram: SystemProgram.programId,
+          });
+
+      await setProviders([complianceProvider.publicKey, admin.publicKey]);
+
+      try {
+        await buildRootUpdate(new BN(29 * 86400), complianceProvidersPda).signers([admin]).rpc();
+        expect.fail("a provider-admin changed the staleness window without the council");
+      } catch (err) {
+        expect(String(err)).to.include("CouncilApprovalRequired");
+      }
+
+      // Publishing a root alone stays on the provider path
+      await buildRootUpdate(null, complianceProvidersPda).signers([admin]).rpc();
+
+      // The admin path needs no provider registry
+      await buildRootUpdate(new BN(29 * 86400), null)
+        .remainingAccounts(coSigners)
+        .signers([admin, ...councilMembers])
+        .rpc();
+      const screening = await program.account.screeningRoot.fetch(screeningPda);
+      expect(screening.maxStalenessSecs.toNumber()).to.equal(29 * 86400);
+
+      await setProviders([complianceProvider.publicKey]);
+    });
+  });
+
+  describe("10. Recurring Swap (TWAP/DCA)", () => {
+    let recurringSwapPda: PublicKey;
+    const recurringElgamalPubkey = randomRistrettoPoint();
+
+    before(() => {
+      [recurringSwapPda] = PublicKey.findProgramAddressSync(
+        [RECURRING_SWAP_SEED, vaultConfigPda.toBuffer(), user.publicKey.toBuffer()],
+        program.programId
+      );
+    });
+
+    const executeSlice = (sliceAmountIn: BN, proofs: TransactionInstruction[] = []) =>
+      program.methods
+        .executeRecurringSwap({ sliceAmountIn })
+        .accountsStrict({
+          keeper: admin.publicKey,
+          vaultConfig: vaultConfigPda,
+          adapterRegistry: adapterRegistryPda,
+          userPosition: userPositionPda,
+          recurringSwapOrder: recurringSwapPda,
+          sourceMint: shieldedMint,
+          destMint: secondaryMint,
+          sourceVault: shieldedVaultAta,
+          destVault: destVaultPda,
+          starpayProgram: STARPAY_PROGRAM_ID,
+          anoncoinProgram: ANONCOIN_PROGRAM_ID,
+          complianceAttestation: null,
+          compliancePolicy: null,
+          jurisdictions: null,
+          instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+          token2022Program: TOKEN_2022_PROGRAM_ID,
+        })
+        .remainingAccounts(venueAccounts)
+        .preInstructions(proofs)
+        .signers([admin])
+        .rpc();
+
+    it("creates a recurring swap and executes the first slice via a keeper", async () => {
+      const encryptedTotal = { handle: randomRistrettoPoint(), commitment: randomRistrettoPoint() };
+      const encryptedSlice = { handle: randomRistrettoPoint(), commitment: randomRistrettoPoint() };
+      await program.methods
+        .recurringSwap({
+          action: {
+            create: {
+              terms: {
+                route: { split: { starpayWeightBps: 6000 } },
+                encryptedTotal,
+                encryptedSlice,
+                elgamalPubkey: recurringElgamalPubkey,
+                minOutCommitment: generateCommitment(),
+                minSliceAmountOut: new BN(495_000_000),
+                maxSlippageBps: 100,
+                intervalSeconds: new BN(3600),
+                totalSlices: 2,
+                startAt: null,
+              },
+            },
+          },
+        })
+        .accountsStrict({
+          owner: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          userPosition: userPositionPda,
+          recurringSwapOrder: recurringSwapPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      let order = await program.account.recurringSwapOrder.fetch(recurringSwapPda);
+      expect(order.status).to.deep.equal({ active: {} });
+      expect(order.totalSlices).to.equal(2);
+
+      const sliceAmountIn = new BN(500_000_000);
+      const sliceProof = ciphertextAmountProof(
+        recurringElgamalPubkey,
+        [...encryptedSlice.commitment, ...encryptedSlice.handle],
+        sliceAmountIn
+      );
+
+      // The keeper cannot swap an amount the owner's encrypted slice does not hold
+      try {
+        await executeSlice(new BN(600_000_000), [sliceProof]);
+        expect.fail("slice executed with an unproven amount");
+      } catch (err) {
+        expect(String(err)).to.include("InvalidCiphertext");
+      }
+
+      await executeSlice(sliceAmountIn, [sliceProof]);
+
+      order = await program.account.recurringSwapOrder.fetch(recurringSwapPda);
+      expect(order.executedSlices).to.equal(1);
+      expect(order.status).to.deep.equal({ active: {} });
+
+      // Each slice is debited from the encrypted total
+      const point = (bytes: number[]) => RistrettoPoint.fromHex(Uint8Array.from(bytes));
+      const remaining = (total: number[], slice: number[]) =>
+        Array.from(point(total).subtract(point(slice)).toRawBytes());
+      expect(order.encryptedTotal.commitment).to.deep.equal(
+        remaining(encryptedTotal.commitment, encryptedSlice.commitment)
+      );
+      expect(order.encryptedTotal.handle).to.deep.equal(remaining(encryptedTotal.handle, encryptedSlice.handle));
+    });
+
+    it("rejects a slice that is not due and cancels the order", async () => {
+      try {
+        await executeSlice(new BN(500_000_000));
+        expect.fail("slice executed before it was due");
+      } catch (err) {
+        expect(String(err)).to.include("RecurringSwapNotDue");
+      }
+
+      await program.methods
+        .recurringSwap({ action: { cancel: {} } })
+        .accountsStrict({
+          owner: user.publicKey,
+          vaultConfig: vaultConfigPda,
+          userPosition: userPositionPda,
+          recurringSwapOrder: recurringSwapPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const order = await program.account.recurringSwapOrder.fetch(recurringSwapPda);
+      expect(order.status).to.deep.equal({ cancelled: {} });
+      expect(order.executedSlices).to.equal(1);
     });
   });
 });